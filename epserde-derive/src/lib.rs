@@ -118,13 +118,121 @@ impl CommonDeriveInput {
     }
 }
 
-/// Return whether the struct has attributes `repr(C)`, `zero_copy`, and `deep_copy`.
+/// Return the sorted list of representation hints of the struct.
 ///
-/// Performs coherence checks (e.g., to be `zero_copy` the struct must be `repr(C)`).
+/// Hints are collected from all `repr` attributes, so `#[repr(C, align(64))]`
+/// and `#[repr(C)] #[repr(align(64))]` yield the same list.
+fn repr_attrs(input: &DeriveInput) -> Vec<String> {
+    let mut repr = input
+        .attrs
+        .iter()
+        .filter(|x| x.meta.path().is_ident("repr"))
+        .flat_map(|x| {
+            x.parse_args_with(Punctuated::<syn::Meta, token::Comma>::parse_terminated)
+                .unwrap()
+                .into_iter()
+                .map(|meta| meta.to_token_stream().to_string())
+        })
+        .collect::<Vec<_>>();
+    repr.sort();
+    repr
+}
+
+/// Return the list of options of the `epserde` attributes of the type.
+///
+/// Presently, the supported options are `stable_names`, `use_discriminant`,
+/// and `polymorphic`.
+fn epserde_attrs(input: &DeriveInput) -> Vec<String> {
+    let options = input
+        .attrs
+        .iter()
+        .filter(|x| x.meta.path().is_ident("epserde"))
+        .flat_map(|x| {
+            x.parse_args_with(Punctuated::<syn::Meta, token::Comma>::parse_terminated)
+                .unwrap()
+                .into_iter()
+                .map(|meta| meta.to_token_stream().to_string())
+        })
+        .collect::<Vec<_>>();
+    for option in &options {
+        if option != "stable_names"
+            && option != "use_discriminant"
+            && option != "polymorphic"
+            && !option.starts_with("type_hash_name =")
+        {
+            panic!(
+                "Type {} has an unknown epserde option: {}",
+                input.ident, option
+            );
+        }
+    }
+    options
+}
+
+/// Return the value of the option `type_hash_name` of the `epserde`
+/// attributes of a type, if present.
+fn type_hash_name(input: &DeriveInput) -> Option<String> {
+    input
+        .attrs
+        .iter()
+        .filter(|x| x.meta.path().is_ident("epserde"))
+        .flat_map(|x| {
+            x.parse_args_with(Punctuated::<syn::Meta, token::Comma>::parse_terminated)
+                .unwrap()
+        })
+        .find_map(|meta| match meta {
+            syn::Meta::NameValue(name_value) if name_value.path.is_ident("type_hash_name") => {
+                match name_value.value {
+                    syn::Expr::Lit(syn::ExprLit {
+                        lit: syn::Lit::Str(lit),
+                        ..
+                    }) => Some(lit.value()),
+                    _ => panic!(
+                        "Type {} has a type_hash_name option that is not a string literal",
+                        input.ident
+                    ),
+                }
+            }
+            _ => None,
+        })
+}
+
+/// Return the list of options of the `epserde` attributes of a field.
+///
+/// Presently, the only supported option is `independent`.
+fn field_epserde_attrs(field: &syn::Field, type_name: &syn::Ident) -> Vec<String> {
+    let options = field
+        .attrs
+        .iter()
+        .filter(|x| x.meta.path().is_ident("epserde"))
+        .flat_map(|x| {
+            x.parse_args_with(Punctuated::<syn::Meta, token::Comma>::parse_terminated)
+                .unwrap()
+                .into_iter()
+                .map(|meta| meta.to_token_stream().to_string())
+        })
+        .collect::<Vec<_>>();
+    for option in &options {
+        if option != "independent" {
+            panic!(
+                "A field of type {} has an unknown epserde option: {}",
+                type_name, option
+            );
+        }
+    }
+    options
+}
+
+/// Return whether the struct has attributes `repr(C)` (or `repr(transparent)`),
+/// `zero_copy`, and `deep_copy`.
+///
+/// Performs coherence checks (e.g., to be `zero_copy` the struct must be `repr(C)`
+/// or `repr(transparent)`).
 fn check_attrs(input: &DeriveInput) -> (bool, bool, bool) {
-    let is_repr_c = input.attrs.iter().any(|x| {
-        x.meta.path().is_ident("repr") && x.meta.require_list().unwrap().tokens.to_string() == "C"
-    });
+    // A repr(transparent) type has the same layout of its only non-zero-sized field
+    let is_repr_c = repr_attrs(input)
+        .iter()
+        .any(|x| x == "C" || x == "transparent");
     let is_zero_copy = input
         .attrs
         .iter()
@@ -135,13 +243,15 @@ fn check_attrs(input: &DeriveInput) -> (bool, bool, bool) {
         .any(|x| x.meta.path().is_ident("deep_copy"));
     if is_zero_copy && !is_repr_c {
         panic!(
-            "Type {} is declared as zero copy, but it is not repr(C)",
+            "Type {} is declared as zero copy, but it is not repr(C) or repr(transparent); \
+            add #[repr(C)], or replace #[zero_copy] with #[deep_copy] to serialize it as a deep-copy type",
             input.ident
         );
     }
     if is_zero_copy && is_deep_copy {
         panic!(
-            "Type {} is declared as both zero copy and deep copy",
+            "Type {} is declared as both zero copy and deep copy; \
+            remove #[zero_copy] to serialize it as a deep-copy type",
             input.ident
         );
     }
@@ -149,6 +259,105 @@ fn check_attrs(input: &DeriveInput) -> (bool, bool, bool) {
     (is_repr_c, is_zero_copy, is_deep_copy)
 }
 
+/// Return whether the type of a field mentions the type being derived (or
+/// `Self`), as in the case of a field of type `Box<Tree>` in a type `Tree`.
+///
+/// Recursion is supported only through `Box`, whose type hash and
+/// representation hash are guarded against infinite recursion; any other
+/// self-reference (e.g., `Vec<Node>` in a type `Node`) is rejected.
+fn is_recursive_field(ty: &syn::Type, name: &syn::Ident) -> bool {
+    fn mentions(tokens: proc_macro2::TokenStream, name: &syn::Ident) -> bool {
+        tokens.into_iter().any(|token| match token {
+            proc_macro2::TokenTree::Ident(ident) => ident == *name || ident == "Self",
+            proc_macro2::TokenTree::Group(group) => mentions(group.stream(), name),
+            _ => false,
+        })
+    }
+    /// Return whether the type mentions the type being derived outside of
+    /// the type parameter of a `Box`.
+    fn unboxed(ty: &syn::Type, name: &syn::Ident) -> bool {
+        match ty {
+            syn::Type::Path(type_path) => {
+                if type_path.qself.is_some() {
+                    return mentions(type_path.to_token_stream(), name);
+                }
+                type_path.path.segments.iter().any(|segment| {
+                    if segment.ident == *name || segment.ident == "Self" {
+                        return true;
+                    }
+                    if segment.ident == "Box" {
+                        return false;
+                    }
+                    match &segment.arguments {
+                        syn::PathArguments::AngleBracketed(args) => {
+                            args.args.iter().any(|arg| match arg {
+                                syn::GenericArgument::Type(ty) => unboxed(ty, name),
+                                arg => mentions(arg.to_token_stream(), name),
+                            })
+                        }
+                        arguments => mentions(arguments.to_token_stream(), name),
+                    }
+                })
+            }
+            syn::Type::Array(array) => unboxed(&array.elem, name),
+            syn::Type::Slice(slice) => unboxed(&slice.elem, name),
+            syn::Type::Paren(paren) => unboxed(&paren.elem, name),
+            syn::Type::Group(group) => unboxed(&group.elem, name),
+            syn::Type::Tuple(tuple) => tuple.elems.iter().any(|ty| unboxed(ty, name)),
+            ty => mentions(ty.to_token_stream(), name),
+        }
+    }
+    if !mentions(ty.to_token_stream(), name) {
+        return false;
+    }
+    if unboxed(ty, name) {
+        panic!(
+            "Type {} has a field of type {} that refers to the type itself not through a Box; recursive types are supported only through Box",
+            name,
+            ty.to_token_stream()
+        );
+    }
+    true
+}
+
+/// Add to a where clause a `'static` bound for each lifetime parameter.
+///
+/// Zero-copy types must be `'static`, and their ε-copy deserialization type
+/// is a reference `&'epserde_desertype Self`, which is well formed only if the
+/// lifetime parameters of the type (e.g., of a marker such as
+/// `PhantomData<&'a T>`) outlive `'epserde_desertype`.
+fn add_static_lifetime_bounds(where_clause: &mut WhereClause, generics: &syn::Generics) {
+    for param in generics.lifetimes() {
+        where_clause
+            .predicates
+            .push(WherePredicate::Lifetime(syn::PredicateLifetime {
+                lifetime: param.lifetime.clone(),
+                colon_token: token::Colon::default(),
+                bounds: Punctuated::from_iter([syn::Lifetime::new(
+                    "'static",
+                    proc_macro2::Span::call_site(),
+                )]),
+            }));
+    }
+}
+
+/// Return the type of the tags of a deep-copy enum with the given number of
+/// variants: the smallest unsigned integer type that can represent all
+/// variant indices, or `usize` if discriminants are used as tags.
+fn enum_tag_type(num_variants: usize, use_discriminant: bool) -> proc_macro2::TokenStream {
+    if use_discriminant {
+        quote! { usize }
+    } else if num_variants <= 1 << 8 {
+        quote! { u8 }
+    } else if num_variants <= 1 << 16 {
+        quote! { u16 }
+    } else if num_variants as u64 <= 1 << 32 {
+        quote! { u32 }
+    } else {
+        quote! { usize }
+    }
+}
+
 /// Generate an ε-serde implementation for custom types.
 ///
 /// It generates implementations for the traits `CopyType`,
@@ -158,18 +367,65 @@ fn check_attrs(input: &DeriveInput) -> (bool, bool, bool) {
 /// Presently we do not support unions.
 ///
 /// The attribute `zero_copy` can be used to generate an implementation for a zero-copy
-/// type, but the type must be `repr(C)` and all fields must be zero-copy.
+/// type, but the type must be `repr(C)` (or `repr(transparent)`) and all fields must
+/// be zero-copy.
 ///
 /// If you do not specify `zero_copy`, the macro assumes your structure is deep-copy.
 /// However, if you have a structure that could be zero-copy, but has no attribute,
 /// a warning will be issued every time you serialize. The warning can be silenced adding
 /// the explicity attribute `deep_copy`.
-#[proc_macro_derive(Epserde, attributes(zero_copy, deep_copy))]
+///
+/// The choice between zero-copy and deep-copy applies to the whole type: it
+/// is not possible to serialize a single field of a zero-copy type as
+/// deep-copy. If you want a type whose fields are all zero-copy to be
+/// serialized as deep-copy (e.g., to be able to add later fields that are
+/// not zero-copy), use the attribute `deep_copy`.
+///
+/// Recursive types can be derived if the recursion goes through a `Box`
+/// (e.g., `enum Tree { Leaf(u32), Node(Box<Tree>, Box<Tree>) }`).
+///
+/// By default, the type hash depends on the names and on the values of const
+/// parameters. The attribute `#[epserde(stable_names)]` excludes the names
+/// from the type hash, so that const parameters can be renamed without
+/// breaking compatibility with existing serialized data.
+///
+/// The attribute `#[epserde(type_hash_name = "Name")]` makes the type hash
+/// use the given name in place of the name of the type, so that a type can
+/// be renamed without breaking compatibility with existing serialized data.
+///
+/// Deep-copy enums are serialized by writing as a tag the index of the
+/// variant, using the smallest unsigned integer type that can represent all
+/// indices (e.g., a `u8` for enums with at most 256 variants). For enums
+/// without fields, the attribute `#[epserde(use_discriminant)]` writes
+/// instead the discriminant of the variant as a `usize`.
+///
+/// The attribute `#[epserde(polymorphic)]` supports the common case of a
+/// deep-copy enum representing a closed set of serializable types: every
+/// variant must wrap a single value of a distinct type, and for each variant
+/// an implementation of `From` for the wrapped type is generated (except for
+/// variants wrapping a type parameter).
+/// Serialization and deserialization work as for any other deep-copy enum.
+///
+/// The attribute `#[epserde(independent)]` on a field of a struct generates
+/// a method `serialize_field_<name>` serializing just that field, with its
+/// own header, as an instance of its type. The field can thus be updated
+/// independently of the rest of the structure, and deserialized using the
+/// methods of its type.
+#[proc_macro_derive(Epserde, attributes(zero_copy, deep_copy, epserde))]
 pub fn epserde_derive(input: TokenStream) -> TokenStream {
     // Cloning input for type hash
     let input_for_typehash = input.clone();
     let derive_input = parse_macro_input!(input as DeriveInput);
     let (is_repr_c, is_zero_copy, is_deep_copy) = check_attrs(&derive_input);
+    let epserde_options = epserde_attrs(&derive_input);
+    let use_discriminant = epserde_options.iter().any(|x| x == "use_discriminant");
+    let polymorphic = epserde_options.iter().any(|x| x == "polymorphic");
+    if polymorphic && (is_zero_copy || !matches!(derive_input.data, Data::Enum(_))) {
+        panic!(
+            "Type {} uses #[epserde(polymorphic)], but it is not a deep-copy enum",
+            derive_input.ident
+        );
+    }
 
     // Common values between serialize and deserialize
     let CommonDeriveInput {
@@ -201,6 +457,7 @@ pub fn epserde_derive(input: TokenStream) -> TokenStream {
             let mut non_generic_types = vec![];
             let mut generic_fields = vec![];
             let mut generic_types = vec![];
+            let mut independent_methods = vec![];
 
             // Scan the struct to find which fields are generics, and which are not.
             s.fields.iter().enumerate().for_each(|(field_idx, field)| {
@@ -211,6 +468,32 @@ pub fn epserde_derive(input: TokenStream) -> TokenStream {
                     .map(|x| x.to_token_stream())
                     .unwrap_or_else(|| syn::Index::from(field_idx).to_token_stream());
 
+                if field_epserde_attrs(field, &name)
+                    .iter()
+                    .any(|x| x == "independent")
+                {
+                    let method = syn::Ident::new(
+                        &format!("serialize_field_{}", field_name),
+                        proc_macro2::Span::call_site(),
+                    );
+                    let doc = format!(
+                        "Serialize the field `{}` with its own header, as an instance of its type.",
+                        field_name
+                    );
+                    independent_methods.push(quote! {
+                        #[doc = #doc]
+                        pub fn #method(
+                            &self,
+                            backend: &mut impl epserde::ser::WriteNoStd,
+                        ) -> epserde::ser::Result<usize>
+                        where
+                            #ty: epserde::ser::Serialize,
+                        {
+                            epserde::ser::Serialize::serialize(&self.#field_name, backend)
+                        }
+                    });
+                }
+
                 if type_names_raw.contains(&ty.to_token_stream().to_string()) {
                     generic_fields.push(field_name.clone());
                     generic_types.push(ty);
@@ -262,8 +545,16 @@ pub fn epserde_derive(input: TokenStream) -> TokenStream {
 
             let mut where_clause_des = where_clause.clone();
             let mut where_clause_ser = where_clause.clone();
+            if is_zero_copy {
+                add_static_lifetime_bounds(&mut where_clause_ser, &derive_input.generics);
+                add_static_lifetime_bounds(&mut where_clause_des, &derive_input.generics);
+            }
 
             fields_types.iter().for_each(|ty| {
+                // Bounds on fields of recursive types would make trait resolution overflow
+                if is_recursive_field(ty, &name) {
+                    return;
+                }
                 // add that every struct field has to implement SerializeInner
                 let mut bounds_ser = Punctuated::new();
                 bounds_ser.push(syn::parse_quote!(epserde::ser::SerializeInner));
@@ -330,7 +621,18 @@ pub fn epserde_derive(input: TokenStream) -> TokenStream {
                 }
             });
 
-            if is_zero_copy {
+            let independent = if independent_methods.is_empty() {
+                quote!()
+            } else {
+                quote! {
+                    #[automatically_derived]
+                    impl<#generics> #name<#generics_names> #where_clause {
+                        #(#independent_methods)*
+                    }
+                }
+            };
+
+            let out = if is_zero_copy {
                 quote! {
                     #[automatically_derived]
                     impl<#generics> epserde::traits::CopyType for  #name<#generics_names> #where_clause {
@@ -414,7 +716,7 @@ pub fn epserde_derive(input: TokenStream) -> TokenStream {
                             use epserde::deser::DeserializeInner;
                             Ok(#name{
                                 #(
-                                    #fields_names: <#fields_types>::_deserialize_full_inner(backend)?,
+                                    #fields_names: backend.read_field::<#fields_types>(stringify!(#fields_names))?,
                                 )*
                             })
                         }
@@ -434,9 +736,23 @@ pub fn epserde_derive(input: TokenStream) -> TokenStream {
                         }
                     }
                 }
+            };
+
+            quote! {
+                #out
+                #independent
             }
         }
         Data::Enum(e) => {
+            e.variants.iter().flat_map(|v| v.fields.iter()).for_each(|field| {
+                if !field_epserde_attrs(field, &name).is_empty() {
+                    panic!(
+                        "Type {} uses #[epserde(independent)] on a variant field, but the option is supported only on struct fields",
+                        name
+                    );
+                }
+            });
+
             let where_clause = derive_input
                 .generics
                 .where_clause
@@ -451,6 +767,10 @@ pub fn epserde_derive(input: TokenStream) -> TokenStream {
             let mut variant_ser = Vec::new();
             let mut where_clause_ser = where_clause.clone();
             let mut where_clause_des = where_clause.clone();
+            if is_zero_copy {
+                add_static_lifetime_bounds(&mut where_clause_ser, &derive_input.generics);
+                add_static_lifetime_bounds(&mut where_clause_des, &derive_input.generics);
+            }
             let mut variant_full_des = Vec::new();
             let mut variant_eps_des = Vec::new();
             let mut generic_types = Vec::new();
@@ -458,13 +778,59 @@ pub fn epserde_derive(input: TokenStream) -> TokenStream {
             let mut non_generic_fields = Vec::new();
             let mut non_generic_types = Vec::new();
             let mut fields_types = Vec::new();
+            // Tags written for variants and matched during deserialization
+            let tag_type = enum_tag_type(e.variants.len(), use_discriminant);
+            let mut tags = Vec::new();
+            let mut tag_patterns = Vec::new();
+            // From implementations for polymorphic enums
+            let mut from_impls = Vec::new();
             e.variants.iter().enumerate().for_each(|(variant_id, variant)| {
                 variants_names.push(variant.ident.to_token_stream());
+                if polymorphic {
+                    let ty = match &variant.fields {
+                        syn::Fields::Unnamed(fields) if fields.unnamed.len() == 1 => {
+                            &fields.unnamed[0].ty
+                        }
+                        _ => panic!(
+                            "Type {} uses #[epserde(polymorphic)], but variant {} does not wrap a single value",
+                            name, variant.ident
+                        ),
+                    };
+                    let ident = &variant.ident;
+                    // A From implementation for a type parameter would
+                    // conflict with the other ones
+                    if !type_names_raw.contains(&ty.to_token_stream().to_string()) {
+                        from_impls.push(quote! {
+                            #[automatically_derived]
+                            impl<#generics> From<#ty> for #name<#generics_names> #where_clause {
+                                #[inline(always)]
+                                fn from(value: #ty) -> Self {
+                                    Self::#ident(value)
+                                }
+                            }
+                        });
+                    }
+                }
+                if use_discriminant {
+                    if !matches!(variant.fields, syn::Fields::Unit) {
+                        panic!(
+                            "Type {} uses #[epserde(use_discriminant)], but variant {} has fields",
+                            name, variant.ident
+                        );
+                    }
+                    let ident = &variant.ident;
+                    tags.push(quote! { (Self::#ident as usize) });
+                    tag_patterns.push(quote! { tag if tag == Self::#ident as usize });
+                } else {
+                    tags.push(quote! { (#variant_id as #tag_type) });
+                    tag_patterns.push(quote! { #variant_id });
+                }
+                let tag = tags.last().unwrap();
                 match &variant.fields {
                 syn::Fields::Unit => {
                     variants.push(variant.ident.to_token_stream());
                     variant_ser.push(quote! {{
-                        backend.write("tag", &#variant_id)?;
+                        backend.write("tag", &#tag)?;
                     }});
                     variant_full_des.push(quote! {});
                     variant_eps_des.push(quote! {});
@@ -490,28 +856,31 @@ pub fn epserde_derive(input: TokenStream) -> TokenStream {
                             var_fields_names.push(ident.to_token_stream());
                             var_fields_types.push(ty.to_token_stream());
 
-                            // add that every struct field has to implement SerializeInner
-                            let mut bounds_ser = Punctuated::new();
-                            bounds_ser.push(syn::parse_quote!(epserde::ser::SerializeInner));
-                            where_clause_ser
-                                .predicates
-                                .push(WherePredicate::Type(PredicateType {
-                                    lifetimes: None,
-                                    bounded_ty: ty.clone(),
-                                    colon_token: token::Colon::default(),
-                                    bounds: bounds_ser,
-                            }));
-                            // add that every struct field has to implement DeserializeInner
-                            let mut bounds_des = Punctuated::new();
-                            bounds_des.push(syn::parse_quote!(epserde::deser::DeserializeInner));
-                            where_clause_des
-                                .predicates
-                                .push(WherePredicate::Type(PredicateType {
-                                    lifetimes: None,
-                                    bounded_ty: ty.clone(),
-                                    colon_token: token::Colon::default(),
-                                    bounds: bounds_des,
-                            }));
+                            // Bounds on fields of recursive types would make trait resolution overflow
+                            if !is_recursive_field(ty, &name) {
+                                // add that every struct field has to implement SerializeInner
+                                let mut bounds_ser = Punctuated::new();
+                                bounds_ser.push(syn::parse_quote!(epserde::ser::SerializeInner));
+                                where_clause_ser
+                                    .predicates
+                                    .push(WherePredicate::Type(PredicateType {
+                                        lifetimes: None,
+                                        bounded_ty: ty.clone(),
+                                        colon_token: token::Colon::default(),
+                                        bounds: bounds_ser,
+                                }));
+                                // add that every struct field has to implement DeserializeInner
+                                let mut bounds_des = Punctuated::new();
+                                bounds_des.push(syn::parse_quote!(epserde::deser::DeserializeInner));
+                                where_clause_des
+                                    .predicates
+                                    .push(WherePredicate::Type(PredicateType {
+                                        lifetimes: None,
+                                        bounded_ty: ty.clone(),
+                                        colon_token: token::Colon::default(),
+                                        bounds: bounds_des,
+                                }));
+                            }
 
                             if type_names_raw.contains(&ty.to_token_stream().to_string()) {
                                 methods.push(syn::parse_quote!(_deserialize_eps_inner));
@@ -525,7 +894,7 @@ pub fn epserde_derive(input: TokenStream) -> TokenStream {
                     });
                     fields_types.extend(var_fields_types.clone());
                     variant_ser.push(quote! {
-                        backend.write("tag", &#variant_id)?;
+                        backend.write("tag", &#tag)?;
                         #(
                             backend.write(stringify!(#var_fields_names), #var_fields_names)?;
                         )*
@@ -571,28 +940,31 @@ pub fn epserde_derive(input: TokenStream) -> TokenStream {
                             var_fields_types.push(ty.to_token_stream());
 
 
-                            // add that every struct field has to implement SerializeInner
-                            let mut bounds_ser = Punctuated::new();
-                            bounds_ser.push(syn::parse_quote!(epserde::ser::SerializeInner));
-                            where_clause_ser
-                                .predicates
-                                .push(WherePredicate::Type(PredicateType {
-                                    lifetimes: None,
-                                    bounded_ty: ty.clone(),
-                                    colon_token: token::Colon::default(),
-                                    bounds: bounds_ser,
-                            }));
-                            // add that every struct field has to implement DeserializeInner
-                            let mut bounds_des = Punctuated::new();
-                            bounds_des.push(syn::parse_quote!(epserde::deser::DeserializeInner));
-                            where_clause_des
-                                .predicates
-                                .push(WherePredicate::Type(PredicateType {
-                                    lifetimes: None,
-                                    bounded_ty: ty.clone(),
-                                    colon_token: token::Colon::default(),
-                                    bounds: bounds_des,
-                            }));
+                            // Bounds on fields of recursive types would make trait resolution overflow
+                            if !is_recursive_field(ty, &name) {
+                                // add that every struct field has to implement SerializeInner
+                                let mut bounds_ser = Punctuated::new();
+                                bounds_ser.push(syn::parse_quote!(epserde::ser::SerializeInner));
+                                where_clause_ser
+                                    .predicates
+                                    .push(WherePredicate::Type(PredicateType {
+                                        lifetimes: None,
+                                        bounded_ty: ty.clone(),
+                                        colon_token: token::Colon::default(),
+                                        bounds: bounds_ser,
+                                }));
+                                // add that every struct field has to implement DeserializeInner
+                                let mut bounds_des = Punctuated::new();
+                                bounds_des.push(syn::parse_quote!(epserde::deser::DeserializeInner));
+                                where_clause_des
+                                    .predicates
+                                    .push(WherePredicate::Type(PredicateType {
+                                        lifetimes: None,
+                                        bounded_ty: ty.clone(),
+                                        colon_token: token::Colon::default(),
+                                        bounds: bounds_des,
+                                }));
+                            }
 
                             if type_names_raw.contains(&ty.to_token_stream().to_string()) {
                                 methods.push(syn::parse_quote!(_deserialize_eps_inner));
@@ -609,7 +981,7 @@ pub fn epserde_derive(input: TokenStream) -> TokenStream {
                     fields_types.extend(var_fields_types.clone());
 
                     variant_ser.push(quote! {
-                        backend.write("tag", &#variant_id)?;
+                        backend.write("tag", &#tag)?;
                         #(
                             backend.write(stringify!(#var_fields_names), #var_fields_names)?;
                         )*
@@ -643,8 +1015,6 @@ pub fn epserde_derive(input: TokenStream) -> TokenStream {
                 })
                 .collect::<Vec<_>>();
 
-            let tag = (0..variants.len()).collect::<Vec<_>>();
-
             if is_zero_copy {
                 quote! {
                     #[automatically_derived]
@@ -693,6 +1063,8 @@ pub fn epserde_derive(input: TokenStream) -> TokenStream {
                 }
             } else {
                 quote! {
+                    #(#from_impls)*
+
                     #[automatically_derived]
                     impl<#generics> epserde::traits::CopyType for  #name<#generics_names> #where_clause {
                         type Copy = epserde::traits::Deep;
@@ -727,9 +1099,9 @@ pub fn epserde_derive(input: TokenStream) -> TokenStream {
                             backend: &mut impl epserde::deser::ReadWithPos,
                         ) -> core::result::Result<Self, epserde::deser::Error> {
                             use epserde::deser::DeserializeInner;
-                            match usize::_deserialize_full_inner(backend)? {
+                            match <#tag_type>::_deserialize_full_inner(backend)? as usize {
                                 #(
-                                    #tag => Ok(Self::#variants_names{ #variant_full_des }),
+                                    #tag_patterns => Ok(Self::#variants_names{ #variant_full_des }),
                                 )*
                                 tag => Err(epserde::deser::Error::InvalidTag(tag)),
                             }
@@ -742,9 +1114,9 @@ pub fn epserde_derive(input: TokenStream) -> TokenStream {
                         ) -> core::result::Result<Self::DeserType<'deserialize_eps_inner_lifetime>, epserde::deser::Error>
                         {
                             use epserde::deser::DeserializeInner;
-                            match usize::_deserialize_full_inner(backend)? {
+                            match <#tag_type>::_deserialize_full_inner(backend)? as usize {
                                 #(
-                                    #tag => Ok(Self::DeserType::<'_>::#variants_names{ #variant_eps_des }),
+                                    #tag_patterns => Ok(Self::DeserType::<'_>::#variants_names{ #variant_eps_des }),
                                 )*
                                 tag => Err(epserde::deser::Error::InvalidTag(tag)),
                             }
@@ -767,10 +1139,16 @@ pub fn epserde_derive(input: TokenStream) -> TokenStream {
 /// It generates implementations just for the traits
 /// `MaxSizeOf`, `TypeHash`, and `ReprHash`. See the documentation
 /// of [`epserde_derive`] for more information.
-#[proc_macro_derive(TypeInfo, attributes(zero_copy, deep_copy))]
+#[proc_macro_derive(TypeInfo, attributes(zero_copy, deep_copy, epserde))]
 pub fn epserde_type_hash(input: TokenStream) -> TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
     let (_, is_zero_copy, _) = check_attrs(&input);
+    // Representation hints to be hashed
+    let repr = repr_attrs(&input);
+    let epserde_options = epserde_attrs(&input);
+    let stable_names = epserde_options.iter().any(|x| x == "stable_names");
+    let use_discriminant = epserde_options.iter().any(|x| x == "use_discriminant");
+    let hash_name = type_hash_name(&input);
 
     let CommonDeriveInput {
         name,
@@ -785,6 +1163,17 @@ pub fn epserde_type_hash(input: TokenStream) -> TokenStream {
         vec![syn::parse_quote!(epserde::traits::TypeHash)],
     );
 
+    // With stable_names, only the values of generic constants are hashed
+    let hash_const_names = if stable_names {
+        quote! {}
+    } else {
+        quote! {
+            #(
+                #const_names_raw.hash(hasher);
+            )*
+        }
+    };
+
     let CommonDeriveInput {
         generics: generics_reprhash,
         ..
@@ -823,15 +1212,7 @@ pub fn epserde_type_hash(input: TokenStream) -> TokenStream {
                 .collect::<Vec<_>>();
 
             // Build type name
-            let name_literal = name.to_string();
-
-            // Add reprs
-            let repr = input
-                .attrs
-                .iter()
-                .filter(|x| x.meta.path().is_ident("repr"))
-                .map(|x| x.meta.require_list().unwrap().tokens.to_string())
-                .collect::<Vec<_>>();
+            let name_literal = hash_name.unwrap_or_else(|| name.to_string());
 
             if is_zero_copy {
                 quote! {
@@ -850,9 +1231,7 @@ pub fn epserde_type_hash(input: TokenStream) -> TokenStream {
                                 #const_names_vec.hash(hasher);
                             )*
                             // Hash the identifiers of generic constants
-                            #(
-                                #const_names_raw.hash(hasher);
-                            )*
+                            #hash_const_names
                             // Hash in struct and field names.
                             #name_literal.hash(hasher);
                             #(
@@ -921,9 +1300,7 @@ pub fn epserde_type_hash(input: TokenStream) -> TokenStream {
                                 #const_names_vec.hash(hasher);
                             )*
                             // Hash the identifiers of generic constants
-                            #(
-                                #const_names_raw.hash(hasher);
-                            )*
+                            #hash_const_names
                             // Hash in struct and field names.
                             #name_literal.hash(hasher);
                             #(
@@ -947,7 +1324,7 @@ pub fn epserde_type_hash(input: TokenStream) -> TokenStream {
                             // as they will be aligned.
                             #(
                                 *offset_of = 0;
-                                <#fields_types as epserde::traits::ReprHash>::repr_hash(hasher, offset_of);
+                                <#fields_types as epserde::traits::ReprHash>::repr_hash_isolated(hasher, offset_of);
                             )*
                         }
                     }
@@ -964,14 +1341,21 @@ pub fn epserde_type_hash(input: TokenStream) -> TokenStream {
                     predicates: Punctuated::new(),
                 });
 
+            let tag_type = enum_tag_type(e.variants.len(), use_discriminant);
             let mut var_type_hashes = Vec::new();
             let mut var_repr_hashes = Vec::new();
+            let mut var_deep_repr_hashes = Vec::new();
             let mut var_max_size_ofs = Vec::new();
 
             e.variants.iter().for_each(|variant| {
                 let ident = variant.ident.to_owned();
                 let mut var_type_hash = quote! { stringify!(#ident).hash(hasher); };
+                if use_discriminant {
+                    // Discriminants are written as tags
+                    var_type_hash.extend([quote! { (Self::#ident as usize).hash(hasher); }]);
+                }
                 let mut var_repr_hash = quote! { };
+                let mut var_deep_repr_hash = quote! { };
                 let mut var_max_size_of = quote! {  };
                 match &variant.fields {
                     syn::Fields::Unit => {}
@@ -990,6 +1374,9 @@ pub fn epserde_type_hash(input: TokenStream) -> TokenStream {
                                 var_repr_hash.extend([quote! {
                                     <#ty as epserde::traits::ReprHash>::repr_hash(hasher, offset_of);
                                 }]);
+                                var_deep_repr_hash.extend([quote! {
+                                    <#ty as epserde::traits::ReprHash>::repr_hash_isolated(hasher, offset_of);
+                                }]);
                                 var_max_size_of.extend([
                                     quote! {
                                         if max_size_of < <#ty as epserde::traits::MaxSizeOf>::max_size_of() {
@@ -1014,6 +1401,9 @@ pub fn epserde_type_hash(input: TokenStream) -> TokenStream {
                                 var_repr_hash.extend([quote! {
                                     <#ty as epserde::traits::ReprHash>::repr_hash(hasher, offset_of);
                                 }]);
+                                var_deep_repr_hash.extend([quote! {
+                                    <#ty as epserde::traits::ReprHash>::repr_hash_isolated(hasher, offset_of);
+                                }]);
                                 var_max_size_of.extend([
                                     quote! {
                                         if max_size_of < <#ty as epserde::traits::MaxSizeOf>::max_size_of() {
@@ -1026,19 +1416,12 @@ pub fn epserde_type_hash(input: TokenStream) -> TokenStream {
                 }
                 var_type_hashes.push(var_type_hash);
                 var_repr_hashes.push(var_repr_hash);
+                var_deep_repr_hashes.push(var_deep_repr_hash);
                 var_max_size_ofs.push(var_max_size_of);
             });
 
             // Build type name
-            let name_literal = name.to_string();
-
-            // Add reprs
-            let repr = input
-                .attrs
-                .iter()
-                .filter(|x| x.meta.path().is_ident("repr"))
-                .map(|x| x.meta.require_list().unwrap().tokens.to_string())
-                .collect::<Vec<_>>();
+            let name_literal = hash_name.unwrap_or_else(|| name.to_string());
 
             if is_zero_copy {
                 quote! {
@@ -1057,9 +1440,7 @@ pub fn epserde_type_hash(input: TokenStream) -> TokenStream {
                                 #const_names_vec.hash(hasher);
                             )*
                             // Hash the identifiers of generic constants
-                            #(
-                                #const_names_raw.hash(hasher);
-                            )*
+                            #hash_const_names
                             // Hash in struct and field names.
                             #name_literal.hash(hasher);
                             #(
@@ -1115,14 +1496,14 @@ pub fn epserde_type_hash(input: TokenStream) -> TokenStream {
                             // No alignment, so we do not hash in anything.
                             // Hash in DeepCopy
                             "DeepCopy".hash(hasher);
+                            // Hash in the type of the tags
+                            stringify!(#tag_type).hash(hasher);
                             // Hash the values of generic constants
                             #(
                                 #const_names_vec.hash(hasher);
                             )*
                             // Hash the identifiers of generic constants
-                            #(
-                                #const_names_raw.hash(hasher);
-                            )*
+                            #hash_const_names
                             // Hash in struct and field names.
                             #name_literal.hash(hasher);
                             #(
@@ -1142,7 +1523,7 @@ pub fn epserde_type_hash(input: TokenStream) -> TokenStream {
                             // as they will be aligned.
                             #(
                                 *offset_of = 0;
-                                #var_repr_hashes
+                                #var_deep_repr_hashes
                             )*
                         }
                     }
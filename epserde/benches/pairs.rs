@@ -0,0 +1,74 @@
+/*
+ * SPDX-FileCopyrightText: 2023 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+//! Compares the serialization and deserialization times of a large vector
+//! of zero-copy pairs with those of a vector of deep-copy pairs.
+//!
+//! Run it with `cargo bench --bench pairs`.
+
+use epserde::prelude::*;
+use maligned::A16;
+use std::time::Instant;
+
+/// A pair serialized field by field.
+#[derive(Epserde, Debug, PartialEq, Eq, Default, Clone, Copy)]
+#[deep_copy]
+struct Pair {
+    key: u32,
+    value: u64,
+}
+
+const LEN: usize = 10_000_000;
+
+fn main() {
+    let zero: Vec<(u32, u64)> = (0..LEN).map(|i| (i as u32, i as u64)).collect();
+    let deep: Vec<Pair> = zero
+        .iter()
+        .map(|&(key, value)| Pair { key, value })
+        .collect();
+
+    let mut cursor = <AlignedCursor<A16>>::new();
+    let start = Instant::now();
+    zero.serialize(&mut cursor).unwrap();
+    println!("Zero-copy pairs, serialization: {:?}", start.elapsed());
+    cursor.set_position(0);
+    let start = Instant::now();
+    let full = <Vec<(u32, u64)>>::deserialize_full(&mut cursor).unwrap();
+    println!(
+        "Zero-copy pairs, full-copy deserialization: {:?}",
+        start.elapsed()
+    );
+    assert_eq!(full, zero);
+    let start = Instant::now();
+    let eps = <Vec<(u32, u64)>>::deserialize_eps(cursor.as_bytes()).unwrap();
+    println!(
+        "Zero-copy pairs, ε-copy deserialization: {:?}",
+        start.elapsed()
+    );
+    assert_eq!(eps, zero);
+
+    println!();
+
+    let mut cursor = <AlignedCursor<A16>>::new();
+    let start = Instant::now();
+    deep.serialize(&mut cursor).unwrap();
+    println!("Deep-copy pairs, serialization: {:?}", start.elapsed());
+    cursor.set_position(0);
+    let start = Instant::now();
+    let full = <Vec<Pair>>::deserialize_full(&mut cursor).unwrap();
+    println!(
+        "Deep-copy pairs, full-copy deserialization: {:?}",
+        start.elapsed()
+    );
+    assert_eq!(full, deep);
+    let start = Instant::now();
+    let eps = <Vec<Pair>>::deserialize_eps(cursor.as_bytes()).unwrap();
+    println!(
+        "Deep-copy pairs, ε-copy deserialization: {:?}",
+        start.elapsed()
+    );
+    assert_eq!(eps, deep);
+}
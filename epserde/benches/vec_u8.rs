@@ -0,0 +1,68 @@
+/*
+ * SPDX-FileCopyrightText: 2023 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+//! Compares the throughput of full-copy and ε-copy deserialization of a
+//! `Vec<u8>` with that of [`std::io::Read::read_to_end`].
+//!
+//! Run it with `cargo bench --bench vec_u8`.
+
+use epserde::prelude::*;
+use maligned::A16;
+use std::hint::black_box;
+use std::io::Read;
+use std::time::{Duration, Instant};
+
+const LEN: usize = 64 << 20;
+const REPEATS: usize = 10;
+
+/// Run `f` several times and return the best time.
+fn best(mut f: impl FnMut()) -> Duration {
+    (0..REPEATS)
+        .map(|_| {
+            let start = Instant::now();
+            f();
+            start.elapsed()
+        })
+        .min()
+        .unwrap()
+}
+
+fn report(name: &str, elapsed: Duration) {
+    println!(
+        "{:<32}{:>10.3} ms {:>10.1} GB/s",
+        name,
+        elapsed.as_secs_f64() * 1E3,
+        LEN as f64 / elapsed.as_secs_f64() / 1E9
+    );
+}
+
+fn main() {
+    let v: Vec<u8> = (0..LEN).map(|i| i as u8).collect();
+    let mut cursor = <AlignedCursor<A16>>::new();
+    v.serialize(&mut cursor).unwrap();
+    let bytes = cursor.as_bytes();
+
+    report(
+        "read_to_end",
+        best(|| {
+            let mut res = Vec::new();
+            std::io::Cursor::new(bytes).read_to_end(&mut res).unwrap();
+            black_box(res);
+        }),
+    );
+    report(
+        "deserialize_full",
+        best(|| {
+            black_box(<Vec<u8>>::deserialize_full(&mut std::io::Cursor::new(bytes)).unwrap());
+        }),
+    );
+    report(
+        "deserialize_eps",
+        best(|| {
+            black_box(<Vec<u8>>::deserialize_eps(bytes).unwrap());
+        }),
+    );
+}
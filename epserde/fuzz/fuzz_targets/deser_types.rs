@@ -0,0 +1,52 @@
+/*
+ * SPDX-FileCopyrightText: 2023 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+#![no_main]
+
+//! Deserializes arbitrary bytes as one of a few representative types,
+//! chosen by the first byte, checking that there are no panics.
+
+use epserde::prelude::*;
+use libfuzzer_sys::fuzz_target;
+use maligned::A64;
+use std::collections::HashMap;
+
+#[derive(Epserde, Debug, Clone, Copy, PartialEq)]
+#[repr(C)]
+#[zero_copy]
+struct Point {
+    x: u32,
+    y: f64,
+}
+
+#[derive(Epserde, Debug, Clone, PartialEq)]
+enum Shape {
+    Empty,
+    Polygon(Vec<Point>),
+    Named { name: String, center: Point },
+}
+
+fn deser<T: Deserialize>(data: &[u8]) {
+    // Copy the data in an aligned buffer
+    let mut aligned = <AlignedCursor<A64>>::new();
+    std::io::Write::write_all(&mut aligned, data).unwrap();
+    let _ = T::deserialize_eps(aligned.as_bytes());
+    let _ = T::deserialize_full(&mut std::io::Cursor::new(data));
+}
+
+fuzz_target!(|data: &[u8]| {
+    let Some((&kind, data)) = data.split_first() else {
+        return;
+    };
+    match kind % 6 {
+        0 => deser::<String>(data),
+        1 => deser::<Vec<String>>(data),
+        2 => deser::<Option<Vec<u64>>>(data),
+        3 => deser::<Vec<Point>>(data),
+        4 => deser::<HashMap<u32, String>>(data),
+        _ => deser::<Vec<Shape>>(data),
+    }
+});
@@ -0,0 +1,22 @@
+/*
+ * SPDX-FileCopyrightText: 2023 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+#![no_main]
+
+//! Deserializes arbitrary bytes as a `Vec<u32>`, checking that there are
+//! no panics.
+
+use epserde::prelude::*;
+use libfuzzer_sys::fuzz_target;
+use maligned::A64;
+
+fuzz_target!(|data: &[u8]| {
+    // Copy the data in an aligned buffer
+    let mut aligned = <AlignedCursor<A64>>::new();
+    std::io::Write::write_all(&mut aligned, data).unwrap();
+    let _ = <Vec<u32>>::deserialize_eps(aligned.as_bytes());
+    let _ = <Vec<u32>>::deserialize_full(&mut std::io::Cursor::new(data));
+});
@@ -0,0 +1,50 @@
+/*
+ * SPDX-FileCopyrightText: 2023 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+use super::helpers::MAX_PREALLOC_BYTES;
+use mem_dbg::{MemDbg, MemSize};
+
+/// Configuration of full-copy deserialization.
+///
+/// A configuration can be passed to
+/// [`Deserialize::deserialize_full_with_config`](super::Deserialize::deserialize_full_with_config),
+/// and it is available to implementations of
+/// [`DeserializeInner`](super::DeserializeInner) through
+/// [`ReadWithPos::config`](super::ReadWithPos::config).
+///
+/// ```rust
+/// use epserde::prelude::*;
+/// use epserde::deser::DeserConfig;
+/// use maligned::A16;
+///
+/// let mut cursor = <AlignedCursor<A16>>::new();
+/// vec!["a".to_string(), "b".to_string()].serialize(&mut cursor).unwrap();
+/// cursor.set_position(0);
+///
+/// let config = DeserConfig {
+///     max_preallocate: 1024,
+/// };
+/// let v = <Vec<String>>::deserialize_full_with_config(&mut cursor, config).unwrap();
+/// assert_eq!(v, vec!["a", "b"]);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, MemDbg, MemSize)]
+pub struct DeserConfig {
+    /// The maximum number of bytes that are preallocated when fully
+    /// deserializing a collection (e.g., a vector).
+    ///
+    /// Declared lengths come from the serialized data, which might be
+    /// corrupted: larger collections grow incrementally as data is read.
+    /// The default is [`MAX_PREALLOC_BYTES`].
+    pub max_preallocate: usize,
+}
+
+impl Default for DeserConfig {
+    fn default() -> Self {
+        Self {
+            max_preallocate: MAX_PREALLOC_BYTES,
+        }
+    }
+}
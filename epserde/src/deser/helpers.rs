@@ -20,6 +20,7 @@ use core::mem::MaybeUninit;
 /// Full-copy deserialize a zero-copy structure.
 pub fn deserialize_full_zero<T: ZeroCopy>(backend: &mut impl ReadWithPos) -> deser::Result<T> {
     backend.align::<T>()?;
+    debug_check_alignment::<T>(backend.pos());
     unsafe {
         #[allow(clippy::uninit_assumed_init)]
         let mut buf: MaybeUninit<T> = MaybeUninit::uninit();
@@ -32,33 +33,235 @@ pub fn deserialize_full_zero<T: ZeroCopy>(backend: &mut impl ReadWithPos) -> des
     }
 }
 
+/// Check, in debug builds, that zero-copy data read at the given position of
+/// the backend is aligned as required by `T`.
+///
+/// `pos` must be a multiple of `align_of::<T>()`, as otherwise ε-copy
+/// deserialization from an aligned backend would yield a misaligned reference.
+/// This condition fails, for example, if the [`MaxSizeOf`] of a type is
+/// smaller than its alignment.
+#[inline(always)]
+fn debug_check_alignment<T: ZeroCopy>(pos: usize) {
+    debug_assert!(
+        crate::pad_align_to(pos, core::mem::align_of::<T>()) == 0,
+        "Layout mismatch for {}: the data starts at position {}, which is not a multiple of the alignment of the type ({}; max_size_of is {})",
+        core::any::type_name::<T>(),
+        pos,
+        core::mem::align_of::<T>(),
+        T::max_size_of()
+    );
+}
+
+/// The default maximum number of bytes that are preallocated when fully
+/// deserializing a vector (see [`deser::DeserConfig`]).
+///
+/// Declared lengths come from the serialized data, which might be corrupted: larger
+/// vectors are allocated incrementally as data is read.
+pub const MAX_PREALLOC_BYTES: usize = 1 << 20;
+
+/// Return the capacity to preallocate for a collection of `len` elements of
+/// type `T`, limited by the [maximum preallocation](deser::DeserConfig::max_preallocate)
+/// of the backend configuration.
+pub fn prealloc_len<T>(backend: &impl ReadWithPos, len: usize) -> usize {
+    len.min(backend.config().max_preallocate / core::mem::size_of::<T>().max(1))
+}
+
+/// Return the size in bytes of `len` elements of type `T`, or
+/// [`deser::Error::LengthTooLarge`] if it cannot be allocated.
+pub(crate) fn checked_bytes<T>(len: usize) -> deser::Result<usize> {
+    match len.checked_mul(core::mem::size_of::<T>()) {
+        Some(bytes) if bytes <= isize::MAX as usize => Ok(bytes),
+        _ => Err(deser::Error::LengthTooLarge(len)),
+    }
+}
+
+/// Check that a sequence of offsets is nonempty, starts from zero, and is
+/// nondecreasing, and return its last element, or return
+/// [`deser::Error::InvalidOffsets`] otherwise.
+///
+/// Offsets delimiting the elements of a flat sequence must satisfy these
+/// conditions so that elements can be accessed without further checks.
+pub(crate) fn check_offsets(offsets: &[usize]) -> deser::Result<usize> {
+    if offsets.first() != Some(&0) || offsets.windows(2).any(|w| w[0] > w[1]) {
+        return Err(deser::Error::InvalidOffsets);
+    }
+    Ok(offsets[offsets.len() - 1])
+}
+
+/// Convert a 64-bit value into a `usize`, or return
+/// [`deser::Error::UsizeValueOverflow`] if it does not fit in a `usize` on the
+/// current architecture.
+///
+/// This function is used to check serialized `usize` values written as `u64`
+/// values (see [`crate::USIZE_SIZE`]), and file lengths.
+pub fn usize_from_u64(value: u64) -> deser::Result<usize> {
+    Ok(check_usize_bits(value, usize::BITS)? as usize)
+}
+
+/// Check that a 64-bit value fits in a `usize` of the given number of bits,
+/// or return [`deser::Error::UsizeValueOverflow`].
+///
+/// [`usize_from_u64`] performs this check using the number of bits of a
+/// native `usize`; this function makes it possible to check the behavior
+/// for a different pointer width.
+pub(crate) fn check_usize_bits(value: u64, usize_bits: u32) -> deser::Result<u64> {
+    if usize_bits < u64::BITS && value >> usize_bits != 0 {
+        return Err(deser::Error::UsizeValueOverflow(value));
+    }
+    Ok(value)
+}
+
+/// Return whether `T` is one of the nonzero integer types.
+#[cfg(feature = "validate")]
+fn is_nonzero<T: 'static>() -> bool {
+    use core::any::TypeId;
+    use core::num::*;
+    [
+        TypeId::of::<NonZeroIsize>(),
+        TypeId::of::<NonZeroI8>(),
+        TypeId::of::<NonZeroI16>(),
+        TypeId::of::<NonZeroI32>(),
+        TypeId::of::<NonZeroI64>(),
+        TypeId::of::<NonZeroI128>(),
+        TypeId::of::<NonZeroUsize>(),
+        TypeId::of::<NonZeroU8>(),
+        TypeId::of::<NonZeroU16>(),
+        TypeId::of::<NonZeroU32>(),
+        TypeId::of::<NonZeroU64>(),
+        TypeId::of::<NonZeroU128>(),
+    ]
+    .contains(&TypeId::of::<T>())
+}
+
+/// If the `validate` feature is enabled and `T` is `bool`, check that all
+/// bytes are valid booleans (i.e., zero or one); if `T` is a nonzero integer
+/// type, check that no element is zero.
+///
+/// Booleans and nonzero integers are zero-copy, so arrays and vectors of such
+/// types are read by reinterpreting bytes; an invalid value would be undefined
+/// behavior. ε-copy deserialization is never checked.
+#[inline(always)]
+#[cfg_attr(not(feature = "validate"), allow(clippy::extra_unused_type_parameters))]
+pub(crate) fn check_values<T: 'static>(_bytes: &[u8]) -> deser::Result<()> {
+    #[cfg(feature = "validate")]
+    if core::any::TypeId::of::<T>() == core::any::TypeId::of::<bool>() {
+        if let Some(&byte) = _bytes.iter().find(|&&byte| byte > 1) {
+            return Err(deser::Error::InvalidBool(byte));
+        }
+    } else if is_nonzero::<T>()
+        && _bytes
+            .chunks_exact(core::mem::size_of::<T>())
+            .any(|chunk| chunk.iter().all(|&byte| byte == 0))
+    {
+        return Err(deser::Error::InvalidNonZero);
+    }
+    Ok(())
+}
+
 /// Full-copy deserialize a vector of zero-copy structures.
 ///
 /// Note that this method uses a single [`ReadNoStd::read_exact`]
-/// call to read the entire vector.
+/// call to read the entire vector, unless the vector is larger
+/// than the [maximum preallocation](deser::DeserConfig::max_preallocate).
+/// If the declared length exceeds the available data, this function returns
+/// [`deser::Error::ReadError`] having allocated at most the maximum
+/// preallocation beyond the data actually read.
 pub fn deserialize_full_vec_zero<T: DeserializeInner + ZeroCopy>(
     backend: &mut impl ReadWithPos,
 ) -> deser::Result<Vec<T>> {
     let len = usize::_deserialize_full_inner(backend)?;
     backend.align::<T>()?;
-    let mut res = Vec::with_capacity(len);
-    // SAFETY: we just allocated this vector so it is safe to set the length.
-    // read_exact guarantees that the vector will be filled with data.
-    #[allow(clippy::uninit_vec)]
+    read_vec_zero(backend, len)
+}
+
+/// Full-copy deserialize a boxed slice of zero-copy structures.
+///
+/// Vectors and boxed slices have the same serialized content, so this
+/// function can be used to deserialize into a boxed slice a vector
+/// serialized with [`serialize_slice_zero`](crate::ser::helpers::serialize_slice_zero)
+/// (e.g., when implementing [`DeserializeInner`] for a structure containing
+/// a vector). The elements are read directly into a boxed slice, unless the
+/// slice is larger than the
+/// [maximum preallocation](deser::DeserConfig::max_preallocate): in that case,
+/// since the length might be corrupted, they are read into a vector that
+/// grows as data is read, and that is then turned into a boxed slice, possibly
+/// reallocating it.
+pub fn deserialize_full_boxed_slice_zero<T: DeserializeInner + ZeroCopy>(
+    backend: &mut impl ReadWithPos,
+) -> deser::Result<Box<[T]>> {
+    let len = usize::_deserialize_full_inner(backend)?;
+    backend.align::<T>()?;
+    let bytes = checked_bytes::<T>(len)?;
+    if bytes > backend.config().max_preallocate {
+        return Ok(read_vec_zero(backend, len)?.into_boxed_slice());
+    }
+    debug_check_alignment::<T>(backend.pos());
+    let mut res = Box::<[T]>::new_uninit_slice(len);
+    // SAFETY: the slice contains exactly len elements, and read_exact
+    // guarantees that they will be filled with data.
     unsafe {
-        res.set_len(len);
-        backend.read_exact(res.align_to_mut::<u8>().1)?;
+        let buf = core::slice::from_raw_parts_mut(res.as_mut_ptr() as *mut u8, bytes);
+        backend.read_exact(buf)?;
+        check_values::<T>(buf)?;
+        Ok(res.assume_init())
     }
+}
 
-    Ok(res)
+/// Read `len` zero-copy structures into a vector.
+///
+/// The backend must be already aligned.
+pub(crate) fn read_vec_zero<T: ZeroCopy>(
+    backend: &mut impl ReadWithPos,
+    len: usize,
+) -> deser::Result<Vec<T>> {
+    debug_check_alignment::<T>(backend.pos());
+    let bytes = checked_bytes::<T>(len)?;
+    let max_preallocate = backend.config().max_preallocate;
+    if bytes <= max_preallocate {
+        let mut res = Vec::with_capacity(len);
+        // SAFETY: we just allocated this vector so it is safe to set the length.
+        // read_exact guarantees that the vector will be filled with data.
+        #[allow(clippy::uninit_vec)]
+        unsafe {
+            res.set_len(len);
+            let buf = res.align_to_mut::<u8>().1;
+            backend.read_exact(buf)?;
+            check_values::<T>(buf)?;
+        }
+        Ok(res)
+    } else {
+        // The length might be corrupted, so we grow the vector as we read
+        let chunk = (max_preallocate / core::mem::size_of::<T>()).max(1);
+        let mut res: Vec<T> = Vec::with_capacity(chunk);
+        while res.len() < len {
+            let start = res.len();
+            let n = chunk.min(len - start);
+            res.reserve(n);
+            // SAFETY: we just reserved space for n elements, and read_exact
+            // guarantees that they will be filled with data.
+            unsafe {
+                let buf = core::slice::from_raw_parts_mut(
+                    res.as_mut_ptr().add(start) as *mut u8,
+                    n * core::mem::size_of::<T>(),
+                );
+                backend.read_exact(buf)?;
+                check_values::<T>(buf)?;
+                res.set_len(start + n);
+            }
+        }
+        Ok(res)
+    }
 }
 
 /// Full-copy deserialize a vector of deep-copy structures.
+///
+/// The capacity preallocated for the vector is limited by the
+/// [maximum preallocation](deser::DeserConfig::max_preallocate).
 pub fn deserialize_full_vec_deep<T: DeserializeInner + DeepCopy>(
     backend: &mut impl ReadWithPos,
 ) -> deser::Result<Vec<T>> {
     let len = usize::_deserialize_full_inner(backend)?;
-    let mut res = Vec::with_capacity(len);
+    let mut res = Vec::with_capacity(prealloc_len::<T>(backend, len));
     for _ in 0..len {
         res.push(T::_deserialize_full_inner(backend)?);
     }
@@ -78,6 +281,9 @@ pub fn deserialize_eps_zero<'a, T: ZeroCopy>(
         return Ok(unsafe { MaybeUninit::uninit().assume_init() });
     }
     backend.align::<T>()?;
+    if bytes > backend.data.len() {
+        return Err(deser::Error::unexpected_eof());
+    }
     let (pre, data, after) = unsafe { backend.data[..bytes].align_to::<T>() };
     debug_assert!(pre.is_empty());
     debug_assert!(after.is_empty());
@@ -88,12 +294,36 @@ pub fn deserialize_eps_zero<'a, T: ZeroCopy>(
 
 /// ε-copy deserialize a reference to a slice of zero-copy structures
 /// backed by the `data` field of `backend`.
+///
+/// If the declared length exceeds the available data, this function returns
+/// [`deser::Error::LengthTooLarge`] without slicing the backend.
 pub fn deserialize_eps_slice_zero<'a, T: ZeroCopy>(
     backend: &mut SliceWithPos<'a>,
 ) -> deser::Result<&'a [T]> {
     let len = usize::_deserialize_full_inner(backend)?;
-    let bytes = len * core::mem::size_of::<T>();
     backend.align::<T>()?;
+    slice_zero(backend, len)
+}
+
+/// Return a reference to a slice of `len` zero-copy structures
+/// backed by the `data` field of `backend`.
+///
+/// The backend must be already aligned.
+pub(crate) fn slice_zero<'a, T: ZeroCopy>(
+    backend: &mut SliceWithPos<'a>,
+    len: usize,
+) -> deser::Result<&'a [T]> {
+    let bytes = checked_bytes::<T>(len)?;
+    if bytes > backend.data.len() {
+        return Err(deser::Error::LengthTooLarge(len));
+    }
+    if core::mem::size_of::<T>() == 0 {
+        // align_to() would return an empty slice for zero-sized types
+        // SAFETY: a dangling pointer is valid for a slice of zero-sized types
+        return Ok(unsafe {
+            core::slice::from_raw_parts(core::ptr::NonNull::<T>::dangling().as_ptr(), len)
+        });
+    }
     let (pre, data, after) = unsafe { backend.data[..bytes].align_to::<T>() };
     debug_assert!(pre.is_empty());
     debug_assert!(after.is_empty());
@@ -102,13 +332,38 @@ pub fn deserialize_eps_slice_zero<'a, T: ZeroCopy>(
 }
 
 /// ε-copy deserialize a vector of deep-copy structures.
+///
+/// The capacity preallocated for the vector is limited by the
+/// [maximum preallocation](deser::DeserConfig::max_preallocate).
 pub fn deserialize_eps_vec_deep<'a, T: DeepCopy + DeserializeInner>(
     backend: &mut SliceWithPos<'a>,
 ) -> deser::Result<Vec<<T as DeserializeInner>::DeserType<'a>>> {
     let len = usize::_deserialize_full_inner(backend)?;
-    let mut res = Vec::with_capacity(len);
+    let mut res = Vec::with_capacity(prealloc_len::<deser::DeserType<'a, T>>(backend, len));
     for _ in 0..len {
         res.push(T::_deserialize_eps_inner(backend)?);
     }
     Ok(res)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_check_usize_bits() {
+        // Simulate a length written by a 64-bit architecture that does not
+        // fit in the usize of a 32-bit architecture
+        let too_large = u32::MAX as u64 + 1;
+        assert!(matches!(
+            check_usize_bits(too_large, 32),
+            Err(deser::Error::UsizeValueOverflow(value)) if value == too_large
+        ));
+        assert_eq!(check_usize_bits(too_large - 1, 32).unwrap(), too_large - 1);
+        assert!(matches!(
+            check_usize_bits(1 << 16, 16),
+            Err(deser::Error::UsizeValueOverflow(value)) if value == 1 << 16
+        ));
+        assert_eq!(check_usize_bits(u64::MAX, 64).unwrap(), u64::MAX);
+    }
+}
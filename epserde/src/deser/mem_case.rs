@@ -5,7 +5,11 @@
  */
 
 use bitflags::bitflags;
-use core::{mem::size_of, ops::Deref};
+use core::{
+    marker::PhantomData,
+    mem::size_of,
+    ops::{Deref, DerefMut},
+};
 use maligned::A64;
 use mem_dbg::{MemDbg, MemSize};
 
@@ -65,11 +69,25 @@ pub type MemoryAlignment = A64;
 /// created in memory; the `Memory` variant is used when the data structure is deserialized
 /// from a file loaded into a heap-allocated memory region; the `Mmap` variant is used when
 /// the data structure is deserialized from a `mmap()`-based region, either coming from
-/// an allocation or a from mapping a file.
+/// an allocation or a from mapping a file; the `Bytes` variant, available with the `bytes`
+/// feature, is used when the data structure is deserialized from a [`bytes::Bytes`] buffer.
+///
+/// All variants are [`Send`] and [`Sync`]: the `Memory` variant owns its
+/// memory region, the `Mmap` variant is a read-only mapping (regions
+/// filled by ε-serde are made read-only before being wrapped), and the
+/// `Bytes` variant is an immutable reference-counted buffer, so the memory
+/// cannot be modified through a shared reference. As a consequence, a
+/// [`MemCase`] is [`Send`] or [`Sync`] if and only if the wrapped structure
+/// is.
+///
+/// The enum is non-exhaustive, as the set of variants depends on the enabled
+/// features.
 #[derive(Debug, MemDbg, MemSize)]
+#[non_exhaustive]
 pub enum MemBackend {
     /// No backend. The data structure is a standard Rust data structure.
-    /// This variant is returned by [`MemCase::encase`].
+    /// This variant is returned by [`MemCase::encase`] and
+    /// [`crate::deser::Deserialize::from_static`].
     None,
     /// The backend is a heap-allocated in a memory region aligned to 16 bytes.
     /// This variant is returned by [`crate::deser::Deserialize::load_mem`].
@@ -77,8 +95,30 @@ pub enum MemBackend {
     /// The backend is the result to a call to `mmap()`.
     /// This variant is returned by [`crate::deser::Deserialize::load_mmap`] and [`crate::deser::Deserialize::mmap`].
     Mmap(mmap_rs::Mmap),
+    /// The backend is a [`bytes::Bytes`] buffer.
+    /// This variant is returned by [`crate::deser::Deserialize::from_bytes`].
+    #[cfg(feature = "bytes")]
+    Bytes(BytesBackend),
+}
+
+/// A [`bytes::Bytes`] buffer used as a [`MemBackend`].
+///
+/// This newtype exists only to provide [`MemSize`] and [`MemDbg`]
+/// implementations, which are not available for [`bytes::Bytes`].
+#[cfg(feature = "bytes")]
+#[derive(Debug, Clone)]
+pub struct BytesBackend(pub bytes::Bytes);
+
+#[cfg(feature = "bytes")]
+impl mem_dbg::MemSize for BytesBackend {
+    fn mem_size(&self, _flags: mem_dbg::SizeFlags) -> usize {
+        size_of::<Self>() + self.0.len()
+    }
 }
 
+#[cfg(feature = "bytes")]
+impl mem_dbg::MemDbgImpl for BytesBackend {}
+
 impl MemBackend {
     pub fn as_ref(&self) -> Option<&[u8]> {
         match self {
@@ -90,6 +130,8 @@ impl MemBackend {
                 )
             }),
             MemBackend::Mmap(mmap) => Some(mmap),
+            #[cfg(feature = "bytes")]
+            MemBackend::Bytes(bytes) => Some(&bytes.0),
         }
     }
 }
@@ -116,10 +158,31 @@ impl<S> MemCase<S> {
     pub fn encase(s: S) -> MemCase<S> {
         MemCase(s, MemBackend::None)
     }
+
+    /// Return the bytes of the backend, or `None` if the backend is
+    /// [`MemBackend::None`].
+    ///
+    /// Note that the bytes might include some zero padding after the
+    /// serialized data.
+    pub fn backend_bytes(&self) -> Option<&[u8]> {
+        self.1.as_ref()
+    }
 }
 
-unsafe impl<S: Send> Send for MemCase<S> {}
-unsafe impl<S: Sync> Sync for MemCase<S> {}
+impl<S: Deref> MemCase<S>
+where
+    S::Target: ToOwned,
+{
+    /// Copies the wrapped structure into owned data, and drops the backend.
+    ///
+    /// For example, the `MemCase<&[u32]>` returned by ε-copy deserializing a
+    /// `Vec<u32>` can be turned into an owned `Vec<u32>`, and the memory
+    /// it was deserialized from is then released.
+    #[allow(clippy::wrong_self_convention)]
+    pub fn to_owned_value(self) -> <S::Target as ToOwned>::Owned {
+        self.0.deref().to_owned()
+    }
+}
 
 impl<S> Deref for MemCase<S> {
     type Target = S;
@@ -141,3 +204,81 @@ impl<S: Send + Sync> From<S> for MemCase<S> {
         MemCase::encase(s)
     }
 }
+
+/// A wrapper keeping together a mutable zero-copy structure and the
+/// writable memory mapping it lives in.
+///
+/// Instances are returned by [`crate::deser::Deserialize::mmap_mut`].
+/// [`MemCaseMut`] implements [`Deref`] and [`DerefMut`] to the wrapped
+/// type, so modifications are performed directly on the mapped
+/// file. Modifications are written back to the file when the mapping is
+/// [flushed](MemCaseMut::flush) or dropped, but the time at which they become
+/// visible to other processes is up to the operating system.
+///
+/// # Safety
+///
+/// The content of the mapping is accessed as an instance of the wrapped
+/// type. The wrapped type is zero-copy, and at construction the header of the
+/// file and the alignment of the structure in memory are checked, but if the
+/// underlying file is modified or truncated externally while it is mapped the
+/// behavior is undefined.
+#[derive(Debug)]
+pub struct MemCaseMut<T> {
+    mmap: mmap_rs::MmapMut,
+    offset: usize,
+    _marker: PhantomData<T>,
+}
+
+impl<T> MemCaseMut<T> {
+    /// Creates a new [`MemCaseMut`] from a writable mapping containing an
+    /// instance of `T` at the given offset.
+    ///
+    /// # Safety
+    ///
+    /// The caller must guarantee that at `offset` there is a properly aligned
+    /// and valid instance of `T` fitting entirely in the mapping.
+    pub(crate) unsafe fn new(mmap: mmap_rs::MmapMut, offset: usize) -> Self {
+        Self {
+            mmap,
+            offset,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Flushes the modifications to the underlying file, blocking until
+    /// they have been written.
+    pub fn flush(&self) -> Result<(), mmap_rs::Error> {
+        self.mmap.flush(0..self.mmap.len())
+    }
+}
+
+impl<T> Deref for MemCaseMut<T> {
+    type Target = T;
+    #[inline(always)]
+    fn deref(&self) -> &Self::Target {
+        // SAFETY: guaranteed at construction
+        unsafe { &*(self.mmap.as_ptr().add(self.offset) as *const T) }
+    }
+}
+
+impl<T> DerefMut for MemCaseMut<T> {
+    #[inline(always)]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        // SAFETY: guaranteed at construction
+        unsafe { &mut *(self.mmap.as_mut_ptr().add(self.offset) as *mut T) }
+    }
+}
+
+impl<T> AsRef<T> for MemCaseMut<T> {
+    #[inline(always)]
+    fn as_ref(&self) -> &T {
+        self
+    }
+}
+
+impl<T> AsMut<T> for MemCaseMut<T> {
+    #[inline(always)]
+    fn as_mut(&mut self) -> &mut T {
+        self
+    }
+}
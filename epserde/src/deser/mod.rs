@@ -18,12 +18,15 @@ which is automatically derived with `#[derive(Deserialize)]`.
 */
 
 use crate::traits::*;
-use crate::{MAGIC, MAGIC_REV, VERSION};
+use crate::{EXTENSIONS_MINOR_VERSION, MAGIC, MAGIC_REV, USIZE_SIZE, VERSION};
 use core::mem::align_of;
 use core::ptr::addr_of_mut;
 use core::{hash::Hasher, mem::MaybeUninit};
+use sealed::sealed;
 use std::{io::BufReader, path::Path};
 
+pub mod config;
+pub use config::*;
 pub mod helpers;
 pub use helpers::*;
 pub mod mem_case;
@@ -32,8 +35,18 @@ pub mod read;
 pub use read::*;
 pub mod reader_with_pos;
 pub use reader_with_pos::*;
+pub mod registry;
+pub use registry::*;
+pub mod seq_reader;
+pub use seq_reader::*;
 pub mod slice_with_pos;
 pub use slice_with_pos::*;
+pub mod verify;
+pub use verify::*;
+#[cfg(feature = "timing")]
+pub mod timed_reader;
+#[cfg(feature = "timing")]
+pub use timed_reader::*;
 
 pub type Result<T> = core::result::Result<T, Error>;
 
@@ -49,9 +62,176 @@ pub type DeserType<'a, T> = <T as DeserializeInner>::DeserType<'a>;
 /// [`Deserialize::load_full`], [`Deserialize::load_mem`], and [`Deserialize::mmap`].
 pub trait Deserialize: TypeHash + ReprHash + DeserializeInner {
     /// Fully deserialize a structure of this type from the given backend.
-    fn deserialize_full(backend: &mut impl ReadNoStd) -> Result<Self>;
+    ///
+    /// The backend can be unsized: for example, it can be a `&mut dyn Read`.
+    fn deserialize_full(backend: &mut (impl ReadNoStd + ?Sized)) -> Result<Self>;
+    /// Fully deserialize a structure of this type from the given backend
+    /// using the given [configuration](DeserConfig).
+    ///
+    /// [`Deserialize::deserialize_full`] uses the default configuration.
+    fn deserialize_full_with_config(
+        backend: &mut (impl ReadNoStd + ?Sized),
+        config: DeserConfig,
+    ) -> Result<Self>;
     /// ε-copy deserialize a structure of this type from the given backend.
     fn deserialize_eps(backend: &'_ [u8]) -> Result<Self::DeserType<'_>>;
+    /// ε-copy deserialize a structure of this type from the given backend,
+    /// returning also the number of bytes that have been read.
+    ///
+    /// This method is useful if the serialized data is followed by other data.
+    fn deserialize_eps_with_pos(backend: &'_ [u8]) -> Result<(Self::DeserType<'_>, usize)>;
+
+    /// Deserialize a structure of this type from the given backend using
+    /// the given [mode](DeserMode), that is, [`Full`] or [`Eps`].
+    ///
+    /// This method makes it possible to write generic code that can be used
+    /// both with full-copy and with ε-copy deserialization:
+    ///
+    /// ```rust
+    /// use epserde::prelude::*;
+    /// use epserde::deser::{DeserMode, Eps, Full};
+    /// use maligned::A16;
+    ///
+    /// fn first<M: DeserMode>(bytes: &[u8]) -> u64
+    /// where
+    ///     for<'a> M::Output<'a, Vec<u64>>: AsRef<[u64]>,
+    /// {
+    ///     <Vec<u64>>::deserialize_with_mode::<M>(bytes).unwrap().as_ref()[0]
+    /// }
+    ///
+    /// let mut cursor = <AlignedCursor<A16>>::new();
+    /// vec![42_u64, 43].serialize(&mut cursor).unwrap();
+    /// assert_eq!(first::<Full>(cursor.as_bytes()), 42);
+    /// assert_eq!(first::<Eps>(cursor.as_bytes()), 42);
+    /// ```
+    fn deserialize_with_mode<M: DeserMode>(backend: &'_ [u8]) -> Result<M::Output<'_, Self>> {
+        M::deserialize::<Self>(backend)
+    }
+
+    /// Fully deserialize a structure of this type from the given backend,
+    /// returning also how long it took to deserialize each of its fields.
+    ///
+    /// Timings are returned in reading order, paired with the name of the
+    /// field. Only the fields of the top-level structure are timed, and only
+    /// if its implementation is derived; for other types, no timing is
+    /// returned. This method is available with the `timing` feature.
+    #[cfg(feature = "timing")]
+    fn deserialize_full_timed(
+        backend: &mut (impl ReadNoStd + ?Sized),
+    ) -> Result<(Self, Vec<(String, std::time::Duration)>)> {
+        let mut backend = TimedReader::new(ReaderWithPos::new(backend));
+        let extensions = check_header_ext::<Self>(&mut backend)?;
+        let res = Self::_deserialize_full_inner(&mut backend)?;
+        if extensions {
+            skip_extensions(&mut backend)?;
+        }
+        Ok((res, backend.into_timings()))
+    }
+
+    /// Fully deserialize a structure of this type from the given backend,
+    /// returning also the bytes following the serialized data.
+    ///
+    /// This method is useful if the serialized data is followed by other data,
+    /// such as a custom footer or a checksum: all bytes are read from the
+    /// backend until end of file. For ε-copy deserialization, see
+    /// [`Deserialize::deserialize_eps_with_pos`].
+    #[cfg(feature = "std")]
+    fn deserialize_full_with_trailer(
+        backend: &mut (impl std::io::Read + ?Sized),
+    ) -> Result<(Self, Vec<u8>)> {
+        let res = Self::deserialize_full(backend)?;
+        let mut trailer = Vec::new();
+        backend
+            .read_to_end(&mut trailer)
+            .map_err(Error::ReadError)?;
+        Ok((res, trailer))
+    }
+
+    /// Fully deserialize a structure of this type from the given backend,
+    /// without reading a header.
+    ///
+    /// This method reads data written by
+    /// [`Serialize::serialize_no_header`](crate::ser::Serialize::serialize_no_header),
+    /// usually after checking a shared header with [`check_header`].
+    fn deserialize_full_no_header(backend: &mut impl ReadWithPos) -> Result<Self> {
+        Self::_deserialize_full_inner(backend)
+    }
+
+    /// ε-copy deserialize a structure of this type from the given backend,
+    /// without reading a header.
+    ///
+    /// This method reads data written by
+    /// [`Serialize::serialize_no_header`](crate::ser::Serialize::serialize_no_header),
+    /// usually after checking a shared header with [`check_header`].
+    fn deserialize_eps_no_header<'a>(
+        backend: &mut SliceWithPos<'a>,
+    ) -> Result<Self::DeserType<'a>> {
+        Self::_deserialize_eps_inner(backend)
+    }
+
+    /// ε-copy deserialize a structure of this type from `'static` bytes,
+    /// returning a [`MemCase`] with no backend.
+    ///
+    /// This method is useful with data that is embedded in the executable, or
+    /// that has been leaked and will never be deallocated. Note that
+    /// the bytes must be aligned as required by the type: in particular, the
+    /// data returned by [`include_bytes!`] has no alignment guarantee, so
+    /// you should use [`include_aligned!`](crate::include_aligned) instead.
+    fn from_static(
+        backend: &'static [u8],
+    ) -> Result<MemCase<<Self as DeserializeInner>::DeserType<'static>>> {
+        Ok(MemCase::encase(Self::deserialize_eps(backend)?))
+    }
+
+    /// ε-copy deserialize a structure of this type from a [`bytes::Bytes`]
+    /// buffer.
+    ///
+    /// As with [`Deserialize::deserialize_eps`], the content of the buffer
+    /// must be aligned as required by the type; buffers received from the
+    /// network or sliced at arbitrary offsets might not be, in which case an
+    /// [alignment error](`Error::AlignmentError`) is returned.
+    #[cfg(feature = "bytes")]
+    fn deserialize_eps_bytes(backend: &bytes::Bytes) -> Result<Self::DeserType<'_>> {
+        Self::deserialize_eps(backend)
+    }
+
+    /// ε-copy deserialize a structure of this type from a [`bytes::Bytes`]
+    /// buffer, returning a [`MemCase`] containing the data structure and the
+    /// buffer, which is kept alive by the [`Bytes`](MemBackend::Bytes)
+    /// backend.
+    ///
+    /// The same alignment requirements of [`Deserialize::deserialize_eps_bytes`]
+    /// apply.
+    #[cfg(feature = "bytes")]
+    fn from_bytes<'a>(
+        backend: bytes::Bytes,
+    ) -> Result<MemCase<<Self as DeserializeInner>::DeserType<'a>>> {
+        let mut uninit: MaybeUninit<MemCase<<Self as DeserializeInner>::DeserType<'_>>> =
+            MaybeUninit::uninit();
+        let ptr = uninit.as_mut_ptr();
+
+        // store the backend inside the MemCase; the content of a Bytes
+        // buffer does not move when the buffer is moved
+        unsafe {
+            addr_of_mut!((*ptr).1).write(MemBackend::Bytes(BytesBackend(backend)));
+        }
+        let bytes = unsafe { (*ptr).1.as_ref().unwrap() };
+        // deserialize the data structure
+        let s = match Self::deserialize_eps(bytes) {
+            Ok(s) => s,
+            Err(err) => {
+                // release the buffer
+                unsafe { addr_of_mut!((*ptr).1).drop_in_place() };
+                return Err(err);
+            }
+        };
+        // write the deserialized struct in the MemCase
+        unsafe {
+            addr_of_mut!((*ptr).0).write(s);
+        }
+        // finish init
+        Ok(unsafe { uninit.assume_init() })
+    }
 
     /// Commodity method to fully deserialize from a file.
     fn load_full(path: impl AsRef<Path>) -> Result<Self> {
@@ -64,16 +244,24 @@ pub trait Deserialize: TypeHash + ReprHash + DeserializeInner {
     /// returning a [`MemCase`] containing the data structure and the
     /// memory. Excess bytes are zeroed out.
     ///
-    /// The allocated memory will have [`MemoryAlignment`] as alignment: types with
-    /// a higher alignment requirement will cause an [alignment error](`Error::AlignmentError`).
+    /// The allocated memory will have [`MemoryAlignment`] as alignment. Types
+    /// with a higher alignment requirement are loaded instead into an
+    /// anonymous `mmap()`-allocated region, as in [`Deserialize::load_mmap`],
+    /// which is aligned to the page size; types requiring an alignment larger
+    /// than the page size will cause an [alignment error](`Error::AlignmentError`).
     fn load_mem<'a>(
         path: impl AsRef<Path>,
     ) -> anyhow::Result<MemCase<<Self as DeserializeInner>::DeserType<'a>>> {
         let align_to = align_of::<MemoryAlignment>();
         if align_of::<Self>() > align_to {
-            return Err(Error::AlignmentError.into());
+            if align_of::<Self>() > mmap_rs::MmapOptions::page_size() {
+                return Err(Error::AlignmentError.into());
+            }
+            // The memory is released by unmapping it, so there is no
+            // layout to match on drop
+            return Self::load_mmap(path, Flags::empty());
         }
-        let file_len = path.as_ref().metadata()?.len() as usize;
+        let file_len = usize_from_u64(path.as_ref().metadata()?.len())?;
         let mut file = std::fs::File::open(path)?;
         // Round up to u128 size
         let capacity = file_len + crate::pad_align_to(file_len, align_to);
@@ -132,7 +320,7 @@ pub trait Deserialize: TypeHash + ReprHash + DeserializeInner {
         path: impl AsRef<Path>,
         flags: Flags,
     ) -> anyhow::Result<MemCase<<Self as DeserializeInner>::DeserType<'a>>> {
-        let file_len = path.as_ref().metadata()?.len() as usize;
+        let file_len = usize_from_u64(path.as_ref().metadata()?.len())?;
         let mut file = std::fs::File::open(path)?;
         let capacity = file_len + crate::pad_align_to(file_len, 16);
 
@@ -176,7 +364,7 @@ pub trait Deserialize: TypeHash + ReprHash + DeserializeInner {
         path: impl AsRef<Path>,
         flags: Flags,
     ) -> anyhow::Result<MemCase<<Self as DeserializeInner>::DeserType<'a>>> {
-        let file_len = path.as_ref().metadata()?.len();
+        let file_len = usize_from_u64(path.as_ref().metadata()?.len())?;
         let file = std::fs::File::open(path)?;
 
         let mut uninit: MaybeUninit<MemCase<<Self as DeserializeInner>::DeserType<'_>>> =
@@ -184,7 +372,7 @@ pub trait Deserialize: TypeHash + ReprHash + DeserializeInner {
         let ptr = uninit.as_mut_ptr();
 
         let mmap = unsafe {
-            mmap_rs::MmapOptions::new(file_len as _)?
+            mmap_rs::MmapOptions::new(file_len)?
                 .with_flags(flags.mmap_flags())
                 .with_file(&file, 0)
                 .map()?
@@ -205,6 +393,47 @@ pub trait Deserialize: TypeHash + ReprHash + DeserializeInner {
         // finish init
         Ok(unsafe { uninit.assume_init() })
     }
+
+    /// Memory map a file in read-write mode and return a [`MemCaseMut`]
+    /// giving mutable access to the zero-copy structure it contains.
+    ///
+    /// Modifications are performed directly on the file: they will be
+    /// written back when the [`MemCaseMut`] is [flushed](MemCaseMut::flush) or
+    /// dropped.
+    ///
+    /// Header, alignment, and length are checked, but this method is
+    /// inherently unsafe in the same way as all mutable memory mappings: if
+    /// the file is modified or truncated externally while it is mapped, the
+    /// behavior is undefined.
+    fn mmap_mut(path: impl AsRef<Path>, flags: Flags) -> anyhow::Result<MemCaseMut<Self>>
+    where
+        Self: ZeroCopy,
+    {
+        let file_len = usize_from_u64(path.as_ref().metadata()?.len())?;
+        let file = std::fs::OpenOptions::new()
+            .read(true)
+            .write(true)
+            .open(path)?;
+
+        let mmap = unsafe {
+            mmap_rs::MmapOptions::new(file_len)?
+                .with_flags(flags.mmap_flags() | mmap_rs::MmapFlags::SHARED)
+                .with_file(&file, 0)
+                .map_mut()?
+        };
+
+        let mut backend = SliceWithPos::new(&mmap);
+        check_header::<Self>(&mut backend)?;
+        // This also checks that the structure is aligned in memory
+        backend.align::<Self>()?;
+        if backend.data.len() < core::mem::size_of::<Self>() {
+            return Err(Error::unexpected_eof().into());
+        }
+        let offset = backend.pos;
+
+        // SAFETY: we checked alignment and length, and Self is zero-copy
+        Ok(unsafe { MemCaseMut::new(mmap, offset) })
+    }
 }
 
 /// Inner trait to implement deserialization of a type. This trait exists
@@ -233,34 +462,56 @@ pub trait DeserializeInner: Sized {
 /// [`DeserializeInner::_deserialize_full_inner`] or
 /// [`DeserializeInner::_deserialize_eps_inner`].
 impl<T: TypeHash + ReprHash + DeserializeInner> Deserialize for T {
-    fn deserialize_full(backend: &mut impl ReadNoStd) -> Result<Self> {
-        let mut backend = ReaderWithPos::new(backend);
-        check_header::<Self>(&mut backend)?;
-        Self::_deserialize_full_inner(&mut backend)
+    fn deserialize_full(backend: &mut (impl ReadNoStd + ?Sized)) -> Result<Self> {
+        Self::deserialize_full_with_config(backend, DeserConfig::default())
+    }
+
+    fn deserialize_full_with_config(
+        backend: &mut (impl ReadNoStd + ?Sized),
+        config: DeserConfig,
+    ) -> Result<Self> {
+        let mut backend = ReaderWithPos::with_config(backend, config);
+        let extensions = check_header_ext::<Self>(&mut backend)?;
+        let res = Self::_deserialize_full_inner(&mut backend)?;
+        if extensions {
+            skip_extensions(&mut backend)?;
+        }
+        Ok(res)
     }
 
     fn deserialize_eps(backend: &'_ [u8]) -> Result<Self::DeserType<'_>> {
+        Ok(Self::deserialize_eps_with_pos(backend)?.0)
+    }
+
+    fn deserialize_eps_with_pos(backend: &'_ [u8]) -> Result<(Self::DeserType<'_>, usize)> {
         let mut backend = SliceWithPos::new(backend);
-        check_header::<Self>(&mut backend)?;
-        Self::_deserialize_eps_inner(&mut backend)
+        let extensions = check_header_ext::<Self>(&mut backend)?;
+        let res = Self::_deserialize_eps_inner(&mut backend)?;
+        if extensions {
+            skip_extensions(&mut backend)?;
+        }
+        Ok((res, backend.pos))
     }
 }
 
-/// Common header check code for both ε-copy and full-copy deserialization.
+/// Read the header written by [`crate::ser::write_header`], checking
+/// magic cookie, version, and size of `usize`, and return the type hash,
+/// the representation hash, and the type name of the serialized type.
+///
+/// The type name is empty if the data was serialized with
+/// [`crate::ser::Serialize::serialize_compact`].
+///
+/// This function is useful when the type of the serialized data is not
+/// known in advance (see, e.g., [`TypeRegistry`]).
 ///
 /// Must be kept in sync with [`crate::ser::write_header`].
-pub fn check_header<T: Deserialize>(backend: &mut impl ReadWithPos) -> Result<()> {
-    let self_type_name = core::any::type_name::<T>().to_string();
-
-    let mut type_hasher = xxhash_rust::xxh3::Xxh3::new();
-    T::type_hash(&mut type_hasher);
-    let self_type_hash = type_hasher.finish();
-
-    let mut repr_hasher = xxhash_rust::xxh3::Xxh3::new();
-    let mut offset_of = 0;
-    T::repr_hash(&mut repr_hasher, &mut offset_of);
-    let self_repr_hash = repr_hasher.finish();
+pub fn read_header(backend: &mut impl ReadWithPos) -> Result<(u64, u64, String)> {
+    Ok(read_header_ext(backend)?.0)
+}
 
+/// Read the header like [`read_header`], returning also whether the payload
+/// is followed by an extension block.
+fn read_header_ext(backend: &mut impl ReadWithPos) -> Result<((u64, u64, String), bool)> {
     let magic = u64::_deserialize_full_inner(backend)?;
     match magic {
         MAGIC => Ok(()),
@@ -276,11 +527,11 @@ pub fn check_header<T: Deserialize>(backend: &mut impl ReadWithPos) -> Result<()
     if minor > VERSION.1 {
         return Err(Error::MinorVersionMismatch(minor));
     };
+    let extensions = minor >= EXTENSIONS_MINOR_VERSION;
 
     let usize_size = u8::_deserialize_full_inner(backend)?;
     let usize_size = usize_size as usize;
-    let native_usize_size = core::mem::size_of::<usize>();
-    if usize_size != native_usize_size {
+    if usize_size != USIZE_SIZE {
         return Err(Error::UsizeSizeMismatch(usize_size));
     };
 
@@ -288,6 +539,78 @@ pub fn check_header<T: Deserialize>(backend: &mut impl ReadWithPos) -> Result<()
     let ser_repr_hash = u64::_deserialize_full_inner(backend)?;
     let ser_type_name = String::_deserialize_full_inner(backend)?;
 
+    Ok(((ser_type_hash, ser_repr_hash, ser_type_name), extensions))
+}
+
+/// Skip the extension block written by
+/// [`crate::ser::Serialize::serialize_with_extensions`].
+fn skip_extensions(backend: &mut impl ReadWithPos) -> Result<()> {
+    let mut len = usize::_deserialize_full_inner(backend)?;
+    let mut buffer = [0_u8; 64];
+    while len > 0 {
+        let chunk = len.min(buffer.len());
+        backend.read_exact(&mut buffer[..chunk])?;
+        len -= chunk;
+    }
+    Ok(())
+}
+
+/// Return the type hash and the representation hash of a type, as
+/// written in the header by [`crate::ser::write_header`].
+pub fn type_and_repr_hash<T: TypeHash + ReprHash + ?Sized>() -> (u64, u64) {
+    let mut type_hasher = xxhash_rust::xxh3::Xxh3::new();
+    T::type_hash(&mut type_hasher);
+
+    let mut repr_hasher = xxhash_rust::xxh3::Xxh3::new();
+    let mut offset_of = 0;
+    T::repr_hash_isolated(&mut repr_hasher, &mut offset_of);
+
+    (type_hasher.finish(), repr_hasher.finish())
+}
+
+/// Return whether a byte slice is suitably aligned for ε-copy deserializing
+/// data containing zero-copy instances of `T`.
+///
+/// The check is the same performed at run time by ε-copy deserialization,
+/// which returns an [alignment error](Error::AlignmentError) if it fails,
+/// so it can be used to realign a buffer in advance. Note that `T` must be
+/// the zero-copy type with the largest [`MaxSizeOf::max_size_of`] in the
+/// serialized data: for example, for a `Vec<u64>` you should pass `u64`.
+pub fn is_aligned_for<T: MaxSizeOf>(bytes: &[u8]) -> bool {
+    crate::pad_align_to(bytes.as_ptr() as usize, T::max_size_of()) == 0
+}
+
+/// Common header check code for both ε-copy and full-copy deserialization.
+///
+/// This function is useful to implement custom framing (see
+/// [`crate::ser::Serialize::serialize_no_header`]).
+///
+/// Must be kept in sync with [`crate::ser::write_header`].
+pub fn check_header<T: Deserialize>(backend: &mut impl ReadWithPos) -> Result<()> {
+    check_header_ext::<T>(backend).map(|_| ())
+}
+
+/// Check that a header prefix, written by [`crate::ser::write_header`], is
+/// compatible with `T`, returning the same errors as deserialization.
+///
+/// This function makes it possible to peek at the header of a non-seekable
+/// stream (e.g., using [`BufRead::fill_buf`](std::io::BufRead::fill_buf))
+/// and decide whether to deserialize without consuming any data. The prefix
+/// must contain the whole header, including the serialized type name, as
+/// otherwise a [read error](Error::ReadError) is returned; additional bytes
+/// are ignored.
+pub fn check_compatible<T: Deserialize>(header_bytes: &[u8]) -> Result<()> {
+    check_header::<T>(&mut SliceWithPos::new(header_bytes))
+}
+
+/// Check the header like [`check_header`], returning also whether the payload
+/// is followed by an extension block.
+fn check_header_ext<T: Deserialize>(backend: &mut impl ReadWithPos) -> Result<bool> {
+    let self_type_name = core::any::type_name::<T>().to_string();
+    let (self_type_hash, self_repr_hash) = type_and_repr_hash::<T>();
+
+    let ((ser_type_hash, ser_repr_hash, ser_type_name), extensions) = read_header_ext(backend)?;
+
     if ser_type_hash != self_type_hash {
         return Err(Error::WrongTypeHash {
             got_type_name: self_type_name,
@@ -305,7 +628,33 @@ pub fn check_header<T: Deserialize>(backend: &mut impl ReadWithPos) -> Result<()
         });
     }
 
-    Ok(())
+    Ok(extensions)
+}
+
+/// ε-copy deserialize a string slice from the given backend.
+///
+/// [`String`] and [`Box<str>`] have the same serialized representation, and
+/// both ε-copy deserialize to a `&str`: this convenience function accepts data
+/// serialized from either type, so that the caller does not need to choose one
+/// of the two as deserialization type.
+pub fn deserialize_str_eps(backend: &'_ [u8]) -> Result<&'_ str> {
+    let mut backend = SliceWithPos::new(backend);
+    let (ser_type_hash, ser_repr_hash, ser_type_name) = read_header(&mut backend)?;
+    let (string_type_hash, string_repr_hash) = type_and_repr_hash::<String>();
+    let (box_str_type_hash, box_str_repr_hash) = type_and_repr_hash::<Box<str>>();
+
+    if ser_type_hash == string_type_hash && ser_repr_hash == string_repr_hash {
+        String::_deserialize_eps_inner(&mut backend)
+    } else if ser_type_hash == box_str_type_hash && ser_repr_hash == box_str_repr_hash {
+        <Box<str>>::_deserialize_eps_inner(&mut backend)
+    } else {
+        Err(Error::WrongTypeHash {
+            got_type_name: core::any::type_name::<String>().to_string(),
+            got: string_type_hash,
+            expected_type_name: ser_type_name,
+            expected: ser_type_hash,
+        })
+    }
 }
 
 /// A helper trait that makes it possible to implement differently
@@ -322,14 +671,61 @@ pub trait DeserializeHelper<T: CopySelector> {
     ) -> Result<Self::DeserType<'a>>;
 }
 
+/// A deserialization mode, that is, [`Full`] or [`Eps`].
+///
+/// Modes make it possible to choose between full-copy and ε-copy
+/// deserialization at the type level (see
+/// [`Deserialize::deserialize_with_mode`]).
+#[sealed]
+pub trait DeserMode {
+    /// The type returned by deserialization in this mode.
+    type Output<'a, T: DeserializeInner>;
+
+    /// Deserialize a structure of type `T` from the given backend in this mode.
+    fn deserialize<T: Deserialize>(backend: &'_ [u8]) -> Result<Self::Output<'_, T>>;
+}
+
+/// A [`DeserMode`] specifying full-copy deserialization, which returns a `T`.
+pub struct Full {}
+
+#[sealed]
+impl DeserMode for Full {
+    type Output<'a, T: DeserializeInner> = T;
+
+    fn deserialize<T: Deserialize>(backend: &'_ [u8]) -> Result<Self::Output<'_, T>> {
+        T::deserialize_full(&mut SliceWithPos::new(backend))
+    }
+}
+
+/// A [`DeserMode`] specifying ε-copy deserialization, which returns a
+/// [`DeserType<T>`](DeserType).
+pub struct Eps {}
+
+#[sealed]
+impl DeserMode for Eps {
+    type Output<'a, T: DeserializeInner> = DeserType<'a, T>;
+
+    fn deserialize<T: Deserialize>(backend: &'_ [u8]) -> Result<Self::Output<'_, T>> {
+        T::deserialize_eps(backend)
+    }
+}
+
 #[derive(thiserror::Error, Debug)]
 /// Errors that can happen during deserialization.
 pub enum Error {
     #[error("Error reading stats for file during ε-serde deserialization: {0}")]
     /// [`Deserialize::load_full`] could not open the provided file.
     FileOpenError(std::io::Error),
+    #[cfg(feature = "std")]
+    #[error("Read error during ε-serde deserialization: {0}")]
+    /// The underlying reader returned an error, or the data ended
+    /// unexpectedly (in which case the error kind is
+    /// [`UnexpectedEof`](std::io::ErrorKind::UnexpectedEof)).
+    ReadError(#[source] std::io::Error),
+    #[cfg(not(feature = "std"))]
     #[error("Read error during ε-serde deserialization")]
-    /// The underlying reader returned an error.
+    /// The underlying reader returned an error, or the data ended
+    /// unexpectedly.
     ReadError,
     /// The file is from ε-serde but the endianess is wrong.
     #[cfg_attr(
@@ -351,11 +747,13 @@ pub enum Error {
     /// The file was serialized with a compatible, but too new version of ε-serde
     /// so we might be missing features.
     MinorVersionMismatch(u16),
-    #[error("The file was serialized on an architecture where a usize has size {0}, but on the current architecture it has size {}.", core::mem::size_of::<usize>())]
+    #[error("The file was serialized with usize values of size {0}, but usize values of size {} were expected.", USIZE_SIZE)]
     /// The the `pointer_width` of the serialized file is different from the
     /// `pointer_width` of the current architecture.
     /// For example, the file was serialized on a 64-bit machine and we are trying to
-    /// deserialize it on a 32-bit machine.
+    /// deserialize it on a 32-bit machine. This error also happens if exactly
+    /// one of the serializing and the deserializing code has the
+    /// `portable-usize` feature enabled.
     UsizeSizeMismatch(usize),
     #[error("Wrong magic cookie 0x{0:016x}. The byte stream does not come from ε-serde.")]
     /// The magic coookie is wrong. The byte sequence does not come from ε-serde.
@@ -391,4 +789,94 @@ The serialized type is '{expected_type_name}' and the deserialized type is '{got
         expected: u64,
         got: u64,
     },
+    #[error(
+        r#"No type registered for type hash 0x{type_hash:016x}.
+The serialized type is '{type_name}'."#
+    )]
+    /// The type hash read from the header has not been registered
+    /// in a [`TypeRegistry`].
+    UnregisteredType { type_name: String, type_hash: u64 },
+    #[error("The declared length {0} exceeds the available data.")]
+    /// The length of a sequence is too large for the available data, or
+    /// for the address space. Most likely, the data is corrupted.
+    LengthTooLarge(usize),
+    #[error("Length mismatch: expected {expected}, got {got}")]
+    /// Two sequences that must have the same length (e.g., the keys and the
+    /// values of a map) have different lengths. Most likely, the data is
+    /// corrupted.
+    LengthMismatch { expected: usize, got: usize },
+    #[error("The field at offset {offset} of size {size} exceeds the data length {data_len}")]
+    /// A field described by a schema (e.g., in a
+    /// [`SelfDescribing`](crate::utils::SelfDescribing)) is not within the
+    /// data it describes. Most likely, the data is corrupted.
+    InvalidFieldRange {
+        offset: usize,
+        size: usize,
+        data_len: usize,
+    },
+    #[error("Invalid offsets: they must be nonempty, start from zero, be nondecreasing, and end at the end of the data")]
+    /// The offsets delimiting the elements of a flat sequence (e.g., the
+    /// rows of a [`Jagged`](crate::utils::Jagged) or the elements of an
+    /// [`IndexedVec`](crate::utils::IndexedVec)) are empty, do not start
+    /// from zero, decrease, or do not end at the end of the data. Most
+    /// likely, the data is corrupted.
+    InvalidOffsets,
+    #[error("The value {0} does not fit in a usize on the current architecture.")]
+    /// A 64-bit value (e.g., the length of a file) cannot be represented by a
+    /// `usize` on the current architecture.
+    UsizeValueOverflow(u64),
+    #[error("Invalid boolean value: {0}")]
+    /// A byte representing a boolean is neither zero nor one. This error is
+    /// returned only by full-copy deserialization of arrays and vectors of
+    /// booleans when the `validate` feature is enabled.
+    InvalidBool(u8),
+    #[error("Invalid UTF-8 data: {0}")]
+    /// The data of a string is not valid UTF-8.
+    InvalidUtf8(core::str::Utf8Error),
+    #[error("Invalid C string: interior nul byte or missing terminating nul")]
+    /// A C string contained an interior nul byte, or it was not terminated
+    /// by a nul byte.
+    InvalidCString,
+    #[error("Invalid zero value for a nonzero type")]
+    /// A nonzero type was deserialized from a zero value. For arrays and
+    /// vectors of nonzero types, this error is returned only by full-copy
+    /// deserialization when the `validate` feature is enabled.
+    InvalidNonZero,
+    #[error("Invalid character value: 0x{0:x}")]
+    /// A value representing a character is not a valid Unicode scalar value.
+    InvalidChar(u32),
+    #[error("Timestamp out of range: {secs} seconds and {nanos} nanoseconds")]
+    /// A timestamp (e.g., of a `chrono::NaiveDateTime`) is out of the range
+    /// of representable dates.
+    InvalidTimestamp { secs: i64, nanos: u32 },
+    #[error("Invalid duration: {secs} seconds and {nanos} nanoseconds")]
+    /// The number of nanoseconds of a [`Duration`](core::time::Duration) is
+    /// not smaller than one billion.
+    InvalidDuration { secs: u64, nanos: u32 },
+    #[error(
+        "The bit length {bit_len} is inconsistent with the number of storage words {num_words}"
+    )]
+    /// The number of storage words of a bit vector (e.g., a
+    /// `bitvec::vec::BitVec`) is not the one necessary to store its bits.
+    InvalidBitLength { bit_len: usize, num_words: usize },
+    #[error("Cannot deserialize a value of the uninhabited type {0}")]
+    /// The serialized data contains a value of a type that has no values,
+    /// such as [`Infallible`](core::convert::Infallible). This error can
+    /// only happen with corrupted data or with a wrong type.
+    Uninhabited(&'static str),
+    #[error("The serialized length {len} exceeds the capacity {capacity}")]
+    /// The serialized length of a fixed-capacity container (e.g., an
+    /// `arrayvec::ArrayVec`) exceeds its capacity.
+    CapacityExceeded { len: usize, capacity: usize },
+}
+
+impl Error {
+    /// Return a [read error](Error::ReadError) signalling that the data
+    /// ended unexpectedly.
+    pub(crate) fn unexpected_eof() -> Self {
+        #[cfg(feature = "std")]
+        return Error::ReadError(std::io::ErrorKind::UnexpectedEof.into());
+        #[cfg(not(feature = "std"))]
+        return Error::ReadError;
+    }
 }
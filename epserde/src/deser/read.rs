@@ -29,10 +29,10 @@ pub trait ReadNoStd {
 #[cfg(feature = "std")]
 use std::io::Read;
 #[cfg(feature = "std")]
-impl<W: Read> ReadNoStd for W {
+impl<W: Read + ?Sized> ReadNoStd for W {
     #[inline(always)]
     fn read_exact(&mut self, buf: &mut [u8]) -> deser::Result<()> {
-        Read::read_exact(self, buf).map_err(|_| deser::Error::ReadError)
+        Read::read_exact(self, buf).map_err(deser::Error::ReadError)
     }
 }
 
@@ -46,4 +46,24 @@ pub trait ReadWithPos: ReadNoStd + Sized {
 
     /// Pad the cursor to the next multiple of [`MaxSizeOf::max_size_of`] 'T'.
     fn align<T: MaxSizeOf>(&mut self) -> deser::Result<()>;
+
+    /// Return the [configuration](deser::DeserConfig) of deserialization.
+    ///
+    /// The default implementation returns the default configuration.
+    #[inline(always)]
+    fn config(&self) -> deser::DeserConfig {
+        deser::DeserConfig::default()
+    }
+
+    /// Fully deserialize a field with the given name.
+    ///
+    /// This method is the reading counterpart of
+    /// [`WriteWithNames::write`](crate::ser::WriteWithNames::write), and it is
+    /// used by derived implementations to read the fields of structures. The
+    /// default implementation ignores the name, but readers can override it
+    /// to collect information about fields.
+    #[inline(always)]
+    fn read_field<T: DeserializeInner>(&mut self, _name: &str) -> deser::Result<T> {
+        T::_deserialize_full_inner(self)
+    }
 }
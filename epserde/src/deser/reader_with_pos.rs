@@ -13,22 +13,35 @@ use mem_dbg::{MemDbg, MemSize};
 /// A wrapper for a [`ReadNoStd`] that implements [`ReadWithPos`]
 /// by keeping track of the current position.
 #[derive(Debug, MemDbg, MemSize)]
-pub struct ReaderWithPos<'a, F: ReadNoStd> {
+pub struct ReaderWithPos<'a, F: ReadNoStd + ?Sized> {
     /// What we actually readfrom
     backend: &'a mut F,
     /// How many bytes we have read from the start
     pos: usize,
+    /// The configuration of deserialization
+    config: deser::DeserConfig,
 }
 
-impl<'a, F: ReadNoStd> ReaderWithPos<'a, F> {
+impl<'a, F: ReadNoStd + ?Sized> ReaderWithPos<'a, F> {
     #[inline(always)]
     /// Create a new [`ReadWithPos`] on top of a generic [`ReadNoStd`].
     pub fn new(backend: &'a mut F) -> Self {
-        Self { backend, pos: 0 }
+        Self::with_config(backend, deser::DeserConfig::default())
+    }
+
+    #[inline(always)]
+    /// Create a new [`ReadWithPos`] on top of a generic [`ReadNoStd`] with
+    /// the given [configuration](deser::DeserConfig).
+    pub fn with_config(backend: &'a mut F, config: deser::DeserConfig) -> Self {
+        Self {
+            backend,
+            pos: 0,
+            config,
+        }
     }
 }
 
-impl<'a, F: ReadNoStd> ReadNoStd for ReaderWithPos<'a, F> {
+impl<'a, F: ReadNoStd + ?Sized> ReadNoStd for ReaderWithPos<'a, F> {
     fn read_exact(&mut self, buf: &mut [u8]) -> deser::Result<()> {
         self.backend.read_exact(buf)?;
         self.pos += buf.len();
@@ -36,15 +49,24 @@ impl<'a, F: ReadNoStd> ReadNoStd for ReaderWithPos<'a, F> {
     }
 }
 
-impl<'a, F: ReadNoStd> ReadWithPos for ReaderWithPos<'a, F> {
+impl<'a, F: ReadNoStd + ?Sized> ReadWithPos for ReaderWithPos<'a, F> {
     fn pos(&self) -> usize {
         self.pos
     }
 
+    fn config(&self) -> deser::DeserConfig {
+        self.config
+    }
+
     fn align<T: MaxSizeOf>(&mut self) -> deser::Result<()> {
-        // Skip bytes as needed
-        let padding = crate::pad_align_to(self.pos, T::max_size_of());
-        self.read_exact(&mut vec![0; padding])?;
+        // Skip bytes as needed, using a stack buffer to avoid allocations
+        let mut padding = crate::pad_align_to(self.pos, T::max_size_of());
+        let mut buf = [0_u8; 64];
+        while padding > 0 {
+            let len = padding.min(buf.len());
+            self.read_exact(&mut buf[..len])?;
+            padding -= len;
+        }
         // No alignment check, we are fully deserializing
         Ok(())
     }
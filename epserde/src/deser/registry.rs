@@ -0,0 +1,141 @@
+/*
+ * SPDX-FileCopyrightText: 2023 Inria
+ * SPDX-FileCopyrightText: 2023 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+/*!
+
+Deserialization of trait objects.
+
+A [`TypeRegistry`] maps type hashes to deserialization functions, making it
+possible to deserialize data whose concrete type is not known in advance into
+a trait object.
+
+*/
+
+use super::*;
+use std::collections::HashMap;
+
+/// A deserialization function for a registered type: it fully deserializes
+/// the type and converts it into a boxed trait object.
+type DeserDyn<D> = Box<dyn Fn(&mut SliceWithPos<'_>) -> Result<Box<D>> + Send + Sync>;
+
+/// A registry of concrete types that can be deserialized into a trait
+/// object `D` (e.g., `dyn MyTrait`).
+///
+/// Concrete types are [registered](TypeRegistry::register) (usually at
+/// startup) together with a function converting them into a boxed trait
+/// object. [`TypeRegistry::deserialize_dyn`] reads the header of the
+/// serialized data, looks up the type hash, checks the representation hash, and
+/// performs full-copy deserialization of the registered type.
+///
+/// ```rust
+/// use epserde::prelude::*;
+/// use maligned::A16;
+///
+/// trait Shape {
+///     fn area(&self) -> f64;
+/// }
+///
+/// #[derive(Epserde, Debug, Clone, Copy)]
+/// #[repr(C)]
+/// #[zero_copy]
+/// struct Square {
+///     side: f64,
+/// }
+///
+/// impl Shape for Square {
+///     fn area(&self) -> f64 {
+///         self.side * self.side
+///     }
+/// }
+///
+/// let mut registry = TypeRegistry::<dyn Shape>::new();
+/// registry.register::<Square>(|s| Box::new(s));
+///
+/// let mut cursor = <AlignedCursor<A16>>::new();
+/// Square { side: 2.0 }.serialize(&mut cursor).unwrap();
+/// let shape = registry.deserialize_dyn(cursor.as_bytes()).unwrap();
+/// assert_eq!(shape.area(), 4.0);
+/// ```
+pub struct TypeRegistry<D: ?Sized> {
+    /// Map from type hashes to representation hashes and deserialization functions.
+    types: HashMap<u64, (u64, DeserDyn<D>)>,
+}
+
+impl<D: ?Sized> core::fmt::Debug for TypeRegistry<D> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("TypeRegistry")
+            .field("types", &self.types.keys().collect::<Vec<_>>())
+            .finish()
+    }
+}
+
+impl<D: ?Sized> Default for TypeRegistry<D> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<D: ?Sized> TypeRegistry<D> {
+    /// Create a new empty registry.
+    pub fn new() -> Self {
+        Self {
+            types: HashMap::new(),
+        }
+    }
+
+    /// Register the type `T`, using `into_dyn` to convert deserialized
+    /// instances into boxed trait objects.
+    ///
+    /// Registering twice a type replaces the previous registration.
+    pub fn register<T: Deserialize + 'static>(&mut self, into_dyn: fn(T) -> Box<D>) -> &mut Self
+    where
+        D: 'static,
+    {
+        let (type_hash, repr_hash) = type_and_repr_hash::<T>();
+        self.types.insert(
+            type_hash,
+            (
+                repr_hash,
+                Box::new(move |backend: &mut SliceWithPos<'_>| {
+                    Ok(into_dyn(T::_deserialize_full_inner(backend)?))
+                }),
+            ),
+        );
+        self
+    }
+
+    /// Return whether a type is registered.
+    pub fn is_registered<T: TypeHash + ReprHash>(&self) -> bool {
+        self.types.contains_key(&type_and_repr_hash::<T>().0)
+    }
+
+    /// Fully deserialize a trait object from the given bytes.
+    ///
+    /// The concrete type is determined by the type hash in the header,
+    /// which must correspond to a [registered](TypeRegistry::register) type;
+    /// otherwise, [`Error::UnregisteredType`] is returned.
+    pub fn deserialize_dyn(&self, backend: &[u8]) -> Result<Box<D>> {
+        let mut backend = SliceWithPos::new(backend);
+        let (type_hash, repr_hash, type_name) = read_header(&mut backend)?;
+        let (self_repr_hash, deser) =
+            self.types
+                .get(&type_hash)
+                .ok_or_else(|| Error::UnregisteredType {
+                    type_name: type_name.clone(),
+                    type_hash,
+                })?;
+        if repr_hash != *self_repr_hash {
+            return Err(Error::WrongTypeReprHash {
+                got_type_name: type_name.clone(),
+                got: *self_repr_hash,
+                expected_type_name: type_name,
+                expected: repr_hash,
+            });
+        }
+        deser(&mut backend)
+    }
+}
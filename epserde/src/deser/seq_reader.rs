@@ -0,0 +1,88 @@
+/*
+ * SPDX-FileCopyrightText: 2023 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+use crate::prelude::*;
+
+use super::check_header;
+use core::marker::PhantomData;
+
+/// An iterator ε-copy deserializing one at a time the elements of a
+/// serialized vector of deep-copy elements.
+///
+/// ε-copy deserializing a `Vec<T>`, when `T` is deep-copy, deserializes all
+/// elements into a vector. A [`SeqReader`] instead deserializes elements
+/// lazily, so it is possible to filter them or stop early without
+/// deserializing the whole vector. Vectors of zero-copy elements do not need
+/// this adapter, as they are ε-copy deserialized as slices.
+///
+/// The iterator returns a [`Result`](deser::Result) for each element; after
+/// an error, it returns no further elements.
+///
+/// ```rust
+/// use epserde::prelude::*;
+/// use epserde::deser::SeqReader;
+/// use maligned::A16;
+///
+/// let v = (0..1000).map(|i| i.to_string()).collect::<Vec<_>>();
+/// let mut cursor = <AlignedCursor<A16>>::new();
+/// v.serialize(&mut cursor).unwrap();
+///
+/// let mut reader = SeqReader::<String>::new(cursor.as_bytes()).unwrap();
+/// assert_eq!(reader.len(), 1000);
+/// assert_eq!(reader.next().unwrap().unwrap(), "0");
+/// assert_eq!(reader.nth(9).unwrap().unwrap(), "10");
+/// ```
+#[derive(Debug, Clone)]
+pub struct SeqReader<'a, T> {
+    backend: SliceWithPos<'a>,
+    remaining: usize,
+    _marker: PhantomData<T>,
+}
+
+impl<'a, T: DeepCopy + DeserializeInner> SeqReader<'a, T> {
+    /// Create a new [`SeqReader`] on the elements of a `Vec<T>` serialized
+    /// in the given backend.
+    ///
+    /// The header is checked as in [`Deserialize::deserialize_eps`], using
+    /// `Vec<T>` as type.
+    pub fn new(backend: &'a [u8]) -> deser::Result<Self>
+    where
+        Vec<T>: Deserialize,
+    {
+        let mut backend = SliceWithPos::new(backend);
+        check_header::<Vec<T>>(&mut backend)?;
+        let remaining = usize::_deserialize_full_inner(&mut backend)?;
+        Ok(Self {
+            backend,
+            remaining,
+            _marker: PhantomData,
+        })
+    }
+}
+
+impl<'a, T: DeepCopy + DeserializeInner> Iterator for SeqReader<'a, T> {
+    type Item = deser::Result<DeserType<'a, T>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+        self.remaining -= 1;
+        let res = T::_deserialize_eps_inner(&mut self.backend);
+        if res.is_err() {
+            self.remaining = 0;
+        }
+        Some(res)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<'a, T: DeepCopy + DeserializeInner> ExactSizeIterator for SeqReader<'a, T> {}
+
+impl<'a, T: DeepCopy + DeserializeInner> core::iter::FusedIterator for SeqReader<'a, T> {}
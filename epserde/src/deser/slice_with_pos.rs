@@ -35,7 +35,7 @@ impl<'a> ReadNoStd for SliceWithPos<'a> {
     fn read_exact(&mut self, buf: &mut [u8]) -> deser::Result<()> {
         let len = buf.len();
         if len > self.data.len() {
-            return Err(Error::ReadError);
+            return Err(Error::unexpected_eof());
         }
         buf.copy_from_slice(&self.data[..len]);
         self.data = &self.data[len..];
@@ -56,6 +56,9 @@ impl<'a> ReadWithPos for SliceWithPos<'a> {
     fn align<T: MaxSizeOf>(&mut self) -> deser::Result<()> {
         // Skip bytes as needed
         let padding = crate::pad_align_to(self.pos, T::max_size_of());
+        if padding > self.data.len() {
+            return Err(Error::unexpected_eof());
+        }
         self.skip(padding);
         // Check that the ptr is indeed aligned
         if self.data.as_ptr() as usize % T::max_size_of() != 0 {
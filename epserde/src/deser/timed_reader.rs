@@ -0,0 +1,79 @@
+/*
+ * SPDX-FileCopyrightText: 2023 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+use crate::prelude::*;
+
+use super::ReadNoStd;
+use std::time::{Duration, Instant};
+
+/// A wrapper for a [`ReadWithPos`] that records how long it takes to
+/// deserialize each top-level field, that is, each field of the structure
+/// being deserialized.
+///
+/// Instances are used by [`Deserialize::deserialize_full_timed`]. Timings are
+/// recorded by [`ReadWithPos::read_field`], so only fields of structures
+/// with a derived implementation are timed.
+#[derive(Debug)]
+pub struct TimedReader<R: ReadWithPos> {
+    /// What we actually read from
+    backend: R,
+    /// The current nesting level of fields
+    depth: usize,
+    /// The timings of top-level fields
+    timings: Vec<(String, Duration)>,
+}
+
+impl<R: ReadWithPos> TimedReader<R> {
+    /// Create a new [`TimedReader`] on top of a [`ReadWithPos`].
+    pub fn new(backend: R) -> Self {
+        Self {
+            backend,
+            depth: 0,
+            timings: vec![],
+        }
+    }
+
+    /// Return the timings of top-level fields, in reading order.
+    pub fn into_timings(self) -> Vec<(String, Duration)> {
+        self.timings
+    }
+}
+
+impl<R: ReadWithPos> ReadNoStd for TimedReader<R> {
+    #[inline(always)]
+    fn read_exact(&mut self, buf: &mut [u8]) -> deser::Result<()> {
+        self.backend.read_exact(buf)
+    }
+}
+
+impl<R: ReadWithPos> ReadWithPos for TimedReader<R> {
+    #[inline(always)]
+    fn pos(&self) -> usize {
+        self.backend.pos()
+    }
+
+    #[inline(always)]
+    fn align<T: MaxSizeOf>(&mut self) -> deser::Result<()> {
+        self.backend.align::<T>()
+    }
+
+    #[inline(always)]
+    fn config(&self) -> deser::DeserConfig {
+        self.backend.config()
+    }
+
+    fn read_field<T: DeserializeInner>(&mut self, name: &str) -> deser::Result<T> {
+        self.depth += 1;
+        let start = Instant::now();
+        let res = T::_deserialize_full_inner(self);
+        let elapsed = start.elapsed();
+        self.depth -= 1;
+        if self.depth == 0 {
+            self.timings.push((name.to_string(), elapsed));
+        }
+        res
+    }
+}
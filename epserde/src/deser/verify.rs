@@ -0,0 +1,231 @@
+/*
+ * SPDX-FileCopyrightText: 2023 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+/*!
+
+Verification of serialized data without deserialization.
+
+[`verify_full`] checks that some data could be full-copy deserialized as an
+instance of a given type—the header is valid, and all lengths, tags, and
+values are consistent—without building the instance: zero-copy data is
+read in chunks of bounded size and discarded, so corrupted lengths cannot
+cause large allocations.
+
+The check is performed by [`VerifyInner`], which walks the serialized data in
+parallel with [`DeserializeInner::_deserialize_full_inner`]. At this time it
+is implemented for primitive types, [`PhantomData`](core::marker::PhantomData),
+[`Option`], strings, vectors, and boxed slices.
+
+*/
+
+use super::helpers::{check_values, checked_bytes};
+use super::*;
+use core::marker::PhantomData;
+use core::num::{
+    NonZeroI128, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI8, NonZeroIsize, NonZeroU128,
+    NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU8, NonZeroUsize,
+};
+
+/// The number of bytes of zero-copy data read at a time during verification.
+const CHUNK_BYTES: usize = 1 << 12;
+
+/// Check that the data read from a backend could be full-copy deserialized
+/// as an instance of `T`, without building the instance.
+///
+/// This function [checks the header](check_header) and then delegates to
+/// [`VerifyInner::_verify_inner`].
+///
+/// ```rust
+/// use epserde::prelude::*;
+/// use maligned::A16;
+///
+/// let v = vec!["a".to_string(), "b".to_string()];
+/// let mut cursor = <AlignedCursor<A16>>::new();
+/// v.serialize(&mut cursor).unwrap();
+///
+/// cursor.set_position(0);
+/// assert!(deser::verify_full::<Vec<String>>(&mut cursor).is_ok());
+/// cursor.set_position(0);
+/// assert!(deser::verify_full::<Vec<u64>>(&mut cursor).is_err());
+/// ```
+pub fn verify_full<T: Deserialize + VerifyInner>(
+    backend: &mut (impl ReadNoStd + ?Sized),
+) -> Result<()> {
+    let mut backend = ReaderWithPos::new(backend);
+    let extensions = check_header_ext::<T>(&mut backend)?;
+    T::_verify_inner(&mut backend)?;
+    if extensions {
+        skip_extensions(&mut backend)?;
+    }
+    Ok(())
+}
+
+/// Inner trait to verify serialized data without deserializing it.
+///
+/// Implementations must read exactly the data read by
+/// [`DeserializeInner::_deserialize_full_inner`], performing the same
+/// checks, but they should avoid building the deserialized value.
+pub trait VerifyInner {
+    fn _verify_inner(backend: &mut impl ReadWithPos) -> Result<()>;
+}
+
+/// A helper trait that makes it possible to implement differently
+/// verification for [`crate::traits::ZeroCopy`] and [`crate::traits::DeepCopy`] types.
+pub trait VerifyHelper<T: CopySelector> {
+    fn _verify_inner_impl(backend: &mut impl ReadWithPos) -> Result<()>;
+}
+
+/// Read and discard `len` zero-copy structures, checking their values.
+///
+/// The backend must be already aligned.
+fn skip_zero<T: ZeroCopy>(backend: &mut impl ReadWithPos, len: usize) -> Result<()> {
+    let mut bytes = checked_bytes::<T>(len)?;
+    let size = core::mem::size_of::<T>();
+    let mut buf = vec![0; ((CHUNK_BYTES / size.max(1)).max(1) * size).min(bytes)];
+    while bytes > 0 {
+        let n = buf.len().min(bytes);
+        backend.read_exact(&mut buf[..n])?;
+        check_values::<T>(&buf[..n])?;
+        bytes -= n;
+    }
+    Ok(())
+}
+
+/// Read and discard `len` bytes, checking that they are valid UTF-8.
+fn skip_utf8(backend: &mut impl ReadWithPos, mut len: usize) -> Result<()> {
+    let mut buf = vec![0; CHUNK_BYTES.min(len)];
+    // Bytes of a character split between chunks
+    let mut carry = 0;
+    while len > 0 {
+        let n = (buf.len() - carry).min(len);
+        backend.read_exact(&mut buf[carry..carry + n])?;
+        len -= n;
+        match core::str::from_utf8(&buf[..carry + n]) {
+            Ok(_) => carry = 0,
+            Err(e) if e.error_len().is_none() && len > 0 => {
+                let valid = e.valid_up_to();
+                buf.copy_within(valid..carry + n, 0);
+                carry = carry + n - valid;
+            }
+            Err(e) => return Err(Error::InvalidUtf8(e)),
+        }
+    }
+    Ok(())
+}
+
+macro_rules! impl_verify_full {
+    ($($ty:ty),*) => {$(
+        impl VerifyInner for $ty {
+            #[inline(always)]
+            fn _verify_inner(backend: &mut impl ReadWithPos) -> Result<()> {
+                <$ty>::_deserialize_full_inner(backend).map(drop)
+            }
+        }
+    )*};
+}
+
+impl_verify_full!(
+    (),
+    bool,
+    char,
+    isize,
+    i8,
+    i16,
+    i32,
+    i64,
+    i128,
+    usize,
+    u8,
+    u16,
+    u32,
+    u64,
+    u128,
+    f32,
+    f64,
+    NonZeroIsize,
+    NonZeroI8,
+    NonZeroI16,
+    NonZeroI32,
+    NonZeroI64,
+    NonZeroI128,
+    NonZeroUsize,
+    NonZeroU8,
+    NonZeroU16,
+    NonZeroU32,
+    NonZeroU64,
+    NonZeroU128
+);
+
+impl<T: ?Sized> VerifyInner for PhantomData<T> {
+    #[inline(always)]
+    fn _verify_inner(_backend: &mut impl ReadWithPos) -> Result<()> {
+        Ok(())
+    }
+}
+
+impl<T: VerifyInner> VerifyInner for Option<T> {
+    fn _verify_inner(backend: &mut impl ReadWithPos) -> Result<()> {
+        let tag = u8::_deserialize_full_inner(backend)?;
+        match tag {
+            0 => Ok(()),
+            1 => T::_verify_inner(backend),
+            _ => Err(Error::InvalidTag(tag as usize)),
+        }
+    }
+}
+
+impl VerifyInner for String {
+    fn _verify_inner(backend: &mut impl ReadWithPos) -> Result<()> {
+        let len = usize::_deserialize_full_inner(backend)?;
+        backend.align::<u8>()?;
+        skip_utf8(backend, len)
+    }
+}
+
+impl VerifyInner for Box<str> {
+    #[inline(always)]
+    fn _verify_inner(backend: &mut impl ReadWithPos) -> Result<()> {
+        String::_verify_inner(backend)
+    }
+}
+
+impl<T: CopyType> VerifyInner for Vec<T>
+where
+    Vec<T>: VerifyHelper<<T as CopyType>::Copy>,
+{
+    #[inline(always)]
+    fn _verify_inner(backend: &mut impl ReadWithPos) -> Result<()> {
+        <Vec<T> as VerifyHelper<<T as CopyType>::Copy>>::_verify_inner_impl(backend)
+    }
+}
+
+impl<T: ZeroCopy> VerifyHelper<Zero> for Vec<T> {
+    fn _verify_inner_impl(backend: &mut impl ReadWithPos) -> Result<()> {
+        let len = usize::_deserialize_full_inner(backend)?;
+        backend.align::<T>()?;
+        skip_zero::<T>(backend, len)
+    }
+}
+
+impl<T: DeepCopy + VerifyInner> VerifyHelper<Deep> for Vec<T> {
+    fn _verify_inner_impl(backend: &mut impl ReadWithPos) -> Result<()> {
+        let len = usize::_deserialize_full_inner(backend)?;
+        for _ in 0..len {
+            T::_verify_inner(backend)?;
+        }
+        Ok(())
+    }
+}
+
+impl<T: CopyType> VerifyInner for Box<[T]>
+where
+    Vec<T>: VerifyHelper<<T as CopyType>::Copy>,
+{
+    #[inline(always)]
+    fn _verify_inner(backend: &mut impl ReadWithPos) -> Result<()> {
+        <Vec<T> as VerifyHelper<<T as CopyType>::Copy>>::_verify_inner_impl(backend)
+    }
+}
@@ -95,7 +95,12 @@ impl<T: ZeroCopy + DeserializeInner + 'static, const N: usize> DeserializeHelper
         backend.align::<T>()?;
         // SAFETY: read_exact guarantees that the array will be filled with data.
         unsafe {
-            backend.read_exact(res.assume_init_mut().align_to_mut::<u8>().1)?;
+            let bytes = core::slice::from_raw_parts_mut(
+                res.as_mut_ptr() as *mut u8,
+                core::mem::size_of::<[T; N]>(),
+            );
+            backend.read_exact(bytes)?;
+            deser::helpers::check_values::<T>(bytes)?;
             Ok(res.assume_init())
         }
     }
@@ -106,6 +111,9 @@ impl<T: ZeroCopy + DeserializeInner + 'static, const N: usize> DeserializeHelper
     ) -> deser::Result<<Self as DeserializeInner>::DeserType<'a>> {
         backend.align::<T>()?;
         let bytes = std::mem::size_of::<[T; N]>();
+        if bytes > backend.data.len() {
+            return Err(deser::Error::unexpected_eof());
+        }
         let (pre, data, after) = unsafe { backend.data[..bytes].align_to::<[T; N]>() };
         debug_assert!(pre.is_empty());
         debug_assert!(after.is_empty());
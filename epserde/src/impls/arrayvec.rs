@@ -0,0 +1,168 @@
+/*
+ * SPDX-FileCopyrightText: 2023 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+/*!
+
+Implementations for [`ArrayVec`], available with the `arrayvec` feature.
+
+An [`ArrayVec`] is serialized exactly like a [`Vec`], that is, as its
+current length followed by the elements. Full-copy deserialization stores
+the elements in an [`ArrayVec`]; ε-copy deserialization returns a slice if
+the elements are zero-copy, and a [`Vec`] of the ε-copy deserialization
+types of the elements otherwise. In both cases, if the serialized length
+exceeds the capacity, deserialization returns
+[`Error::CapacityExceeded`](crate::deser::Error::CapacityExceeded).
+
+The capacity is not part of the type hash, as it does not influence the
+serialized data.
+
+*/
+
+use crate::deser;
+use crate::deser::helpers::*;
+use crate::deser::*;
+use crate::ser;
+use crate::ser::helpers::*;
+use crate::ser::*;
+use crate::traits::*;
+use ::arrayvec::ArrayVec;
+use core::hash::Hash;
+
+/// Return an error if `len` exceeds the capacity `CAP`.
+#[inline(always)]
+fn check_capacity<const CAP: usize>(len: usize) -> deser::Result<()> {
+    if len > CAP {
+        return Err(deser::Error::CapacityExceeded { len, capacity: CAP });
+    }
+    Ok(())
+}
+
+impl<T, const CAP: usize> CopyType for ArrayVec<T, CAP> {
+    type Copy = Deep;
+}
+
+impl<T: TypeHash, const CAP: usize> TypeHash for ArrayVec<T, CAP> {
+    fn type_hash(hasher: &mut impl core::hash::Hasher) {
+        "ArrayVec".hash(hasher);
+        T::type_hash(hasher);
+    }
+}
+
+impl<T: ReprHash, const CAP: usize> ReprHash for ArrayVec<T, CAP> {
+    fn repr_hash(hasher: &mut impl core::hash::Hasher, offset_of: &mut usize) {
+        *offset_of = 0;
+        T::repr_hash(hasher, offset_of);
+    }
+}
+
+impl<T: CopyType + SerializeInner + TypeHash, const CAP: usize> SerializeInner for ArrayVec<T, CAP>
+where
+    ArrayVec<T, CAP>: SerializeHelper<<T as CopyType>::Copy>,
+{
+    const IS_ZERO_COPY: bool = false;
+    const ZERO_COPY_MISMATCH: bool = false;
+    fn _serialize_inner(&self, backend: &mut impl WriteWithNames) -> ser::Result<()> {
+        SerializeHelper::_serialize_inner(self, backend)
+    }
+}
+
+impl<T: ZeroCopy + SerializeInner, const CAP: usize> SerializeHelper<Zero> for ArrayVec<T, CAP> {
+    #[inline(always)]
+    fn _serialize_inner(&self, backend: &mut impl WriteWithNames) -> ser::Result<()> {
+        serialize_slice_zero(backend, self.as_slice())
+    }
+}
+
+impl<T: DeepCopy + SerializeInner, const CAP: usize> SerializeHelper<Deep> for ArrayVec<T, CAP> {
+    #[inline(always)]
+    fn _serialize_inner(&self, backend: &mut impl WriteWithNames) -> ser::Result<()> {
+        serialize_slice_deep(backend, self.as_slice())
+    }
+}
+
+impl<T: CopyType + DeserializeInner + 'static, const CAP: usize> DeserializeInner
+    for ArrayVec<T, CAP>
+where
+    ArrayVec<T, CAP>: DeserializeHelper<<T as CopyType>::Copy, FullType = ArrayVec<T, CAP>>,
+{
+    type DeserType<'a> =
+        <ArrayVec<T, CAP> as DeserializeHelper<<T as CopyType>::Copy>>::DeserType<'a>;
+    #[inline(always)]
+    fn _deserialize_full_inner(backend: &mut impl ReadWithPos) -> deser::Result<Self> {
+        <ArrayVec<T, CAP> as DeserializeHelper<<T as CopyType>::Copy>>::_deserialize_full_inner_impl(
+            backend,
+        )
+    }
+
+    #[inline(always)]
+    fn _deserialize_eps_inner<'a>(
+        backend: &mut SliceWithPos<'a>,
+    ) -> deser::Result<<ArrayVec<T, CAP> as DeserializeHelper<<T as CopyType>::Copy>>::DeserType<'a>>
+    {
+        <ArrayVec<T, CAP> as DeserializeHelper<<T as CopyType>::Copy>>::_deserialize_eps_inner_impl(
+            backend,
+        )
+    }
+}
+
+impl<T: ZeroCopy + DeserializeInner + 'static, const CAP: usize> DeserializeHelper<Zero>
+    for ArrayVec<T, CAP>
+{
+    type FullType = Self;
+    type DeserType<'a> = &'a [T];
+    #[inline(always)]
+    fn _deserialize_full_inner_impl(backend: &mut impl ReadWithPos) -> deser::Result<Self> {
+        let len = usize::_deserialize_full_inner(backend)?;
+        check_capacity::<CAP>(len)?;
+        backend.align::<T>()?;
+        let mut res = ArrayVec::new();
+        // SAFETY: the array has room for len elements, and read_exact
+        // guarantees that they will be filled with data.
+        unsafe {
+            let buf = core::slice::from_raw_parts_mut(
+                res.as_mut_ptr() as *mut u8,
+                len * core::mem::size_of::<T>(),
+            );
+            backend.read_exact(buf)?;
+            check_values::<T>(buf)?;
+            res.set_len(len);
+        }
+        Ok(res)
+    }
+    #[inline(always)]
+    fn _deserialize_eps_inner_impl<'a>(
+        backend: &mut SliceWithPos<'a>,
+    ) -> deser::Result<<Self as DeserializeInner>::DeserType<'a>> {
+        let res = deserialize_eps_slice_zero(backend)?;
+        check_capacity::<CAP>(res.len())?;
+        Ok(res)
+    }
+}
+
+impl<T: DeepCopy + DeserializeInner + 'static, const CAP: usize> DeserializeHelper<Deep>
+    for ArrayVec<T, CAP>
+{
+    type FullType = Self;
+    type DeserType<'a> = Vec<<T as DeserializeInner>::DeserType<'a>>;
+    #[inline(always)]
+    fn _deserialize_full_inner_impl(backend: &mut impl ReadWithPos) -> deser::Result<Self> {
+        let len = usize::_deserialize_full_inner(backend)?;
+        check_capacity::<CAP>(len)?;
+        let mut res = ArrayVec::new();
+        for _ in 0..len {
+            res.push(T::_deserialize_full_inner(backend)?);
+        }
+        Ok(res)
+    }
+    #[inline(always)]
+    fn _deserialize_eps_inner_impl<'a>(
+        backend: &mut SliceWithPos<'a>,
+    ) -> deser::Result<<Self as DeserializeInner>::DeserType<'a>> {
+        let res = deserialize_eps_vec_deep::<T>(backend)?;
+        check_capacity::<CAP>(res.len())?;
+        Ok(res)
+    }
+}
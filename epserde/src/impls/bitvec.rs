@@ -0,0 +1,118 @@
+/*
+ * SPDX-FileCopyrightText: 2023 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+/*!
+
+Implementations for [`BitVec`], available with the `bitvec` feature.
+
+A [`BitVec`] is serialized as its length in bits followed by its storage
+words, which are serialized as a zero-copy slice. Full-copy deserialization
+returns a [`BitVec`], whereas ε-copy deserialization returns a [`BitSlice`]
+referring to the backend. Only unsigned integers are supported as storage
+types.
+
+The storage type and the bit order are part of the type hash; the storage
+type is also part of the representation hash.
+
+*/
+
+use crate::deser;
+use crate::deser::helpers::*;
+use crate::deser::*;
+use crate::ser;
+use crate::ser::helpers::*;
+use crate::ser::*;
+use crate::traits::*;
+use ::bitvec::order::{BitOrder, Lsb0, Msb0};
+use ::bitvec::slice::BitSlice;
+use ::bitvec::store::BitStore;
+use ::bitvec::vec::BitVec;
+use core::hash::Hash;
+
+impl TypeHash for Lsb0 {
+    fn type_hash(hasher: &mut impl core::hash::Hasher) {
+        "Lsb0".hash(hasher);
+    }
+}
+
+impl TypeHash for Msb0 {
+    fn type_hash(hasher: &mut impl core::hash::Hasher) {
+        "Msb0".hash(hasher);
+    }
+}
+
+/// Return an error if `num_words` is not the number of words of type `T`
+/// necessary to store `bit_len` bits.
+fn check_num_words<T>(bit_len: usize, num_words: usize) -> deser::Result<()> {
+    let bits = core::mem::size_of::<T>() * 8;
+    if num_words != bit_len.div_ceil(bits) {
+        return Err(deser::Error::InvalidBitLength { bit_len, num_words });
+    }
+    Ok(())
+}
+
+impl<T: BitStore, O: BitOrder> CopyType for BitVec<T, O> {
+    type Copy = Deep;
+}
+
+impl<T: BitStore + TypeHash, O: BitOrder + TypeHash> TypeHash for BitVec<T, O> {
+    fn type_hash(hasher: &mut impl core::hash::Hasher) {
+        "BitVec".hash(hasher);
+        T::type_hash(hasher);
+        O::type_hash(hasher);
+    }
+}
+
+impl<T: BitStore + ReprHash, O: BitOrder> ReprHash for BitVec<T, O> {
+    fn repr_hash(hasher: &mut impl core::hash::Hasher, offset_of: &mut usize) {
+        *offset_of = 0;
+        T::repr_hash(hasher, offset_of);
+    }
+}
+
+impl<T: BitStore + ZeroCopy + SerializeInner, O: BitOrder> SerializeInner for BitVec<T, O> {
+    const IS_ZERO_COPY: bool = false;
+    const ZERO_COPY_MISMATCH: bool = false;
+
+    fn _serialize_inner(&self, backend: &mut impl WriteWithNames) -> ser::Result<()> {
+        backend.write("bit_len", &self.len())?;
+        if self.as_bitptr().bit().into_inner() == 0 {
+            serialize_slice_zero(backend, self.as_raw_slice())
+        } else {
+            // The bits do not start at the beginning of the first word
+            let mut aligned = self.clone();
+            aligned.force_align();
+            serialize_slice_zero(backend, aligned.as_raw_slice())
+        }
+    }
+}
+
+impl<T: BitStore + ZeroCopy + DeserializeInner + 'static, O: BitOrder + 'static> DeserializeInner
+    for BitVec<T, O>
+{
+    fn _deserialize_full_inner(backend: &mut impl ReadWithPos) -> deser::Result<Self> {
+        let bit_len = usize::_deserialize_full_inner(backend)?;
+        let words = deserialize_full_vec_zero::<T>(backend)?;
+        check_num_words::<T>(bit_len, words.len())?;
+        let mut res =
+            BitVec::try_from_vec(words).map_err(|_| deser::Error::LengthTooLarge(bit_len))?;
+        res.truncate(bit_len);
+        Ok(res)
+    }
+
+    type DeserType<'a> = &'a BitSlice<T, O>;
+
+    fn _deserialize_eps_inner<'a>(
+        backend: &mut SliceWithPos<'a>,
+    ) -> deser::Result<Self::DeserType<'a>> {
+        let bit_len = usize::_deserialize_full_inner(backend)?;
+        let words = deserialize_eps_slice_zero::<T>(backend)?;
+        check_num_words::<T>(bit_len, words.len())?;
+        let res =
+            BitSlice::try_from_slice(words).map_err(|_| deser::Error::LengthTooLarge(bit_len))?;
+        Ok(&res[..bit_len])
+    }
+}
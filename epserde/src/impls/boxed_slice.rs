@@ -85,7 +85,7 @@ impl<T: ZeroCopy + DeserializeInner + 'static> DeserializeHelper<Zero> for Box<[
     type DeserType<'a> = &'a [T];
     #[inline(always)]
     fn _deserialize_full_inner_impl(backend: &mut impl ReadWithPos) -> deser::Result<Self> {
-        Ok(deserialize_full_vec_zero::<T>(backend)?.into_boxed_slice())
+        deserialize_full_boxed_slice_zero::<T>(backend)
     }
     #[inline(always)]
     fn _deserialize_eps_inner_impl<'a>(
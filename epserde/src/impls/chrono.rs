@@ -0,0 +1,76 @@
+/*
+ * SPDX-FileCopyrightText: 2023 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+/*!
+
+Implementations for [`NaiveDateTime`], available with the `chrono` feature.
+
+A [`NaiveDateTime`] is deep-copy: its layout is not guaranteed, so it is
+serialized as the number of non-leap seconds since the Unix epoch followed by
+the number of nanoseconds, as returned by
+[`timestamp`](chrono::DateTime::timestamp) and
+[`timestamp_subsec_nanos`](chrono::DateTime::timestamp_subsec_nanos) of the
+corresponding UTC date. Deserialization returns
+[`Error::InvalidTimestamp`](crate::deser::Error::InvalidTimestamp) if the
+values are out of range.
+
+*/
+
+use crate::prelude::*;
+use ::chrono::{DateTime, NaiveDateTime};
+use core::hash::Hash;
+use deser::*;
+use ser::*;
+
+impl CopyType for NaiveDateTime {
+    type Copy = Deep;
+}
+
+impl TypeHash for NaiveDateTime {
+    fn type_hash(hasher: &mut impl core::hash::Hasher) {
+        "chrono::NaiveDateTime".hash(hasher);
+    }
+}
+
+impl ReprHash for NaiveDateTime {
+    fn repr_hash(hasher: &mut impl core::hash::Hasher, offset_of: &mut usize) {
+        *offset_of = 0;
+        i64::repr_hash(hasher, offset_of);
+        *offset_of = 0;
+        u32::repr_hash(hasher, offset_of);
+    }
+}
+
+impl SerializeInner for NaiveDateTime {
+    const IS_ZERO_COPY: bool = false;
+    const ZERO_COPY_MISMATCH: bool = false;
+
+    #[inline(always)]
+    fn _serialize_inner(&self, backend: &mut impl WriteWithNames) -> ser::Result<()> {
+        let utc = self.and_utc();
+        backend.write("secs", &utc.timestamp())?;
+        backend.write("nanos", &utc.timestamp_subsec_nanos())?;
+        Ok(())
+    }
+}
+
+impl DeserializeInner for NaiveDateTime {
+    #[inline(always)]
+    fn _deserialize_full_inner(backend: &mut impl ReadWithPos) -> deser::Result<Self> {
+        let secs = i64::_deserialize_full_inner(backend)?;
+        let nanos = u32::_deserialize_full_inner(backend)?;
+        DateTime::from_timestamp(secs, nanos)
+            .map(|date_time| date_time.naive_utc())
+            .ok_or(deser::Error::InvalidTimestamp { secs, nanos })
+    }
+    type DeserType<'a> = NaiveDateTime;
+    #[inline(always)]
+    fn _deserialize_eps_inner<'a>(
+        backend: &mut SliceWithPos<'a>,
+    ) -> deser::Result<Self::DeserType<'a>> {
+        Self::_deserialize_full_inner(backend)
+    }
+}
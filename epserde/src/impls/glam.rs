@@ -0,0 +1,86 @@
+/*
+ * SPDX-FileCopyrightText: 2023 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+/*!
+
+Implementations for the vector, matrix, and quaternion types of [`glam`],
+available with the `glam` feature.
+
+All these types are `repr(C)` and contain only primitive values, so they are
+zero-copy: for example, a `Vec<Vec3>` is ε-copy deserialized as a `&[Vec3]`.
+
+Some types (e.g., [`Vec4`](::glam::Vec4) and [`Mat4`](::glam::Mat4)) use SIMD
+registers, and thus have a larger alignment, only on some architectures or
+depending on the features of [`glam`]. For this reason, the representation
+hash includes the alignment, and [`MaxSizeOf::max_size_of`] returns the
+alignment, which is the maximum size of a primitive field or of a SIMD
+register.
+
+*/
+
+use crate::prelude::*;
+use ::glam::*;
+use core::hash::Hash;
+use deser::*;
+use ser::*;
+
+macro_rules! impl_glam {
+    ($($ty:ident),*) => {$(
+        impl CopyType for $ty {
+            type Copy = Zero;
+        }
+
+        impl TypeHash for $ty {
+            fn type_hash(hasher: &mut impl core::hash::Hasher) {
+                concat!("glam::", stringify!($ty)).hash(hasher);
+            }
+        }
+
+        impl ReprHash for $ty {
+            fn repr_hash(hasher: &mut impl core::hash::Hasher, offset_of: &mut usize) {
+                core::mem::align_of::<Self>().hash(hasher);
+                crate::traits::std_repr_hash::<Self>(hasher, offset_of)
+            }
+        }
+
+        impl MaxSizeOf for $ty {
+            fn max_size_of() -> usize {
+                core::mem::align_of::<Self>()
+            }
+        }
+
+        impl SerializeInner for $ty {
+            const IS_ZERO_COPY: bool = true;
+            const ZERO_COPY_MISMATCH: bool = false;
+
+            #[inline(always)]
+            fn _serialize_inner(&self, backend: &mut impl WriteWithNames) -> ser::Result<()> {
+                serialize_zero(backend, self)
+            }
+        }
+
+        impl DeserializeInner for $ty {
+            #[inline(always)]
+            fn _deserialize_full_inner(backend: &mut impl ReadWithPos) -> deser::Result<Self> {
+                deserialize_full_zero(backend)
+            }
+
+            type DeserType<'a> = &'a $ty;
+
+            #[inline(always)]
+            fn _deserialize_eps_inner<'a>(
+                backend: &mut SliceWithPos<'a>,
+            ) -> deser::Result<Self::DeserType<'a>> {
+                deserialize_eps_zero(backend)
+            }
+        }
+    )*};
+}
+
+impl_glam!(Vec2, Vec3, Vec3A, Vec4, Mat2, Mat3, Mat3A, Mat4, Quat, Affine2, Affine3A);
+impl_glam!(DVec2, DVec3, DVec4, DMat2, DMat3, DMat4, DQuat, DAffine2, DAffine3);
+impl_glam!(IVec2, IVec3, IVec4, UVec2, UVec3, UVec4);
+impl_glam!(I64Vec2, I64Vec3, I64Vec4, U64Vec2, U64Vec3, U64Vec4);
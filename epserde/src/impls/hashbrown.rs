@@ -0,0 +1,121 @@
+/*
+ * SPDX-FileCopyrightText: 2023 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+/*!
+
+Implementations for [`hashbrown::HashMap`], available with the `hashbrown`
+feature.
+
+Maps are serialized exactly like a [standard hash
+map](std::collections::HashMap), that is, as a length followed by the
+entries, in iteration order (or sorted by the serialized bytes of the keys,
+if the backend is deterministic). The state of the hasher is not
+serialized: maps are reconstructed using the default value of the hasher
+type, which is part of the type hash. The ε-copy deserialization type is a
+hash map whose keys and values are the ε-copy deserialization types of the
+keys and values.
+
+Differently from the implementation for standard hash maps, this
+implementation does not need the `std` feature.
+
+*/
+
+use crate::deser;
+use crate::deser::*;
+use crate::ser;
+use crate::ser::*;
+use crate::traits::*;
+use ::hashbrown::{DefaultHashBuilder, HashMap};
+use core::hash::{BuildHasher, Hash};
+
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use alloc::vec::Vec;
+
+impl TypeHash for DefaultHashBuilder {
+    fn type_hash(hasher: &mut impl core::hash::Hasher) {
+        "hashbrown::DefaultHashBuilder".hash(hasher);
+    }
+}
+
+impl<K, V, S> CopyType for HashMap<K, V, S> {
+    type Copy = Deep;
+}
+
+impl<K: TypeHash, V: TypeHash, S: TypeHash> TypeHash for HashMap<K, V, S> {
+    fn type_hash(hasher: &mut impl core::hash::Hasher) {
+        "hashbrown::HashMap".hash(hasher);
+        K::type_hash(hasher);
+        V::type_hash(hasher);
+        S::type_hash(hasher);
+    }
+}
+
+impl<K: ReprHash, V: ReprHash, S> ReprHash for HashMap<K, V, S> {
+    fn repr_hash(hasher: &mut impl core::hash::Hasher, offset_of: &mut usize) {
+        *offset_of = 0;
+        K::repr_hash_isolated(hasher, offset_of);
+        *offset_of = 0;
+        V::repr_hash_isolated(hasher, offset_of);
+    }
+}
+
+impl<K: SerializeInner, V: SerializeInner, S> SerializeInner for HashMap<K, V, S> {
+    const IS_ZERO_COPY: bool = false;
+    const ZERO_COPY_MISMATCH: bool = false;
+
+    fn _serialize_inner(&self, backend: &mut impl WriteWithNames) -> ser::Result<()> {
+        backend.write("len", &self.len())?;
+        if backend.deterministic() {
+            for (key, value) in ser::helpers::sort_by_key_bytes(self)? {
+                backend.write("key", key)?;
+                backend.write("value", value)?;
+            }
+        } else {
+            for (key, value) in self {
+                backend.write("key", key)?;
+                backend.write("value", value)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<K, V, S> DeserializeInner for HashMap<K, V, S>
+where
+    K: DeserializeInner + Eq + Hash,
+    V: DeserializeInner,
+    S: BuildHasher + Default,
+    for<'a> DeserType<'a, K>: Eq + Hash,
+{
+    fn _deserialize_full_inner(backend: &mut impl ReadWithPos) -> deser::Result<Self> {
+        let len = usize::_deserialize_full_inner(backend)?;
+        let capacity = deser::helpers::prealloc_len::<(K, V)>(backend, len);
+        let mut res = HashMap::with_capacity_and_hasher(capacity, S::default());
+        for _ in 0..len {
+            let key = K::_deserialize_full_inner(backend)?;
+            let value = V::_deserialize_full_inner(backend)?;
+            res.insert(key, value);
+        }
+        Ok(res)
+    }
+
+    type DeserType<'a> = HashMap<DeserType<'a, K>, DeserType<'a, V>, S>;
+
+    fn _deserialize_eps_inner<'a>(
+        backend: &mut SliceWithPos<'a>,
+    ) -> deser::Result<Self::DeserType<'a>> {
+        let len = usize::_deserialize_full_inner(backend)?;
+        let capacity =
+            deser::helpers::prealloc_len::<(DeserType<'a, K>, DeserType<'a, V>)>(backend, len);
+        let mut res = HashMap::with_capacity_and_hasher(capacity, S::default());
+        for _ in 0..len {
+            let key = K::_deserialize_eps_inner(backend)?;
+            let value = V::_deserialize_eps_inner(backend)?;
+            res.insert(key, value);
+        }
+        Ok(res)
+    }
+}
@@ -13,12 +13,33 @@ and [`DeserializeInner`](crate::deser::DeserializeInner) for standard Rust types
 */
 
 pub mod array;
+#[cfg(feature = "arrayvec")]
+pub mod arrayvec;
+#[cfg(feature = "bitvec")]
+pub mod bitvec;
 pub mod boxed_slice;
+#[cfg(feature = "chrono")]
+pub mod chrono;
+#[cfg(feature = "glam")]
+pub mod glam;
+#[cfg(feature = "hashbrown")]
+pub mod hashbrown;
+pub mod net;
+#[cfg(feature = "ordered-float")]
+pub mod ordered_float;
 pub mod prim;
+#[cfg(any(feature = "alloc", feature = "std"))]
+pub mod shared_slice;
 pub mod slice;
+#[cfg(feature = "smallvec")]
+pub mod smallvec;
 #[cfg(feature = "std")]
 pub mod stdlib;
 pub mod string;
+#[cfg(feature = "tinyvec")]
+pub mod tinyvec;
 pub mod tuple;
+#[cfg(feature = "uuid")]
+pub mod uuid;
 #[cfg(any(feature = "alloc", feature = "std"))]
 pub mod vec;
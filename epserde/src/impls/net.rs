@@ -0,0 +1,126 @@
+/*
+ * SPDX-FileCopyrightText: 2023 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+/*!
+
+Implementations for the IP address types of [`core::net`].
+
+These types do not need the `std` feature. Their layout is not guaranteed to
+be stable, so they are deep-copy: [`Ipv4Addr`] and [`Ipv6Addr`] are
+serialized as arrays of octets, and [`IpAddr`] as a `u8` tag (0 for
+[`V4`](IpAddr::V4) and 1 for [`V6`](IpAddr::V6)) followed by the address.
+
+*/
+
+use crate::prelude::*;
+use core::hash::Hash;
+use core::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+use deser::*;
+use ser::*;
+
+macro_rules! impl_ip_addr {
+    ($ty:ty, $name:literal, $len:literal) => {
+        impl CopyType for $ty {
+            type Copy = Deep;
+        }
+
+        impl TypeHash for $ty {
+            fn type_hash(hasher: &mut impl core::hash::Hasher) {
+                $name.hash(hasher);
+            }
+        }
+
+        impl ReprHash for $ty {
+            fn repr_hash(hasher: &mut impl core::hash::Hasher, offset_of: &mut usize) {
+                *offset_of = 0;
+                <[u8; $len]>::repr_hash(hasher, offset_of);
+            }
+        }
+
+        impl SerializeInner for $ty {
+            const IS_ZERO_COPY: bool = false;
+            const ZERO_COPY_MISMATCH: bool = false;
+
+            #[inline(always)]
+            fn _serialize_inner(&self, backend: &mut impl WriteWithNames) -> ser::Result<()> {
+                backend.write("octets", &self.octets())
+            }
+        }
+
+        impl DeserializeInner for $ty {
+            #[inline(always)]
+            fn _deserialize_full_inner(backend: &mut impl ReadWithPos) -> deser::Result<Self> {
+                Ok(<[u8; $len]>::_deserialize_full_inner(backend)?.into())
+            }
+            type DeserType<'a> = $ty;
+            #[inline(always)]
+            fn _deserialize_eps_inner<'a>(
+                backend: &mut SliceWithPos<'a>,
+            ) -> deser::Result<Self::DeserType<'a>> {
+                Self::_deserialize_full_inner(backend)
+            }
+        }
+    };
+}
+
+impl_ip_addr!(Ipv4Addr, "core::net::Ipv4Addr", 4);
+impl_ip_addr!(Ipv6Addr, "core::net::Ipv6Addr", 16);
+
+impl CopyType for IpAddr {
+    type Copy = Deep;
+}
+
+impl TypeHash for IpAddr {
+    fn type_hash(hasher: &mut impl core::hash::Hasher) {
+        "core::net::IpAddr".hash(hasher);
+    }
+}
+
+impl ReprHash for IpAddr {
+    fn repr_hash(hasher: &mut impl core::hash::Hasher, offset_of: &mut usize) {
+        *offset_of = 0;
+        u8::repr_hash(hasher, offset_of);
+        Ipv4Addr::repr_hash(hasher, offset_of);
+        Ipv6Addr::repr_hash(hasher, offset_of);
+    }
+}
+
+impl SerializeInner for IpAddr {
+    const IS_ZERO_COPY: bool = false;
+    const ZERO_COPY_MISMATCH: bool = false;
+
+    #[inline(always)]
+    fn _serialize_inner(&self, backend: &mut impl WriteWithNames) -> ser::Result<()> {
+        match self {
+            IpAddr::V4(addr) => {
+                backend.write("tag", &0_u8)?;
+                backend.write("V4", addr)
+            }
+            IpAddr::V6(addr) => {
+                backend.write("tag", &1_u8)?;
+                backend.write("V6", addr)
+            }
+        }
+    }
+}
+
+impl DeserializeInner for IpAddr {
+    #[inline(always)]
+    fn _deserialize_full_inner(backend: &mut impl ReadWithPos) -> deser::Result<Self> {
+        match u8::_deserialize_full_inner(backend)? {
+            0 => Ok(IpAddr::V4(Ipv4Addr::_deserialize_full_inner(backend)?)),
+            1 => Ok(IpAddr::V6(Ipv6Addr::_deserialize_full_inner(backend)?)),
+            tag => Err(deser::Error::InvalidTag(tag as usize)),
+        }
+    }
+    type DeserType<'a> = IpAddr;
+    #[inline(always)]
+    fn _deserialize_eps_inner<'a>(
+        backend: &mut SliceWithPos<'a>,
+    ) -> deser::Result<Self::DeserType<'a>> {
+        Self::_deserialize_full_inner(backend)
+    }
+}
@@ -0,0 +1,72 @@
+/*
+ * SPDX-FileCopyrightText: 2023 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+/*!
+
+Implementations for [`OrderedFloat`], available with the `ordered-float`
+feature.
+
+An [`OrderedFloat`] is a `repr(transparent)` wrapper around a float, so it is
+zero-copy if the wrapped type is: for example, a `Vec<OrderedFloat<f64>>` is
+ε-copy deserialized as a `&[OrderedFloat<f64>]`. The representation hash
+and [`MaxSizeOf::max_size_of`] are those of the wrapped type.
+
+*/
+
+use crate::prelude::*;
+use ::ordered_float::OrderedFloat;
+use core::hash::Hash;
+use deser::*;
+use ser::*;
+
+impl<T: ZeroCopy> CopyType for OrderedFloat<T> {
+    type Copy = Zero;
+}
+
+impl<T: TypeHash> TypeHash for OrderedFloat<T> {
+    fn type_hash(hasher: &mut impl core::hash::Hasher) {
+        "ordered_float::OrderedFloat".hash(hasher);
+        T::type_hash(hasher);
+    }
+}
+
+impl<T: ReprHash> ReprHash for OrderedFloat<T> {
+    fn repr_hash(hasher: &mut impl core::hash::Hasher, offset_of: &mut usize) {
+        T::repr_hash(hasher, offset_of);
+    }
+}
+
+impl<T: MaxSizeOf> MaxSizeOf for OrderedFloat<T> {
+    fn max_size_of() -> usize {
+        T::max_size_of()
+    }
+}
+
+impl<T: ZeroCopy + SerializeInner> SerializeInner for OrderedFloat<T> {
+    const IS_ZERO_COPY: bool = true;
+    const ZERO_COPY_MISMATCH: bool = false;
+
+    #[inline(always)]
+    fn _serialize_inner(&self, backend: &mut impl WriteWithNames) -> ser::Result<()> {
+        serialize_zero(backend, self)
+    }
+}
+
+impl<T: ZeroCopy + DeserializeInner> DeserializeInner for OrderedFloat<T> {
+    #[inline(always)]
+    fn _deserialize_full_inner(backend: &mut impl ReadWithPos) -> deser::Result<Self> {
+        deserialize_full_zero(backend)
+    }
+
+    type DeserType<'a> = &'a OrderedFloat<T>;
+
+    #[inline(always)]
+    fn _deserialize_eps_inner<'a>(
+        backend: &mut SliceWithPos<'a>,
+    ) -> deser::Result<Self::DeserType<'a>> {
+        deserialize_eps_zero(backend)
+    }
+}
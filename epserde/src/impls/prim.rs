@@ -7,7 +7,9 @@
 
 /*!
 
-Implementations for primitive types, `()`, [`PhantomData`] and [`Option`].
+Implementations for primitive types, `()`, [`PhantomData`], function pointers,
+references and raw pointers (only as [`PhantomData`] parameters),
+and [`Option`].
 
 */
 
@@ -37,15 +39,35 @@ macro_rules! impl_prim_type_hash {
             }
         }
 
+        impl MaxSizeOf for $ty {
+            fn max_size_of() -> usize {
+                size_of::<$ty>()
+            }
+        }
+    )*};
+}
+
+macro_rules! impl_prim_repr_hash {
+    ($($ty:ty),*) => {$(
         impl ReprHash for $ty {
             fn repr_hash(hasher: &mut impl core::hash::Hasher, offset_of: &mut usize) {
                 crate::traits::std_repr_hash::<Self>(hasher, offset_of)
             }
         }
+    )*};
+}
 
-        impl MaxSizeOf for $ty {
-            fn max_size_of() -> usize {
-                size_of::<$ty>()
+// The alignment of 128-bit integers is 16 bytes on some architectures and 8
+// on others, and in both cases it is smaller than or equal to their size, so
+// it would not be visible in the representation hash of, say, a Vec<u128>.
+// We hash it explicitly, so that data written on an architecture is rejected
+// on an architecture with a different alignment rather than misread.
+macro_rules! impl_prim_repr_hash_with_align {
+    ($($ty:ty),*) => {$(
+        impl ReprHash for $ty {
+            fn repr_hash(hasher: &mut impl core::hash::Hasher, offset_of: &mut usize) {
+                crate::traits::hash_usize(hasher, core::mem::align_of::<Self>());
+                crate::traits::std_repr_hash::<Self>(hasher, offset_of)
             }
         }
     )*};
@@ -78,20 +100,71 @@ macro_rules! impl_prim_ser_des {
             fn _deserialize_eps_inner<'a>(
                 backend: &mut SliceWithPos<'a>,
             ) -> deser::Result<Self::DeserType<'a>> {
-                let res = <$ty>::from_ne_bytes(
-                        backend.data[..size_of::<$ty>()]
-                            .try_into()
-                            .unwrap());
-
-                backend.skip(size_of::<$ty>());
-                Ok(res)
+                Self::_deserialize_full_inner(backend)
             }
         }
     )*};
 }
 
 impl_prim_type_hash!(isize, i8, i16, i32, i64, i128, usize, u8, u16, u32, u64, u128, f32, f64);
-impl_prim_ser_des!(isize, i8, i16, i32, i64, i128, usize, u8, u16, u32, u64, u128, f32, f64);
+impl_prim_repr_hash!(isize, i8, i16, i32, i64, u8, u16, u32, u64, f32, f64);
+impl_prim_repr_hash_with_align!(i128, u128);
+impl_prim_ser_des!(isize, i8, i16, i32, i64, i128, u8, u16, u32, u64, u128, f32, f64);
+// usize values (e.g., lengths) are written using USIZE_SIZE bytes. When it is
+// eight (on 64-bit architectures, or with the portable-usize feature) they are
+// written as u64 values, and at deserialization they are checked to fit in a
+// native usize. Note that this does not apply to usize values that are part of
+// zero-copy types, which are always written using the native size.
+impl ReprHash for usize {
+    fn repr_hash(hasher: &mut impl core::hash::Hasher, offset_of: &mut usize) {
+        crate::traits::std_repr_hash::<Self>(hasher, offset_of)
+    }
+
+    // In isolation a usize is written as a u64 when USIZE_SIZE is eight, so
+    // its representation must be hashed as such; inside zero-copy data, which
+    // uses the native size, we hash the native representation.
+    fn repr_hash_isolated(hasher: &mut impl core::hash::Hasher, offset_of: &mut usize) {
+        if crate::USIZE_SIZE == size_of::<u64>() {
+            u64::repr_hash(hasher, offset_of)
+        } else {
+            Self::repr_hash(hasher, offset_of)
+        }
+    }
+}
+
+impl SerializeInner for usize {
+    const IS_ZERO_COPY: bool = true;
+    const ZERO_COPY_MISMATCH: bool = false;
+
+    #[inline(always)]
+    fn _serialize_inner(&self, backend: &mut impl WriteWithNames) -> ser::Result<()> {
+        if crate::USIZE_SIZE == size_of::<u64>() {
+            backend.write_all(&(*self as u64).to_ne_bytes())
+        } else {
+            backend.write_all(&self.to_ne_bytes())
+        }
+    }
+}
+
+impl DeserializeInner for usize {
+    #[inline(always)]
+    fn _deserialize_full_inner(backend: &mut impl ReadWithPos) -> deser::Result<usize> {
+        if crate::USIZE_SIZE == size_of::<u64>() {
+            deser::helpers::usize_from_u64(u64::_deserialize_full_inner(backend)?)
+        } else {
+            let mut buf = [0; size_of::<usize>()];
+            backend.read_exact(&mut buf)?;
+            Ok(usize::from_ne_bytes(buf))
+        }
+    }
+    type DeserType<'a> = Self;
+    #[inline(always)]
+    fn _deserialize_eps_inner<'a>(
+        backend: &mut SliceWithPos<'a>,
+    ) -> deser::Result<Self::DeserType<'a>> {
+        Self::_deserialize_full_inner(backend)
+    }
+}
 
 macro_rules! impl_nonzero_ser_des {
     ($($ty:ty),*) => {$(
@@ -113,20 +186,16 @@ macro_rules! impl_nonzero_ser_des {
             fn _deserialize_full_inner(backend: &mut impl ReadWithPos) -> deser::Result<$ty> {
                 let mut buf = [0; size_of::<$ty>()];
                 backend.read_exact(&mut buf)?;
-                Ok(<$ty as NonZero>::BaseType::from_ne_bytes(buf).try_into().unwrap())
+                <$ty as NonZero>::BaseType::from_ne_bytes(buf)
+                    .try_into()
+                    .map_err(|_| deser::Error::InvalidNonZero)
             }
             type DeserType<'a> = Self;
             #[inline(always)]
             fn _deserialize_eps_inner<'a>(
                 backend: &mut SliceWithPos<'a>,
             ) -> deser::Result<Self::DeserType<'a>> {
-                let res = <$ty as NonZero>::BaseType::from_ne_bytes(
-                        backend.data[..size_of::<$ty>()]
-                            .try_into()
-                            .unwrap()).try_into().unwrap();
-
-                backend.skip(size_of::<$ty>());
-                Ok(res)
+                Self::_deserialize_full_inner(backend)
             }
         }
     )*};
@@ -147,6 +216,26 @@ impl_prim_type_hash!(
     NonZeroU128
 );
 
+impl_prim_repr_hash!(
+    NonZeroIsize,
+    NonZeroI8,
+    NonZeroI16,
+    NonZeroI32,
+    NonZeroI64,
+    NonZeroUsize,
+    NonZeroU8,
+    NonZeroU16,
+    NonZeroU32,
+    NonZeroU64
+);
+impl_prim_repr_hash_with_align!(NonZeroI128, NonZeroU128);
+
+// Nonzero integers are zero-copy serialized as the corresponding integers.
+// Arrays and vectors of nonzero integers are deserialized by reinterpreting
+// bytes: as in the case of booleans, full-copy deserialization checks that
+// there are no zero elements if the feature `validate` is enabled, whereas
+// ε-copy deserialization is unchecked.
+
 impl_nonzero_ser_des!(
     NonZeroIsize,
     NonZeroI8,
@@ -162,9 +251,13 @@ impl_nonzero_ser_des!(
     NonZeroU128
 );
 
-impl_prim_type_hash!(bool, char, ());
+impl_prim_type_hash!(bool, char);
+impl_prim_repr_hash!(bool, char);
 
-// Booleans are zero-copy serialized as u8.
+// Booleans are zero-copy serialized as u8. Arrays and vectors of booleans are
+// deserialized by reinterpreting bytes, which must be zero or one: full-copy
+// deserialization checks this condition if the feature `validate` is enabled,
+// whereas ε-copy deserialization is unchecked.
 
 impl SerializeInner for bool {
     const IS_ZERO_COPY: bool = true;
@@ -187,9 +280,7 @@ impl DeserializeInner for bool {
     fn _deserialize_eps_inner<'a>(
         backend: &mut SliceWithPos<'a>,
     ) -> deser::Result<Self::DeserType<'a>> {
-        let res = backend.data[0] != 0;
-        backend.skip(1);
-        Ok(res)
+        Self::_deserialize_full_inner(backend)
     }
 }
 
@@ -208,19 +299,44 @@ impl SerializeInner for char {
 impl DeserializeInner for char {
     #[inline(always)]
     fn _deserialize_full_inner(backend: &mut impl ReadWithPos) -> deser::Result<Self> {
-        Ok(char::from_u32(u32::_deserialize_full_inner(backend)?).unwrap())
+        let code = u32::_deserialize_full_inner(backend)?;
+        char::from_u32(code).ok_or(deser::Error::InvalidChar(code))
     }
     type DeserType<'a> = Self;
     #[inline(always)]
     fn _deserialize_eps_inner<'a>(
         backend: &mut SliceWithPos<'a>,
     ) -> deser::Result<Self::DeserType<'a>> {
-        Ok(char::from_u32(u32::_deserialize_eps_inner(backend)?).unwrap())
+        Self::_deserialize_full_inner(backend)
     }
 }
 
 // () is zero-copy. No reading or writing is performed when (de)serializing it.
 
+impl CopyType for () {
+    type Copy = Zero;
+}
+
+impl TypeHash for () {
+    fn type_hash(hasher: &mut impl core::hash::Hasher) {
+        stringify!(()).hash(hasher);
+    }
+}
+
+impl ReprHash for () {
+    fn repr_hash(hasher: &mut impl core::hash::Hasher, offset_of: &mut usize) {
+        crate::traits::std_repr_hash::<Self>(hasher, offset_of)
+    }
+}
+
+// Zero-sized types have alignment one: returning their size would
+// make alignment impossible (e.g., when serializing a Vec<()>).
+impl MaxSizeOf for () {
+    fn max_size_of() -> usize {
+        1
+    }
+}
+
 impl SerializeInner for () {
     const IS_ZERO_COPY: bool = true;
     const ZERO_COPY_MISMATCH: bool = false;
@@ -245,6 +361,48 @@ impl DeserializeInner for () {
     }
 }
 
+// Infallible is deep-copy, as it has no values: serialization is unreachable,
+// and deserialization always returns an error. It is useful in generic code
+// in which, for example, an error type is instantiated to Infallible.
+
+impl CopyType for core::convert::Infallible {
+    type Copy = Deep;
+}
+
+impl TypeHash for core::convert::Infallible {
+    fn type_hash(hasher: &mut impl core::hash::Hasher) {
+        "core::convert::Infallible".hash(hasher);
+    }
+}
+
+impl ReprHash for core::convert::Infallible {
+    fn repr_hash(_hasher: &mut impl core::hash::Hasher, _offset_of: &mut usize) {}
+}
+
+impl SerializeInner for core::convert::Infallible {
+    const IS_ZERO_COPY: bool = false;
+    const ZERO_COPY_MISMATCH: bool = false;
+
+    #[inline(always)]
+    fn _serialize_inner(&self, _backend: &mut impl WriteWithNames) -> ser::Result<()> {
+        match *self {}
+    }
+}
+
+impl DeserializeInner for core::convert::Infallible {
+    #[inline(always)]
+    fn _deserialize_full_inner(_backend: &mut impl ReadWithPos) -> deser::Result<Self> {
+        Err(deser::Error::Uninhabited("core::convert::Infallible"))
+    }
+    type DeserType<'a> = Self;
+    #[inline(always)]
+    fn _deserialize_eps_inner<'a>(
+        _backend: &mut SliceWithPos<'a>,
+    ) -> deser::Result<Self::DeserType<'a>> {
+        Err(deser::Error::Uninhabited("core::convert::Infallible"))
+    }
+}
+
 // PhantomData is zero-copy. No reading or writing is performed when
 // (de)serializing it.
 
@@ -254,7 +412,7 @@ impl<T: ?Sized> CopyType for PhantomData<T> {
 
 impl<T: ?Sized> MaxSizeOf for PhantomData<T> {
     fn max_size_of() -> usize {
-        0
+        1
     }
 }
 
@@ -295,6 +453,79 @@ impl<T: ?Sized + TypeHash> DeserializeInner for PhantomData<T> {
     }
 }
 
+// Function pointers are supported only so that they can be used as type
+// parameters of a PhantomData (e.g., as variance markers like
+// PhantomData<fn() -> T>): they are deep-copy, but they do not implement
+// SerializeInner or DeserializeInner, so actual function pointers cannot be
+// serialized.
+
+macro_rules! impl_fn_ptr {
+    ($($arg:ident),*) => {
+        impl<R, $($arg,)*> CopyType for fn($($arg,)*) -> R {
+            type Copy = Deep;
+        }
+
+        impl<R: TypeHash, $($arg: TypeHash,)*> TypeHash for fn($($arg,)*) -> R {
+            #[inline(always)]
+            fn type_hash(hasher: &mut impl core::hash::Hasher) {
+                "fn".hash(hasher);
+                $(
+                    <$arg>::type_hash(hasher);
+                )*
+                "->".hash(hasher);
+                R::type_hash(hasher);
+            }
+        }
+
+        impl<R, $($arg,)*> ReprHash for fn($($arg,)*) -> R {
+            #[inline(always)]
+            fn repr_hash(_hasher: &mut impl core::hash::Hasher, _offset_of: &mut usize) {}
+        }
+
+        impl<R, $($arg,)*> MaxSizeOf for fn($($arg,)*) -> R {
+            #[inline(always)]
+            fn max_size_of() -> usize {
+                core::mem::align_of::<Self>()
+            }
+        }
+    };
+}
+
+impl_fn_ptr!();
+impl_fn_ptr!(A);
+impl_fn_ptr!(A, B);
+impl_fn_ptr!(A, B, C);
+impl_fn_ptr!(A, B, C, D);
+
+// Similarly, references and raw pointers are supported only so that they can
+// be used as type parameters of a PhantomData (e.g., as markers like
+// PhantomData<&'a T> or PhantomData<*const T>). References to slices are
+// handled in the slice module.
+
+impl<T: TypeHash> TypeHash for &T {
+    #[inline(always)]
+    fn type_hash(hasher: &mut impl core::hash::Hasher) {
+        "&".hash(hasher);
+        T::type_hash(hasher);
+    }
+}
+
+impl<T: ?Sized + TypeHash> TypeHash for *const T {
+    #[inline(always)]
+    fn type_hash(hasher: &mut impl core::hash::Hasher) {
+        "*const".hash(hasher);
+        T::type_hash(hasher);
+    }
+}
+
+impl<T: ?Sized + TypeHash> TypeHash for *mut T {
+    #[inline(always)]
+    fn type_hash(hasher: &mut impl core::hash::Hasher) {
+        "*mut".hash(hasher);
+        T::type_hash(hasher);
+    }
+}
+
 // Options are deep-copy types serialized as a one-byte tag (0 for None, 1 for Some) followed, in case, by the value.
 
 impl<T> CopyType for Option<T> {
@@ -312,7 +543,7 @@ impl<T: TypeHash> TypeHash for Option<T> {
 impl<T: ReprHash> ReprHash for Option<T> {
     fn repr_hash(hasher: &mut impl core::hash::Hasher, offset_of: &mut usize) {
         *offset_of = 0;
-        T::repr_hash(hasher, offset_of);
+        T::repr_hash_isolated(hasher, offset_of);
     }
 }
 
@@ -351,7 +582,7 @@ impl<T: DeserializeInner> DeserializeInner for Option<T> {
         match tag {
             0 => Ok(None),
             1 => Ok(Some(T::_deserialize_eps_inner(backend)?)),
-            _ => Err(deser::Error::InvalidTag(backend.data[0] as usize)),
+            _ => Err(deser::Error::InvalidTag(tag as usize)),
         }
     }
 }
@@ -0,0 +1,135 @@
+/*
+ * SPDX-FileCopyrightText: 2023 Inria
+ * SPDX-FileCopyrightText: 2023 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+/*!
+
+Implementations for shared slices.
+
+We support `Rc<[T]>` and `Arc<[T]>`: they have the same serialized
+representation as [boxed slices](crate::impls::boxed_slice), but different type
+hashes. Full-copy deserialization builds the shared slice directly, so the
+result is a single allocation containing the reference counts and the
+elements; ε-copy deserialization of zero-copy elements returns a reference to
+the backend.
+
+*/
+use crate::deser::helpers::*;
+use crate::prelude::*;
+use core::hash::Hash;
+use deser::*;
+use ser::*;
+
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use alloc::{rc::Rc, sync::Arc};
+#[cfg(feature = "std")]
+use std::{rc::Rc, sync::Arc};
+
+macro_rules! impl_shared_slice {
+    ($ptr:ident) => {
+        impl<T> CopyType for $ptr<[T]> {
+            type Copy = Deep;
+        }
+
+        impl<T: TypeHash> TypeHash for $ptr<[T]> {
+            fn type_hash(hasher: &mut impl core::hash::Hasher) {
+                concat!(stringify!($ptr), "<[]>").hash(hasher);
+                T::type_hash(hasher);
+            }
+        }
+
+        impl<T: ReprHash> ReprHash for $ptr<[T]> {
+            fn repr_hash(hasher: &mut impl core::hash::Hasher, offset_of: &mut usize) {
+                *offset_of = 0;
+                T::repr_hash(hasher, offset_of);
+            }
+        }
+
+        impl<T: CopyType + TypeHash + ReprHash + SerializeInner> SerializeInner for $ptr<[T]>
+        where
+            $ptr<[T]>: SerializeHelper<<T as CopyType>::Copy>,
+        {
+            const IS_ZERO_COPY: bool = false;
+            const ZERO_COPY_MISMATCH: bool = false;
+            fn _serialize_inner(&self, backend: &mut impl WriteWithNames) -> ser::Result<()> {
+                SerializeHelper::_serialize_inner(self, backend)
+            }
+        }
+
+        impl<T: ZeroCopy + SerializeInner> SerializeHelper<Zero> for $ptr<[T]> {
+            #[inline(always)]
+            fn _serialize_inner(&self, backend: &mut impl WriteWithNames) -> ser::Result<()> {
+                serialize_slice_zero(backend, self)
+            }
+        }
+
+        impl<T: DeepCopy + SerializeInner> SerializeHelper<Deep> for $ptr<[T]> {
+            #[inline(always)]
+            fn _serialize_inner(&self, backend: &mut impl WriteWithNames) -> ser::Result<()> {
+                serialize_slice_deep(backend, self)
+            }
+        }
+
+        // This delegates to a private helper trait which we can specialize on in stable rust
+        impl<T: DeserializeInner + CopyType + 'static> DeserializeInner for $ptr<[T]>
+        where
+            $ptr<[T]>: DeserializeHelper<<T as CopyType>::Copy, FullType = $ptr<[T]>>,
+        {
+            type DeserType<'a> =
+                <$ptr<[T]> as DeserializeHelper<<T as CopyType>::Copy>>::DeserType<'a>;
+            #[inline(always)]
+            fn _deserialize_full_inner(backend: &mut impl ReadWithPos) -> deser::Result<Self> {
+                <$ptr<[T]> as DeserializeHelper<<T as CopyType>::Copy>>::_deserialize_full_inner_impl(
+                    backend,
+                )
+            }
+
+            #[inline(always)]
+            fn _deserialize_eps_inner<'a>(
+                backend: &mut SliceWithPos<'a>,
+            ) -> deser::Result<
+                <$ptr<[T]> as DeserializeHelper<<T as CopyType>::Copy>>::DeserType<'a>,
+            > {
+                <$ptr<[T]> as DeserializeHelper<<T as CopyType>::Copy>>::_deserialize_eps_inner_impl(
+                    backend,
+                )
+            }
+        }
+
+        impl<T: ZeroCopy + DeserializeInner + 'static> DeserializeHelper<Zero> for $ptr<[T]> {
+            type FullType = Self;
+            type DeserType<'a> = &'a [T];
+            #[inline(always)]
+            fn _deserialize_full_inner_impl(backend: &mut impl ReadWithPos) -> deser::Result<Self> {
+                Ok(deserialize_full_vec_zero::<T>(backend)?.into())
+            }
+            #[inline(always)]
+            fn _deserialize_eps_inner_impl<'a>(
+                backend: &mut SliceWithPos<'a>,
+            ) -> deser::Result<<Self as DeserializeInner>::DeserType<'a>> {
+                deserialize_eps_slice_zero(backend)
+            }
+        }
+
+        impl<T: DeepCopy + DeserializeInner + 'static> DeserializeHelper<Deep> for $ptr<[T]> {
+            type FullType = Self;
+            type DeserType<'a> = $ptr<[<T as DeserializeInner>::DeserType<'a>]>;
+            #[inline(always)]
+            fn _deserialize_full_inner_impl(backend: &mut impl ReadWithPos) -> deser::Result<Self> {
+                Ok(deserialize_full_vec_deep(backend)?.into())
+            }
+            #[inline(always)]
+            fn _deserialize_eps_inner_impl<'a>(
+                backend: &mut SliceWithPos<'a>,
+            ) -> deser::Result<<Self as DeserializeInner>::DeserType<'a>> {
+                Ok(deserialize_eps_vec_deep::<T>(backend)?.into())
+            }
+        }
+    };
+}
+
+impl_shared_slice!(Rc);
+impl_shared_slice!(Arc);
@@ -16,7 +16,18 @@ We also provide a serialize-only (slightly cheaty) implementation
 for slices that deserializes to vectors.
 
 It is slightly cheaty in that it serializes a vector using the
-slice as a backing array, so it must be deserialized using a vector as type.
+slice as a backing array, so it must be deserialized using a vector as type:
+the bytes written by serializing a `&[T]` are exactly the same as those
+written by serializing a `Vec<T>` with the same content. Thus,
+[full-copy deserialization](crate::deser::Deserialize::deserialize_full)
+returns an owned `Vec<T>`, whereas
+[ε-copy deserialization](crate::deser::Deserialize::deserialize_eps)
+returns a `&[T]` if `T` is [zero-copy](ZeroCopy), and a vector
+of the ε-copy deserialization types of the elements if `T` is
+[deep-copy](DeepCopy).
+
+Mutable slices are serialized in the same way, so a `&mut [T]` can be
+passed where a [`Serialize`] instance is required without reborrowing.
 
 Note that if you ε-copy deserialize the vector, you will
 get back the same slice.
@@ -34,6 +45,21 @@ let b: &[i32] = <Vec<i32>>::deserialize_eps(cursor.as_bytes()).unwrap();
 assert_eq!(a, *b);
 ```
 
+Slices of deep-copy elements are serialized element by element:
+```rust
+use epserde::prelude::*;
+use maligned::A16;
+let a = vec!["a".to_string(), "bc".to_string()];
+let s = a.as_slice();
+let mut cursor = <AlignedCursor<A16>>::new();
+s.serialize(&mut cursor).unwrap();
+cursor.set_position(0);
+let b: Vec<String> = <Vec<String>>::deserialize_full(&mut cursor).unwrap();
+assert_eq!(a, b);
+let b: Vec<&str> = <Vec<String>>::deserialize_eps(cursor.as_bytes()).unwrap();
+assert_eq!(a, b);
+```
+
 */
 
 use crate::prelude::*;
@@ -67,3 +93,30 @@ where
         backend.flush()
     }
 }
+
+// Serialize requires TypeHash and ReprHash, so mutable slices just forward
+// to slices: the header is anyway that of a Vec<T>.
+
+impl<T: TypeHash> TypeHash for &mut [T] {
+    #[inline(always)]
+    fn type_hash(hasher: &mut impl core::hash::Hasher) {
+        <[T]>::type_hash(hasher);
+    }
+}
+
+impl<T> ReprHash for &mut [T] {
+    #[inline(always)]
+    fn repr_hash(hasher: &mut impl core::hash::Hasher, offset_of: &mut usize) {
+        <[T]>::repr_hash(hasher, offset_of);
+    }
+}
+
+impl<T: SerializeInner + CopyType + TypeHash + ReprHash> Serialize for &mut [T]
+where
+    Vec<T>: SerializeHelper<<T as CopyType>::Copy>,
+{
+    #[inline(always)]
+    fn serialize_on_field_write(&self, backend: &mut impl WriteWithNames) -> ser::Result<()> {
+        (**self).serialize_on_field_write(backend)
+    }
+}
@@ -0,0 +1,170 @@
+/*
+ * SPDX-FileCopyrightText: 2023 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+/*!
+
+Implementations for [`SmallVec`], available with the `smallvec` feature.
+
+A [`SmallVec`] is serialized exactly like a [`Vec`], that is, as a length
+followed by the elements. Full-copy deserialization stores the elements
+inline if they fit, and on the heap otherwise. ε-copy deserialization
+returns a slice if the elements are zero-copy, and a [`Vec`] of the
+ε-copy deserialization types of the elements otherwise.
+
+The inline capacity is not part of the type hash, as it does not
+influence the serialized data.
+
+*/
+
+use crate::deser;
+use crate::deser::helpers::*;
+use crate::deser::*;
+use crate::ser;
+use crate::ser::helpers::*;
+use crate::ser::*;
+use crate::traits::*;
+use ::smallvec::{Array, SmallVec};
+use core::hash::Hash;
+
+impl<A: Array> CopyType for SmallVec<A> {
+    type Copy = Deep;
+}
+
+impl<A: Array> TypeHash for SmallVec<A>
+where
+    A::Item: TypeHash,
+{
+    fn type_hash(hasher: &mut impl core::hash::Hasher) {
+        "SmallVec".hash(hasher);
+        A::Item::type_hash(hasher);
+    }
+}
+
+impl<A: Array> ReprHash for SmallVec<A>
+where
+    A::Item: ReprHash,
+{
+    fn repr_hash(hasher: &mut impl core::hash::Hasher, offset_of: &mut usize) {
+        *offset_of = 0;
+        A::Item::repr_hash(hasher, offset_of);
+    }
+}
+
+impl<A: Array> SerializeInner for SmallVec<A>
+where
+    A::Item: CopyType + SerializeInner + TypeHash,
+    SmallVec<A>: SerializeHelper<<A::Item as CopyType>::Copy>,
+{
+    const IS_ZERO_COPY: bool = false;
+    const ZERO_COPY_MISMATCH: bool = false;
+    fn _serialize_inner(&self, backend: &mut impl WriteWithNames) -> ser::Result<()> {
+        SerializeHelper::_serialize_inner(self, backend)
+    }
+}
+
+impl<A: Array> SerializeHelper<Zero> for SmallVec<A>
+where
+    A::Item: ZeroCopy + SerializeInner,
+{
+    #[inline(always)]
+    fn _serialize_inner(&self, backend: &mut impl WriteWithNames) -> ser::Result<()> {
+        serialize_slice_zero(backend, self.as_slice())
+    }
+}
+
+impl<A: Array> SerializeHelper<Deep> for SmallVec<A>
+where
+    A::Item: DeepCopy + SerializeInner,
+{
+    #[inline(always)]
+    fn _serialize_inner(&self, backend: &mut impl WriteWithNames) -> ser::Result<()> {
+        serialize_slice_deep(backend, self.as_slice())
+    }
+}
+
+impl<A: Array> DeserializeInner for SmallVec<A>
+where
+    A::Item: CopyType + DeserializeInner + 'static,
+    SmallVec<A>: DeserializeHelper<<A::Item as CopyType>::Copy, FullType = SmallVec<A>>,
+{
+    type DeserType<'a> =
+        <SmallVec<A> as DeserializeHelper<<A::Item as CopyType>::Copy>>::DeserType<'a>;
+    #[inline(always)]
+    fn _deserialize_full_inner(backend: &mut impl ReadWithPos) -> deser::Result<Self> {
+        <SmallVec<A> as DeserializeHelper<<A::Item as CopyType>::Copy>>::_deserialize_full_inner_impl(
+            backend,
+        )
+    }
+
+    #[inline(always)]
+    fn _deserialize_eps_inner<'a>(
+        backend: &mut SliceWithPos<'a>,
+    ) -> deser::Result<<SmallVec<A> as DeserializeHelper<<A::Item as CopyType>::Copy>>::DeserType<'a>>
+    {
+        <SmallVec<A> as DeserializeHelper<<A::Item as CopyType>::Copy>>::_deserialize_eps_inner_impl(
+            backend,
+        )
+    }
+}
+
+impl<A: Array> DeserializeHelper<Zero> for SmallVec<A>
+where
+    A::Item: ZeroCopy + DeserializeInner + 'static,
+{
+    type FullType = Self;
+    type DeserType<'a> = &'a [A::Item];
+    #[inline(always)]
+    fn _deserialize_full_inner_impl(backend: &mut impl ReadWithPos) -> deser::Result<Self> {
+        let len = usize::_deserialize_full_inner(backend)?;
+        backend.align::<A::Item>()?;
+        if len > A::size() {
+            // The elements are stored on the heap anyway
+            return Ok(SmallVec::from_vec(read_vec_zero(backend, len)?));
+        }
+        let mut res = SmallVec::new();
+        // SAFETY: the inline storage has room for len elements, and
+        // read_exact guarantees that they will be filled with data.
+        unsafe {
+            let buf = core::slice::from_raw_parts_mut(
+                res.as_mut_ptr() as *mut u8,
+                len * core::mem::size_of::<A::Item>(),
+            );
+            backend.read_exact(buf)?;
+            check_values::<A::Item>(buf)?;
+            res.set_len(len);
+        }
+        Ok(res)
+    }
+    #[inline(always)]
+    fn _deserialize_eps_inner_impl<'a>(
+        backend: &mut SliceWithPos<'a>,
+    ) -> deser::Result<<Self as DeserializeInner>::DeserType<'a>> {
+        deserialize_eps_slice_zero(backend)
+    }
+}
+
+impl<A: Array> DeserializeHelper<Deep> for SmallVec<A>
+where
+    A::Item: DeepCopy + DeserializeInner + 'static,
+{
+    type FullType = Self;
+    type DeserType<'a> = Vec<<A::Item as DeserializeInner>::DeserType<'a>>;
+    #[inline(always)]
+    fn _deserialize_full_inner_impl(backend: &mut impl ReadWithPos) -> deser::Result<Self> {
+        let len = usize::_deserialize_full_inner(backend)?;
+        let mut res = SmallVec::with_capacity(prealloc_len::<A::Item>(backend, len));
+        for _ in 0..len {
+            res.push(A::Item::_deserialize_full_inner(backend)?);
+        }
+        Ok(res)
+    }
+    #[inline(always)]
+    fn _deserialize_eps_inner_impl<'a>(
+        backend: &mut SliceWithPos<'a>,
+    ) -> deser::Result<<Self as DeserializeInner>::DeserType<'a>> {
+        deserialize_eps_vec_deep::<A::Item>(backend)
+    }
+}
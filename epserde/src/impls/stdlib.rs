@@ -12,7 +12,9 @@ use core::{
     hash::Hash,
     ops::{Bound, RangeBounds},
 };
-use std::collections::hash_map::DefaultHasher;
+use std::borrow::Cow;
+use std::collections::hash_map::{DefaultHasher, HashMap, RandomState};
+use std::hash::BuildHasher;
 
 impl TypeHash for DefaultHasher {
     fn type_hash(hasher: &mut impl core::hash::Hasher) {
@@ -32,10 +34,25 @@ impl MaxSizeOf for DefaultHasher {
     }
 }
 
+impl TypeHash for RandomState {
+    fn type_hash(hasher: &mut impl core::hash::Hasher) {
+        "std::hash::RandomState".hash(hasher);
+    }
+}
+
+impl<H: TypeHash> TypeHash for core::hash::BuildHasherDefault<H> {
+    fn type_hash(hasher: &mut impl core::hash::Hasher) {
+        "core::hash::BuildHasherDefault".hash(hasher);
+        H::type_hash(hasher);
+    }
+}
+
+// Ranges are deep-copy even when Idx is zero-copy, as they do not implement
+// Copy; their bounds are serialized as fields, so Idx can be deep-copy, too.
 macro_rules! impl_ranges {
     ($ty:ident) => {
-        impl<Idx: CopyType> CopyType for core::ops::$ty<Idx> {
-            type Copy = Zero;
+        impl<Idx> CopyType for core::ops::$ty<Idx> {
+            type Copy = Deep;
         }
 
         impl<Idx: TypeHash> TypeHash for core::ops::$ty<Idx> {
@@ -47,13 +64,8 @@ macro_rules! impl_ranges {
 
         impl<Idx: ReprHash> ReprHash for core::ops::$ty<Idx> {
             fn repr_hash(hasher: &mut impl core::hash::Hasher, offset_of: &mut usize) {
-                crate::traits::std_repr_hash::<Self>(hasher, offset_of)
-            }
-        }
-
-        impl<Idx: MaxSizeOf> MaxSizeOf for core::ops::$ty<Idx> {
-            fn max_size_of() -> usize {
-                core::mem::size_of::<Self>()
+                *offset_of = 0;
+                Idx::repr_hash_isolated(hasher, offset_of);
             }
         }
     };
@@ -66,7 +78,7 @@ impl_ranges!(RangeTo);
 impl_ranges!(RangeToInclusive);
 
 impl<Idx: SerializeInner> SerializeInner for core::ops::Range<Idx> {
-    const IS_ZERO_COPY: bool = true;
+    const IS_ZERO_COPY: bool = false;
     const ZERO_COPY_MISMATCH: bool = false;
 
     #[inline(always)]
@@ -96,7 +108,7 @@ impl<Idx: DeserializeInner> DeserializeInner for core::ops::Range<Idx> {
 }
 
 impl<Idx: SerializeInner> SerializeInner for core::ops::RangeFrom<Idx> {
-    const IS_ZERO_COPY: bool = true;
+    const IS_ZERO_COPY: bool = false;
     const ZERO_COPY_MISMATCH: bool = false;
 
     #[inline(always)]
@@ -123,7 +135,7 @@ impl<Idx: DeserializeInner> DeserializeInner for core::ops::RangeFrom<Idx> {
 }
 
 impl<Idx: SerializeInner> SerializeInner for core::ops::RangeInclusive<Idx> {
-    const IS_ZERO_COPY: bool = true;
+    const IS_ZERO_COPY: bool = false;
     const ZERO_COPY_MISMATCH: bool = false;
 
     #[inline(always)]
@@ -158,7 +170,7 @@ impl<Idx: DeserializeInner> DeserializeInner for core::ops::RangeInclusive<Idx>
 }
 
 impl<Idx: SerializeInner> SerializeInner for core::ops::RangeTo<Idx> {
-    const IS_ZERO_COPY: bool = true;
+    const IS_ZERO_COPY: bool = false;
     const ZERO_COPY_MISMATCH: bool = false;
 
     #[inline(always)]
@@ -185,7 +197,7 @@ impl<Idx: DeserializeInner> DeserializeInner for core::ops::RangeTo<Idx> {
 }
 
 impl<Idx: SerializeInner> SerializeInner for core::ops::RangeToInclusive<Idx> {
-    const IS_ZERO_COPY: bool = true;
+    const IS_ZERO_COPY: bool = false;
     const ZERO_COPY_MISMATCH: bool = false;
 
     #[inline(always)]
@@ -229,7 +241,8 @@ impl ReprHash for core::ops::RangeFull {
 
 impl MaxSizeOf for core::ops::RangeFull {
     fn max_size_of() -> usize {
-        core::mem::size_of::<Self>()
+        // The size is zero, but the alignment is one
+        1
     }
 }
 
@@ -335,6 +348,117 @@ impl<T: DeserializeInner> DeserializeInner for core::ops::Bound<T> {
     }
 }
 
+/// An interval delimited by two [bounds](Bound).
+///
+/// This type is a convenience for storing general intervals (e.g., with
+/// an excluded start bound) in a structure. It is serialized as its two
+/// bounds, and it implements [`RangeBounds`].
+///
+/// ```rust
+/// use core::ops::Bound;
+/// use epserde::impls::stdlib::Interval;
+///
+/// let interval = Interval::new(Bound::Excluded(0), Bound::Included(10));
+/// assert!(!interval.contains(&0));
+/// assert!(interval.contains(&10));
+///
+/// let interval = Interval::from_range(..5);
+/// assert_eq!(interval.start, Bound::Unbounded);
+/// assert_eq!(interval.end, Bound::Excluded(5));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Interval<T> {
+    /// The start bound.
+    pub start: Bound<T>,
+    /// The end bound.
+    pub end: Bound<T>,
+}
+
+impl<T> Interval<T> {
+    /// Create a new interval from its bounds.
+    pub fn new(start: Bound<T>, end: Bound<T>) -> Self {
+        Self { start, end }
+    }
+
+    /// Create a new interval with the same bounds as the given range.
+    pub fn from_range(range: impl RangeBounds<T>) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            start: range.start_bound().cloned(),
+            end: range.end_bound().cloned(),
+        }
+    }
+
+    /// Return whether `value` is contained in the interval.
+    pub fn contains(&self, value: &T) -> bool
+    where
+        T: PartialOrd,
+    {
+        RangeBounds::contains(self, value)
+    }
+}
+
+impl<T> RangeBounds<T> for Interval<T> {
+    fn start_bound(&self) -> Bound<&T> {
+        self.start.as_ref()
+    }
+
+    fn end_bound(&self) -> Bound<&T> {
+        self.end.as_ref()
+    }
+}
+
+impl<T> CopyType for Interval<T> {
+    type Copy = Deep;
+}
+
+impl<T: TypeHash> TypeHash for Interval<T> {
+    fn type_hash(hasher: &mut impl core::hash::Hasher) {
+        "Interval".hash(hasher);
+        T::type_hash(hasher);
+    }
+}
+
+impl<T: ReprHash> ReprHash for Interval<T> {
+    fn repr_hash(hasher: &mut impl core::hash::Hasher, offset_of: &mut usize) {
+        *offset_of = 0;
+        Bound::<T>::repr_hash(hasher, offset_of);
+        *offset_of = 0;
+        Bound::<T>::repr_hash(hasher, offset_of);
+    }
+}
+
+impl<T: SerializeInner> SerializeInner for Interval<T> {
+    const IS_ZERO_COPY: bool = false;
+    const ZERO_COPY_MISMATCH: bool = false;
+
+    #[inline(always)]
+    fn _serialize_inner(&self, backend: &mut impl WriteWithNames) -> ser::Result<()> {
+        backend.write("start", &self.start)?;
+        backend.write("end", &self.end)
+    }
+}
+
+impl<T: DeserializeInner> DeserializeInner for Interval<T> {
+    #[inline(always)]
+    fn _deserialize_full_inner(backend: &mut impl ReadWithPos) -> deser::Result<Self> {
+        let start = Bound::<T>::_deserialize_full_inner(backend)?;
+        let end = Bound::<T>::_deserialize_full_inner(backend)?;
+        Ok(Interval { start, end })
+    }
+    type DeserType<'a> = Interval<<T as DeserializeInner>::DeserType<'a>>;
+    #[inline(always)]
+    fn _deserialize_eps_inner<'a>(
+        backend: &mut SliceWithPos<'a>,
+    ) -> deser::Result<Self::DeserType<'a>> {
+        let start = Bound::<T>::_deserialize_eps_inner(backend)?;
+        let end = Bound::<T>::_deserialize_eps_inner(backend)?;
+        Ok(Interval { start, end })
+    }
+}
+
 impl<B: CopyType, C: CopyType> CopyType for core::ops::ControlFlow<B, C> {
     type Copy = Deep;
 }
@@ -416,3 +540,440 @@ impl<B: DeserializeInner, C: DeserializeInner> DeserializeInner for core::ops::C
         }
     }
 }
+
+// Duration is deep-copy: its fields are private and it is not `repr(C)`, so
+// its layout is not guaranteed to be stable across compiler versions or
+// architectures. We serialize the number of seconds and the number of
+// nanoseconds separately, so the serialized form is independent of the
+// in-memory layout.
+
+impl CopyType for core::time::Duration {
+    type Copy = Deep;
+}
+
+impl TypeHash for core::time::Duration {
+    fn type_hash(hasher: &mut impl core::hash::Hasher) {
+        "core::time::Duration".hash(hasher);
+    }
+}
+
+impl ReprHash for core::time::Duration {
+    fn repr_hash(hasher: &mut impl core::hash::Hasher, offset_of: &mut usize) {
+        *offset_of = 0;
+        u64::repr_hash(hasher, offset_of);
+        *offset_of = 0;
+        u32::repr_hash(hasher, offset_of);
+    }
+}
+
+impl SerializeInner for core::time::Duration {
+    const IS_ZERO_COPY: bool = false;
+    const ZERO_COPY_MISMATCH: bool = false;
+
+    #[inline(always)]
+    fn _serialize_inner(&self, backend: &mut impl WriteWithNames) -> ser::Result<()> {
+        backend.write("secs", &self.as_secs())?;
+        backend.write("nanos", &self.subsec_nanos())?;
+        Ok(())
+    }
+}
+
+impl DeserializeInner for core::time::Duration {
+    #[inline(always)]
+    fn _deserialize_full_inner(backend: &mut impl ReadWithPos) -> deser::Result<Self> {
+        let secs = u64::_deserialize_full_inner(backend)?;
+        let nanos = u32::_deserialize_full_inner(backend)?;
+        // Duration::new would carry the excess nanoseconds into the seconds,
+        // possibly panicking on overflow
+        if nanos >= 1_000_000_000 {
+            return Err(deser::Error::InvalidDuration { secs, nanos });
+        }
+        Ok(core::time::Duration::new(secs, nanos))
+    }
+    type DeserType<'a> = core::time::Duration;
+    #[inline(always)]
+    fn _deserialize_eps_inner<'a>(
+        backend: &mut SliceWithPos<'a>,
+    ) -> deser::Result<Self::DeserType<'a>> {
+        Self::_deserialize_full_inner(backend)
+    }
+}
+
+// Ordering is deep-copy, and it is serialized as a u8 tag (0 for Less, 1 for
+// Equal, and 2 for Greater), independently of its in-memory representation.
+
+impl CopyType for core::cmp::Ordering {
+    type Copy = Deep;
+}
+
+impl TypeHash for core::cmp::Ordering {
+    fn type_hash(hasher: &mut impl core::hash::Hasher) {
+        "core::cmp::Ordering".hash(hasher);
+    }
+}
+
+impl ReprHash for core::cmp::Ordering {
+    fn repr_hash(hasher: &mut impl core::hash::Hasher, offset_of: &mut usize) {
+        *offset_of = 0;
+        u8::repr_hash(hasher, offset_of);
+    }
+}
+
+impl SerializeInner for core::cmp::Ordering {
+    const IS_ZERO_COPY: bool = false;
+    const ZERO_COPY_MISMATCH: bool = false;
+
+    #[inline(always)]
+    fn _serialize_inner(&self, backend: &mut impl WriteWithNames) -> ser::Result<()> {
+        let tag: u8 = match self {
+            core::cmp::Ordering::Less => 0,
+            core::cmp::Ordering::Equal => 1,
+            core::cmp::Ordering::Greater => 2,
+        };
+        backend.write("Tag", &tag)
+    }
+}
+
+impl DeserializeInner for core::cmp::Ordering {
+    #[inline(always)]
+    fn _deserialize_full_inner(backend: &mut impl ReadWithPos) -> deser::Result<Self> {
+        let tag = u8::_deserialize_full_inner(backend)?;
+        match tag {
+            0 => Ok(core::cmp::Ordering::Less),
+            1 => Ok(core::cmp::Ordering::Equal),
+            2 => Ok(core::cmp::Ordering::Greater),
+            _ => Err(deser::Error::InvalidTag(tag as usize)),
+        }
+    }
+    type DeserType<'a> = core::cmp::Ordering;
+    #[inline(always)]
+    fn _deserialize_eps_inner<'a>(
+        backend: &mut SliceWithPos<'a>,
+    ) -> deser::Result<Self::DeserType<'a>> {
+        Self::_deserialize_full_inner(backend)
+    }
+}
+
+// Boxes are deep-copy and are serialized as their content, so they make it
+// possible to serialize recursive types. The ε-copy deserialization type is a
+// box containing the ε-copy deserialization type of the content.
+//
+// Since the type and representation hashes of a type include those of its
+// fields, the hashes of a recursive type would recurse indefinitely: thus,
+// when computing the hashes of the content of a box we keep track of the types
+// whose hashes are being computed, and if a type is met again we just hash a
+// marker.
+
+std::thread_local! {
+    static BOX_HASH_STACK: core::cell::RefCell<Vec<core::any::TypeId>> =
+        const { core::cell::RefCell::new(Vec::new()) };
+}
+
+/// A guard recording that the hashes of the content of a box are being
+/// computed.
+struct BoxHashGuard;
+
+impl BoxHashGuard {
+    /// Return a guard, or `None` if the hashes of `T` are already being
+    /// computed.
+    fn enter<T: 'static>() -> Option<Self> {
+        let type_id = core::any::TypeId::of::<T>();
+        BOX_HASH_STACK.with(|stack| {
+            let mut stack = stack.borrow_mut();
+            if stack.contains(&type_id) {
+                None
+            } else {
+                stack.push(type_id);
+                Some(BoxHashGuard)
+            }
+        })
+    }
+}
+
+impl Drop for BoxHashGuard {
+    fn drop(&mut self) {
+        BOX_HASH_STACK.with(|stack| stack.borrow_mut().pop());
+    }
+}
+
+impl<T> CopyType for Box<T> {
+    type Copy = Deep;
+}
+
+impl<T: TypeHash + 'static> TypeHash for Box<T> {
+    fn type_hash(hasher: &mut impl core::hash::Hasher) {
+        "Box".hash(hasher);
+        match BoxHashGuard::enter::<T>() {
+            Some(_guard) => T::type_hash(hasher),
+            None => "Recursive".hash(hasher),
+        }
+    }
+}
+
+impl<T: ReprHash + 'static> ReprHash for Box<T> {
+    fn repr_hash(hasher: &mut impl core::hash::Hasher, offset_of: &mut usize) {
+        *offset_of = 0;
+        match BoxHashGuard::enter::<T>() {
+            Some(_guard) => T::repr_hash_isolated(hasher, offset_of),
+            None => "Recursive".hash(hasher),
+        }
+    }
+}
+
+impl<T: SerializeInner> SerializeInner for Box<T> {
+    const IS_ZERO_COPY: bool = false;
+    const ZERO_COPY_MISMATCH: bool = false;
+
+    #[inline(always)]
+    fn _serialize_inner(&self, backend: &mut impl WriteWithNames) -> ser::Result<()> {
+        backend.write("content", self.as_ref())
+    }
+}
+
+impl<T: DeserializeInner> DeserializeInner for Box<T> {
+    #[inline(always)]
+    fn _deserialize_full_inner(backend: &mut impl ReadWithPos) -> deser::Result<Self> {
+        Ok(Box::new(T::_deserialize_full_inner(backend)?))
+    }
+    type DeserType<'a> = Box<DeserType<'a, T>>;
+    #[inline(always)]
+    fn _deserialize_eps_inner<'a>(
+        backend: &mut SliceWithPos<'a>,
+    ) -> deser::Result<Self::DeserType<'a>> {
+        Ok(Box::new(T::_deserialize_eps_inner(backend)?))
+    }
+}
+
+// Mutexes and read-write locks are deep-copy and are serialized as their
+// content, which is accessed by acquiring the lock: serializing a poisoned
+// lock returns a ser::Error::PoisonedLock error. Deserialization wraps a
+// freshly deserialized value in a new lock, and the ε-copy deserialization
+// type is a lock containing the ε-copy deserialization type of the content.
+
+macro_rules! impl_lock {
+    ($lock:ident, $acquire:ident) => {
+        impl<T> CopyType for std::sync::$lock<T> {
+            type Copy = Deep;
+        }
+
+        impl<T: TypeHash> TypeHash for std::sync::$lock<T> {
+            fn type_hash(hasher: &mut impl core::hash::Hasher) {
+                stringify!($lock).hash(hasher);
+                T::type_hash(hasher);
+            }
+        }
+
+        impl<T: ReprHash> ReprHash for std::sync::$lock<T> {
+            fn repr_hash(hasher: &mut impl core::hash::Hasher, offset_of: &mut usize) {
+                *offset_of = 0;
+                T::repr_hash_isolated(hasher, offset_of);
+            }
+        }
+
+        impl<T: SerializeInner> SerializeInner for std::sync::$lock<T> {
+            const IS_ZERO_COPY: bool = false;
+            const ZERO_COPY_MISMATCH: bool = false;
+
+            fn _serialize_inner(&self, backend: &mut impl WriteWithNames) -> ser::Result<()> {
+                let content = self.$acquire().map_err(|_| ser::Error::PoisonedLock)?;
+                backend.write("content", &*content)
+            }
+        }
+
+        impl<T: DeserializeInner> DeserializeInner for std::sync::$lock<T> {
+            #[inline(always)]
+            fn _deserialize_full_inner(backend: &mut impl ReadWithPos) -> deser::Result<Self> {
+                Ok(std::sync::$lock::new(T::_deserialize_full_inner(backend)?))
+            }
+            type DeserType<'a> = std::sync::$lock<DeserType<'a, T>>;
+            #[inline(always)]
+            fn _deserialize_eps_inner<'a>(
+                backend: &mut SliceWithPos<'a>,
+            ) -> deser::Result<Self::DeserType<'a>> {
+                Ok(std::sync::$lock::new(T::_deserialize_eps_inner(backend)?))
+            }
+        }
+    };
+}
+
+impl_lock!(Mutex, lock);
+impl_lock!(RwLock, read);
+
+// Copy-on-write smart pointers to sized types are deep-copy and are
+// serialized as their content. Full-copy deserialization always returns an
+// owned value. ε-copy deserialization returns a borrowed value pointing into
+// the backend if the content is zero-copy; otherwise, since the ε-copy
+// deserialization type of the content is a different type, we return just
+// that, as it happens for boxes. Unsized types (e.g., `Cow<str>`) are not
+// supported.
+
+impl<T: Clone> CopyType for Cow<'_, T> {
+    type Copy = Deep;
+}
+
+impl<T: Clone + TypeHash> TypeHash for Cow<'_, T> {
+    fn type_hash(hasher: &mut impl core::hash::Hasher) {
+        "Cow".hash(hasher);
+        T::type_hash(hasher);
+    }
+}
+
+impl<T: Clone + ReprHash> ReprHash for Cow<'_, T> {
+    fn repr_hash(hasher: &mut impl core::hash::Hasher, offset_of: &mut usize) {
+        *offset_of = 0;
+        T::repr_hash_isolated(hasher, offset_of);
+    }
+}
+
+impl<T: Clone + SerializeInner> SerializeInner for Cow<'_, T> {
+    const IS_ZERO_COPY: bool = false;
+    const ZERO_COPY_MISMATCH: bool = false;
+
+    #[inline(always)]
+    fn _serialize_inner(&self, backend: &mut impl WriteWithNames) -> ser::Result<()> {
+        backend.write("content", self.as_ref())
+    }
+}
+
+impl<'a, T: Clone + CopyType + DeserializeInner> DeserializeInner for Cow<'a, T>
+where
+    Cow<'a, T>: DeserializeHelper<<T as CopyType>::Copy, FullType = Cow<'a, T>>,
+{
+    type DeserType<'b> = <Cow<'a, T> as DeserializeHelper<<T as CopyType>::Copy>>::DeserType<'b>;
+    #[inline(always)]
+    fn _deserialize_full_inner(backend: &mut impl ReadWithPos) -> deser::Result<Self> {
+        <Cow<'a, T> as DeserializeHelper<<T as CopyType>::Copy>>::_deserialize_full_inner_impl(
+            backend,
+        )
+    }
+
+    #[inline(always)]
+    fn _deserialize_eps_inner<'b>(
+        backend: &mut SliceWithPos<'b>,
+    ) -> deser::Result<Self::DeserType<'b>> {
+        <Cow<'a, T> as DeserializeHelper<<T as CopyType>::Copy>>::_deserialize_eps_inner_impl(
+            backend,
+        )
+    }
+}
+
+impl<'a, T: ZeroCopy + DeserializeInner> DeserializeHelper<Zero> for Cow<'a, T> {
+    type FullType = Self;
+    type DeserType<'b> = Cow<'b, T>;
+    #[inline(always)]
+    fn _deserialize_full_inner_impl(backend: &mut impl ReadWithPos) -> deser::Result<Self> {
+        Ok(Cow::Owned(T::_deserialize_full_inner(backend)?))
+    }
+    #[inline(always)]
+    fn _deserialize_eps_inner_impl<'b>(
+        backend: &mut SliceWithPos<'b>,
+    ) -> deser::Result<Self::DeserType<'b>> {
+        Ok(Cow::Borrowed(deser::helpers::deserialize_eps_zero(
+            backend,
+        )?))
+    }
+}
+
+impl<'a, T: DeepCopy + Clone + DeserializeInner> DeserializeHelper<Deep> for Cow<'a, T> {
+    type FullType = Self;
+    type DeserType<'b> = DeserType<'b, T>;
+    #[inline(always)]
+    fn _deserialize_full_inner_impl(backend: &mut impl ReadWithPos) -> deser::Result<Self> {
+        Ok(Cow::Owned(T::_deserialize_full_inner(backend)?))
+    }
+    #[inline(always)]
+    fn _deserialize_eps_inner_impl<'b>(
+        backend: &mut SliceWithPos<'b>,
+    ) -> deser::Result<Self::DeserType<'b>> {
+        T::_deserialize_eps_inner(backend)
+    }
+}
+
+// Hash maps are deep-copy and are serialized as a length followed by the
+// entries, in iteration order (or sorted by the serialized bytes of the keys,
+// if the backend is deterministic). The state of the hasher is not serialized: maps
+// are reconstructed using the default value of the hasher type, which however
+// is part of the type hash, so maps with different hasher types cannot be
+// deserialized one into the other. The ε-copy deserialization type is a
+// hash map whose keys and values are the ε-copy deserialization types of the
+// keys and values.
+
+impl<K, V, S> CopyType for HashMap<K, V, S> {
+    type Copy = Deep;
+}
+
+impl<K: TypeHash, V: TypeHash, S: TypeHash> TypeHash for HashMap<K, V, S> {
+    fn type_hash(hasher: &mut impl core::hash::Hasher) {
+        "HashMap".hash(hasher);
+        K::type_hash(hasher);
+        V::type_hash(hasher);
+        S::type_hash(hasher);
+    }
+}
+
+impl<K: ReprHash, V: ReprHash, S> ReprHash for HashMap<K, V, S> {
+    fn repr_hash(hasher: &mut impl core::hash::Hasher, offset_of: &mut usize) {
+        *offset_of = 0;
+        K::repr_hash_isolated(hasher, offset_of);
+        *offset_of = 0;
+        V::repr_hash_isolated(hasher, offset_of);
+    }
+}
+
+impl<K: SerializeInner, V: SerializeInner, S> SerializeInner for HashMap<K, V, S> {
+    const IS_ZERO_COPY: bool = false;
+    const ZERO_COPY_MISMATCH: bool = false;
+
+    fn _serialize_inner(&self, backend: &mut impl WriteWithNames) -> ser::Result<()> {
+        backend.write("len", &self.len())?;
+        if backend.deterministic() {
+            for (key, value) in ser::helpers::sort_by_key_bytes(self)? {
+                backend.write("key", key)?;
+                backend.write("value", value)?;
+            }
+        } else {
+            for (key, value) in self {
+                backend.write("key", key)?;
+                backend.write("value", value)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<K, V, S> DeserializeInner for HashMap<K, V, S>
+where
+    K: DeserializeInner + Eq + Hash,
+    V: DeserializeInner,
+    S: BuildHasher + Default,
+    for<'a> DeserType<'a, K>: Eq + Hash,
+{
+    fn _deserialize_full_inner(backend: &mut impl ReadWithPos) -> deser::Result<Self> {
+        let len = usize::_deserialize_full_inner(backend)?;
+        let capacity = deser::helpers::prealloc_len::<(K, V)>(backend, len);
+        let mut res = HashMap::with_capacity_and_hasher(capacity, S::default());
+        for _ in 0..len {
+            let key = K::_deserialize_full_inner(backend)?;
+            let value = V::_deserialize_full_inner(backend)?;
+            res.insert(key, value);
+        }
+        Ok(res)
+    }
+
+    type DeserType<'a> = HashMap<DeserType<'a, K>, DeserType<'a, V>, S>;
+
+    fn _deserialize_eps_inner<'a>(
+        backend: &mut SliceWithPos<'a>,
+    ) -> deser::Result<Self::DeserType<'a>> {
+        let len = usize::_deserialize_full_inner(backend)?;
+        let capacity =
+            deser::helpers::prealloc_len::<(DeserType<'a, K>, DeserType<'a, V>)>(backend, len);
+        let mut res = HashMap::with_capacity_and_hasher(capacity, S::default());
+        for _ in 0..len {
+            let key = K::_deserialize_eps_inner(backend)?;
+            let value = V::_deserialize_eps_inner(backend)?;
+            res.insert(key, value);
+        }
+        Ok(res)
+    }
+}
@@ -9,6 +9,17 @@
 
 Implementations for strings.
 
+Besides [`String`], we support `Box<str>`, `Rc<str>`, and `Arc<str>`: all these
+types have the same serialized representation as [`String`], and ε-copy
+deserialize to a `&str`, but they have different type hashes.
+
+[`CString`](std::ffi::CString) is serialized as the length of its bytes,
+excluding the terminating nul, followed by the bytes, including the
+terminating nul, so that it can be ε-copy deserialized to a borrowed
+[`&CStr`](std::ffi::CStr). Data with an interior nul byte or without a
+terminating nul is rejected with an
+[`InvalidCString`](deser::Error::InvalidCString) error.
+
 */
 
 use crate::prelude::*;
@@ -22,6 +33,10 @@ impl CopyType for String {
 
 #[cfg(all(feature = "alloc", not(feature = "std")))]
 use alloc::string::String;
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use alloc::{rc::Rc, sync::Arc};
+#[cfg(feature = "std")]
+use std::{rc::Rc, sync::Arc};
 
 #[cfg(feature = "alloc")]
 impl TypeHash for String {
@@ -66,8 +81,10 @@ impl SerializeInner for String {
 
 impl DeserializeInner for String {
     fn _deserialize_full_inner(backend: &mut impl ReadWithPos) -> deser::Result<Self> {
+        // The bytes are read directly into the buffer of the vector, which
+        // becomes the buffer of the string after validation, with no copy
         let slice = deserialize_full_vec_zero(backend)?;
-        Ok(String::from_utf8(slice).unwrap())
+        String::from_utf8(slice).map_err(|e| deser::Error::InvalidUtf8(e.utf8_error()))
     }
     type DeserType<'a> = &'a str;
     #[inline(always)]
@@ -75,10 +92,7 @@ impl DeserializeInner for String {
         backend: &mut SliceWithPos<'a>,
     ) -> deser::Result<Self::DeserType<'a>> {
         let slice = deserialize_eps_slice_zero(backend)?;
-        Ok(unsafe {
-            #[allow(clippy::transmute_bytes_to_str)]
-            core::mem::transmute::<&'_ [u8], &'_ str>(slice)
-        })
+        core::str::from_utf8(slice).map_err(deser::Error::InvalidUtf8)
     }
 }
 
@@ -109,3 +123,102 @@ impl DeserializeInner for Box<str> {
         String::_deserialize_eps_inner(backend)
     }
 }
+
+macro_rules! impl_shared_str {
+    ($ptr:ident) => {
+        impl CopyType for $ptr<str> {
+            type Copy = Deep;
+        }
+
+        impl TypeHash for $ptr<str> {
+            fn type_hash(hasher: &mut impl core::hash::Hasher) {
+                stringify!($ptr).hash(hasher);
+                str::type_hash(hasher);
+            }
+        }
+
+        impl ReprHash for $ptr<str> {
+            fn repr_hash(_hasher: &mut impl core::hash::Hasher, _offset_of: &mut usize) {}
+        }
+
+        impl SerializeInner for $ptr<str> {
+            const IS_ZERO_COPY: bool = false;
+            const ZERO_COPY_MISMATCH: bool = false;
+
+            fn _serialize_inner(&self, backend: &mut impl WriteWithNames) -> ser::Result<()> {
+                serialize_slice_zero(backend, self.as_bytes())
+            }
+        }
+
+        impl DeserializeInner for $ptr<str> {
+            #[inline(always)]
+            fn _deserialize_full_inner(backend: &mut impl ReadWithPos) -> deser::Result<Self> {
+                Ok(String::_deserialize_full_inner(backend)?.into())
+            }
+            type DeserType<'a> = &'a str;
+            #[inline(always)]
+            fn _deserialize_eps_inner<'a>(
+                backend: &mut SliceWithPos<'a>,
+            ) -> deser::Result<Self::DeserType<'a>> {
+                String::_deserialize_eps_inner(backend)
+            }
+        }
+    };
+}
+
+impl_shared_str!(Rc);
+impl_shared_str!(Arc);
+
+#[cfg(feature = "std")]
+impl CopyType for std::ffi::CString {
+    type Copy = Deep;
+}
+
+#[cfg(feature = "std")]
+impl TypeHash for std::ffi::CString {
+    fn type_hash(hasher: &mut impl core::hash::Hasher) {
+        "CString".hash(hasher);
+    }
+}
+
+#[cfg(feature = "std")]
+impl ReprHash for std::ffi::CString {
+    fn repr_hash(_hasher: &mut impl core::hash::Hasher, _offset_of: &mut usize) {}
+}
+
+#[cfg(feature = "std")]
+impl SerializeInner for std::ffi::CString {
+    const IS_ZERO_COPY: bool = false;
+    const ZERO_COPY_MISMATCH: bool = false;
+
+    fn _serialize_inner(&self, backend: &mut impl WriteWithNames) -> ser::Result<()> {
+        let bytes = self.as_bytes_with_nul();
+        backend.write("len", &(bytes.len() - 1))?;
+        backend.write_bytes::<u8>(bytes)
+    }
+}
+
+/// Return the length of the bytes of a serialized C string, including the
+/// terminating nul.
+#[cfg(feature = "std")]
+fn cstring_len(backend: &mut impl ReadWithPos) -> deser::Result<usize> {
+    let len = usize::_deserialize_full_inner(backend)?;
+    len.checked_add(1).ok_or(deser::Error::LengthTooLarge(len))
+}
+
+#[cfg(feature = "std")]
+impl DeserializeInner for std::ffi::CString {
+    fn _deserialize_full_inner(backend: &mut impl ReadWithPos) -> deser::Result<Self> {
+        let len = cstring_len(backend)?;
+        let bytes = read_vec_zero::<u8>(backend, len)?;
+        std::ffi::CString::from_vec_with_nul(bytes).map_err(|_| deser::Error::InvalidCString)
+    }
+    type DeserType<'a> = &'a std::ffi::CStr;
+    fn _deserialize_eps_inner<'a>(
+        backend: &mut SliceWithPos<'a>,
+    ) -> deser::Result<Self::DeserType<'a>> {
+        let len = cstring_len(backend)?;
+        let bytes = slice_zero::<u8>(backend, len)?;
+        std::ffi::CStr::from_bytes_with_nul(bytes).map_err(|_| deser::Error::InvalidCString)
+    }
+}
@@ -0,0 +1,170 @@
+/*
+ * SPDX-FileCopyrightText: 2023 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+/*!
+
+Implementations for [`TinyVec`], available with the `tinyvec` feature.
+
+A [`TinyVec`] is serialized exactly like a [`Vec`], that is, as a length
+followed by the elements. Full-copy deserialization stores the elements
+inline if they fit, and on the heap otherwise. ε-copy deserialization
+returns a slice if the elements are zero-copy, and a [`Vec`] of the
+ε-copy deserialization types of the elements otherwise.
+
+The inline capacity is not part of the type hash, as it does not
+influence the serialized data.
+
+*/
+
+use crate::deser;
+use crate::deser::helpers::*;
+use crate::deser::*;
+use crate::ser;
+use crate::ser::helpers::*;
+use crate::ser::*;
+use crate::traits::*;
+use ::tinyvec::{Array, ArrayVec, TinyVec};
+use core::hash::Hash;
+
+impl<A: Array> CopyType for TinyVec<A> {
+    type Copy = Deep;
+}
+
+impl<A: Array> TypeHash for TinyVec<A>
+where
+    A::Item: TypeHash,
+{
+    fn type_hash(hasher: &mut impl core::hash::Hasher) {
+        "TinyVec".hash(hasher);
+        A::Item::type_hash(hasher);
+    }
+}
+
+impl<A: Array> ReprHash for TinyVec<A>
+where
+    A::Item: ReprHash,
+{
+    fn repr_hash(hasher: &mut impl core::hash::Hasher, offset_of: &mut usize) {
+        *offset_of = 0;
+        A::Item::repr_hash(hasher, offset_of);
+    }
+}
+
+impl<A: Array> SerializeInner for TinyVec<A>
+where
+    A::Item: CopyType + SerializeInner + TypeHash,
+    TinyVec<A>: SerializeHelper<<A::Item as CopyType>::Copy>,
+{
+    const IS_ZERO_COPY: bool = false;
+    const ZERO_COPY_MISMATCH: bool = false;
+    fn _serialize_inner(&self, backend: &mut impl WriteWithNames) -> ser::Result<()> {
+        SerializeHelper::_serialize_inner(self, backend)
+    }
+}
+
+impl<A: Array> SerializeHelper<Zero> for TinyVec<A>
+where
+    A::Item: ZeroCopy + SerializeInner,
+{
+    #[inline(always)]
+    fn _serialize_inner(&self, backend: &mut impl WriteWithNames) -> ser::Result<()> {
+        serialize_slice_zero(backend, self.as_slice())
+    }
+}
+
+impl<A: Array> SerializeHelper<Deep> for TinyVec<A>
+where
+    A::Item: DeepCopy + SerializeInner,
+{
+    #[inline(always)]
+    fn _serialize_inner(&self, backend: &mut impl WriteWithNames) -> ser::Result<()> {
+        serialize_slice_deep(backend, self.as_slice())
+    }
+}
+
+impl<A: Array> DeserializeInner for TinyVec<A>
+where
+    A::Item: CopyType + DeserializeInner + 'static,
+    TinyVec<A>: DeserializeHelper<<A::Item as CopyType>::Copy, FullType = TinyVec<A>>,
+{
+    type DeserType<'a> =
+        <TinyVec<A> as DeserializeHelper<<A::Item as CopyType>::Copy>>::DeserType<'a>;
+    #[inline(always)]
+    fn _deserialize_full_inner(backend: &mut impl ReadWithPos) -> deser::Result<Self> {
+        <TinyVec<A> as DeserializeHelper<<A::Item as CopyType>::Copy>>::_deserialize_full_inner_impl(
+            backend,
+        )
+    }
+
+    #[inline(always)]
+    fn _deserialize_eps_inner<'a>(
+        backend: &mut SliceWithPos<'a>,
+    ) -> deser::Result<<TinyVec<A> as DeserializeHelper<<A::Item as CopyType>::Copy>>::DeserType<'a>>
+    {
+        <TinyVec<A> as DeserializeHelper<<A::Item as CopyType>::Copy>>::_deserialize_eps_inner_impl(
+            backend,
+        )
+    }
+}
+
+impl<A: Array> DeserializeHelper<Zero> for TinyVec<A>
+where
+    A::Item: ZeroCopy + DeserializeInner + 'static,
+{
+    type FullType = Self;
+    type DeserType<'a> = &'a [A::Item];
+    #[inline(always)]
+    fn _deserialize_full_inner_impl(backend: &mut impl ReadWithPos) -> deser::Result<Self> {
+        let len = usize::_deserialize_full_inner(backend)?;
+        backend.align::<A::Item>()?;
+        if len > A::CAPACITY {
+            // The elements are stored on the heap anyway
+            return Ok(TinyVec::Heap(read_vec_zero(backend, len)?));
+        }
+        let mut res = ArrayVec::<A>::new();
+        res.set_len(len);
+        // SAFETY: the inline storage contains len initialized elements, and
+        // read_exact guarantees that they will be overwritten with data.
+        unsafe {
+            let buf = core::slice::from_raw_parts_mut(
+                res.as_mut_slice().as_mut_ptr() as *mut u8,
+                len * core::mem::size_of::<A::Item>(),
+            );
+            backend.read_exact(buf)?;
+            check_values::<A::Item>(buf)?;
+        }
+        Ok(TinyVec::Inline(res))
+    }
+    #[inline(always)]
+    fn _deserialize_eps_inner_impl<'a>(
+        backend: &mut SliceWithPos<'a>,
+    ) -> deser::Result<<Self as DeserializeInner>::DeserType<'a>> {
+        deserialize_eps_slice_zero(backend)
+    }
+}
+
+impl<A: Array> DeserializeHelper<Deep> for TinyVec<A>
+where
+    A::Item: DeepCopy + DeserializeInner + 'static,
+{
+    type FullType = Self;
+    type DeserType<'a> = Vec<<A::Item as DeserializeInner>::DeserType<'a>>;
+    #[inline(always)]
+    fn _deserialize_full_inner_impl(backend: &mut impl ReadWithPos) -> deser::Result<Self> {
+        let len = usize::_deserialize_full_inner(backend)?;
+        let mut res = TinyVec::with_capacity(prealloc_len::<A::Item>(backend, len));
+        for _ in 0..len {
+            res.push(A::Item::_deserialize_full_inner(backend)?);
+        }
+        Ok(res)
+    }
+    #[inline(always)]
+    fn _deserialize_eps_inner_impl<'a>(
+        backend: &mut SliceWithPos<'a>,
+    ) -> deser::Result<<Self as DeserializeInner>::DeserType<'a>> {
+        deserialize_eps_vec_deep::<A::Item>(backend)
+    }
+}
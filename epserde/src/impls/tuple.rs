@@ -13,6 +13,14 @@ For the time being, we only support tuples of up to 10 elements all of which
 are [`ZeroCopy`] and parameterless. For tuples of more than 10 elements, tuples with elements
 that are not [`ZeroCopy`], or types with parameters, you must use [`epserde_derive::Epserde`] on a newtype.
 
+Since tuples of zero-copy types are zero-copy, a vector of pairs such as
+`Vec<(u32, u64)>` is serialized as a zero-copy slice, and it is ε-copy
+deserialized as a `&[(u32, u64)]`, with no per-element processing (see the
+`pairs` benchmark). Note, however, that the layout of tuples is not
+specified by Rust, and the compiler might in principle change it: if you
+need to read data written by a different compiler version, use a
+`repr(C)` structure instead.
+
 */
 use crate::prelude::*;
 use core::hash::Hash;
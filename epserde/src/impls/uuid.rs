@@ -0,0 +1,69 @@
+/*
+ * SPDX-FileCopyrightText: 2023 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+/*!
+
+Implementations for [`Uuid`], available with the `uuid` feature.
+
+A [`Uuid`] is a `repr(transparent)` wrapper around an array of 16 bytes, so
+it is zero-copy: for example, a `Vec<Uuid>` is ε-copy deserialized as a
+`&[Uuid]`.
+
+*/
+
+use crate::prelude::*;
+use ::uuid::Uuid;
+use core::hash::Hash;
+use deser::*;
+use ser::*;
+
+impl CopyType for Uuid {
+    type Copy = Zero;
+}
+
+impl TypeHash for Uuid {
+    fn type_hash(hasher: &mut impl core::hash::Hasher) {
+        "uuid::Uuid".hash(hasher);
+    }
+}
+
+impl ReprHash for Uuid {
+    fn repr_hash(hasher: &mut impl core::hash::Hasher, offset_of: &mut usize) {
+        crate::traits::std_repr_hash::<Self>(hasher, offset_of)
+    }
+}
+
+impl MaxSizeOf for Uuid {
+    fn max_size_of() -> usize {
+        core::mem::align_of::<Self>()
+    }
+}
+
+impl SerializeInner for Uuid {
+    const IS_ZERO_COPY: bool = true;
+    const ZERO_COPY_MISMATCH: bool = false;
+
+    #[inline(always)]
+    fn _serialize_inner(&self, backend: &mut impl WriteWithNames) -> ser::Result<()> {
+        serialize_zero(backend, self)
+    }
+}
+
+impl DeserializeInner for Uuid {
+    #[inline(always)]
+    fn _deserialize_full_inner(backend: &mut impl ReadWithPos) -> deser::Result<Self> {
+        deserialize_full_zero(backend)
+    }
+
+    type DeserType<'a> = &'a Uuid;
+
+    #[inline(always)]
+    fn _deserialize_eps_inner<'a>(
+        backend: &mut SliceWithPos<'a>,
+    ) -> deser::Result<Self::DeserType<'a>> {
+        deserialize_eps_zero(backend)
+    }
+}
@@ -30,10 +30,16 @@ pub mod prelude {
     pub use crate::deser::MemCase;
     pub use crate::deser::ReadWithPos;
     pub use crate::deser::SliceWithPos;
+    pub use crate::deser::TypeRegistry;
+    pub use crate::include_aligned;
     pub use crate::ser;
+    pub use crate::ser::Schema;
+    pub use crate::ser::SchemaRow;
+    pub use crate::ser::SchemaWriter;
     pub use crate::ser::Serialize;
     pub use crate::ser::SerializeHelper;
     pub use crate::ser::SerializeInner;
+    pub use crate::ser::WriterWithPos;
     pub use crate::traits::*;
     pub use crate::utils::*;
     #[cfg(feature = "derive")]
@@ -41,13 +47,31 @@ pub mod prelude {
 }
 
 /// (Major, Minor) version of the file format, this follows semantic versioning
-pub const VERSION: (u16, u16) = (1, 1);
+pub const VERSION: (u16, u16) = (1, 2);
+
+/// Minor version starting from which the payload is followed by an
+/// [extension block](crate::ser::Serialize::serialize_with_extensions).
+///
+/// Data without an extension block is written with the previous minor
+/// version, so that it can still be read by older versions of ε-serde.
+pub const EXTENSIONS_MINOR_VERSION: u16 = 2;
 
 /// Magic cookie, also used as endianess marker.
 pub const MAGIC: u64 = u64::from_ne_bytes(*b"epserde ");
 /// What we will read if the endianness is mismatched.
 pub const MAGIC_REV: u64 = u64::from_le_bytes(MAGIC.to_be_bytes());
 
+/// Size in bytes of a `usize` value in serialized data, written in the header.
+///
+/// It is the native size of a `usize`, or eight if the `portable-usize`
+/// feature is enabled. Note that `usize` values inside zero-copy data (e.g.,
+/// a `Vec<usize>`) are always written using the native size.
+pub const USIZE_SIZE: usize = if cfg!(feature = "portable-usize") {
+    8
+} else {
+    core::mem::size_of::<usize>()
+};
+
 /// Compute the padding needed for alignment, that is, the smallest
 /// number such that `((value + pad_align_to(value, align_to) & (align_to - 1) == 0`.
 pub fn pad_align_to(value: usize, align_to: usize) -> usize {
@@ -15,6 +15,26 @@ use super::{SerializeInner, WriteWithNames};
 use crate::ser;
 use crate::traits::*;
 
+/// Sort the entries of a map by the serialized bytes of their keys.
+///
+/// This function is used by maps to write their entries in a deterministic
+/// order when the backend is [deterministic](WriteWithNames::deterministic).
+pub(crate) fn sort_by_key_bytes<'a, K: SerializeInner, V>(
+    entries: impl IntoIterator<Item = (&'a K, &'a V)>,
+) -> ser::Result<Vec<(&'a K, &'a V)>> {
+    let mut keyed = Vec::new();
+    for (key, value) in entries {
+        let mut bytes = Vec::new();
+        key._serialize_inner(&mut ser::WriterWithPos::new_deterministic(&mut bytes))?;
+        keyed.push((bytes, key, value));
+    }
+    keyed.sort_unstable_by(|a, b| a.0.cmp(&b.0));
+    Ok(keyed
+        .into_iter()
+        .map(|(_, key, value)| (key, value))
+        .collect())
+}
+
 pub fn check_zero_copy<V: SerializeInner>() {
     if !V::IS_ZERO_COPY {
         panic!(
@@ -24,6 +44,24 @@ pub fn check_zero_copy<V: SerializeInner>() {
     }
 }
 
+/// Check in debug mode that the current position is compatible with the
+/// alignment of `V`.
+///
+/// If this is not the case, the implementation of [`MaxSizeOf`] for `V` (or
+/// for one of its fields) is wrong, and the data would be rejected at
+/// deserialization time with an [alignment error](crate::deser::Error::AlignmentError).
+#[inline(always)]
+fn check_alignment<V: ZeroCopy>(backend: &impl WriteWithNames) {
+    debug_assert!(
+        crate::pad_align_to(backend.pos(), core::mem::align_of::<V>()) == 0,
+        "Type {} is being written at position {}, which is not a multiple of its alignment {}: its implementation of MaxSizeOf is probably wrong (max_size_of() returns {})",
+        core::any::type_name::<V>(),
+        backend.pos(),
+        core::mem::align_of::<V>(),
+        V::max_size_of(),
+    );
+}
+
 /// Serialize a zero-copy structure by writing its bytes properly [aligned](WriteWithNames::align).
 ///
 /// Note that this method uses a single `write_all` call to write the entire structure.
@@ -39,6 +77,7 @@ pub fn serialize_zero<V: ZeroCopy + SerializeInner>(
         core::slice::from_raw_parts(value as *const V as *const u8, core::mem::size_of::<V>())
     };
     backend.align::<V>()?;
+    check_alignment::<V>(backend);
     backend.write_bytes::<V>(buffer)
 }
 
@@ -62,12 +101,45 @@ pub fn serialize_slice_zero<V: SerializeInner + ZeroCopy>(
         core::slice::from_raw_parts(data.as_ptr() as *const u8, len * core::mem::size_of::<V>())
     };
     backend.align::<V>()?;
+    check_alignment::<V>(backend);
     backend.write_bytes::<V>(buffer)
 }
 
+/// The function handling warnings issued during serialization.
+static WARNING_HANDLER: std::sync::RwLock<fn(&str)> = std::sync::RwLock::new(eprint_warning);
+
+/// The default warning handler, printing the warning on standard error.
+fn eprint_warning(warning: &str) {
+    eprintln!("{}", warning);
+}
+
+/// Set the function handling warnings issued during serialization (e.g.,
+/// when a type [could be zero-copy](SerializeInner::ZERO_COPY_MISMATCH) but
+/// it is not declared as such), returning the previous one.
+///
+/// By default, warnings are printed on standard error. Applications can
+/// suppress them or redirect them, for example, to a logging framework:
+///
+/// ```rust
+/// use epserde::ser::set_warning_handler;
+///
+/// // Ignore all warnings
+/// set_warning_handler(|_| {});
+/// ```
+pub fn set_warning_handler(handler: fn(&str)) -> fn(&str) {
+    let mut current = WARNING_HANDLER.write().unwrap_or_else(|e| e.into_inner());
+    core::mem::replace(&mut *current, handler)
+}
+
+/// Pass a warning to the current [warning handler](set_warning_handler).
+pub(crate) fn warn(warning: &str) {
+    let handler = *WARNING_HANDLER.read().unwrap_or_else(|e| e.into_inner());
+    handler(warning);
+}
+
 pub fn check_mismatch<V: SerializeInner>() {
     if V::ZERO_COPY_MISMATCH {
-        eprintln!("Type {} is zero-copy, but it has not declared as such; use the #[deep_copy] attribute to silence this warning", core::any::type_name::<V>());
+        warn(&format!("Type {} is zero-copy, but it has not declared as such; use the #[deep_copy] attribute to silence this warning", core::any::type_name::<V>()));
     }
 }
 
@@ -42,12 +42,49 @@ pub type Result<T> = core::result::Result<T, Error>;
 /// the type to a file.
 pub trait Serialize: TypeHash + ReprHash {
     /// Serialize the type using the given backend.
+    ///
+    /// Note that containers with unspecified iteration order, such as
+    /// [`HashMap`](std::collections::HashMap), are written in iteration
+    /// order, so equal values might be serialized to different bytes: use
+    /// [`Serialize::serialize_deterministic`] if you need reproducible output.
     fn serialize(&self, backend: &mut impl WriteNoStd) -> Result<usize> {
         let mut write_with_pos = WriterWithPos::new(backend);
         self.serialize_on_field_write(&mut write_with_pos)?;
         Ok(write_with_pos.pos())
     }
 
+    /// Serialize the type using the given backend, writing containers with
+    /// unspecified iteration order in a deterministic order.
+    ///
+    /// Equal values are serialized to identical bytes, which is useful,
+    /// for example, for content-addressed storage. Hash maps are written
+    /// sorting their entries by the serialized bytes of their keys, which
+    /// has some additional cost. The result can be deserialized as usual.
+    fn serialize_deterministic(&self, backend: &mut impl WriteNoStd) -> Result<usize> {
+        let mut write_with_pos = WriterWithPos::new_deterministic(backend);
+        self.serialize_on_field_write(&mut write_with_pos)?;
+        Ok(write_with_pos.pos())
+    }
+
+    /// Serialize the type using the given backend, reporting periodically to
+    /// the given callback the number of bytes written so far, and return the
+    /// number of bytes written.
+    ///
+    /// The callback is invoked every [`PROGRESS_INTERVAL`] bytes (or more, as
+    /// zero-copy data is written in a single call) and when the backend is
+    /// flushed; the last invocation reports the total number of bytes written.
+    fn serialize_with_progress(
+        &self,
+        backend: &mut impl WriteNoStd,
+        progress: impl FnMut(usize),
+    ) -> Result<usize> {
+        let mut progress_writer = ProgressWriter::new(backend, progress);
+        let bytes_written = self.serialize(&mut progress_writer)?;
+        // Usually a no-op, as serialization ends with a flush
+        progress_writer.report();
+        Ok(bytes_written)
+    }
+
     /// Serialize the type using the given backend and return a [schema](Schema)
     /// describing the data that has been written.
     ///
@@ -60,6 +97,106 @@ pub trait Serialize: TypeHash + ReprHash {
         Ok(schema_writer.schema)
     }
 
+    /// Serialize the type in self-describing form, that is, together with
+    /// its [schema](Serialize::serialize_with_schema), and return the number
+    /// of bytes written.
+    ///
+    /// The data can be inspected without knowing the type by deserializing
+    /// it as a [`SelfDescribing`](crate::utils::SelfDescribing) instance.
+    fn serialize_self_describing(&self, backend: &mut impl WriteNoStd) -> Result<usize> {
+        crate::utils::SelfDescribing::new(self)?.serialize(backend)
+    }
+
+    /// Serialize the type using the given backend, omitting the type name
+    /// from the header, and return the number of bytes written.
+    ///
+    /// The header contains the name of the type only for debugging purposes
+    /// (e.g., in error messages), but for deeply nested generic types the
+    /// name can be large: this method writes an empty string in its place.
+    /// Type and representation hashes are still written, and checked at
+    /// deserialization, which works as usual.
+    fn serialize_compact(&self, backend: &mut impl WriteNoStd) -> Result<usize>
+    where
+        Self: SerializeInner + Sized,
+    {
+        let mut write_with_pos = WriterWithPos::new(backend);
+        write_header_with_minor::<Self>(&mut write_with_pos, EXTENSIONS_MINOR_VERSION - 1, "")?;
+        write_with_pos.write("ROOT", self)?;
+        write_with_pos.flush()?;
+        Ok(write_with_pos.pos())
+    }
+
+    /// Serialize the type using the given backend, followed by an
+    /// extension block containing the given bytes, and return the number of
+    /// bytes written.
+    ///
+    /// Extension blocks make it possible to add, within the same major
+    /// version, optional data after the payload: the presence of the block is
+    /// marked in the header by the [minor version](EXTENSIONS_MINOR_VERSION),
+    /// and deserialization methods skip it, so readers that do not know about
+    /// the content of the extension block can read the payload anyway. Since
+    /// the block follows the payload, readers that just
+    /// [check the header](crate::deser::check_header) and deserialize the
+    /// payload ignore it, too, but versions of ε-serde preceding extension
+    /// blocks will reject the data with a
+    /// [minor-version mismatch](crate::deser::Error::MinorVersionMismatch).
+    fn serialize_with_extensions(
+        &self,
+        backend: &mut impl WriteNoStd,
+        extensions: &[u8],
+    ) -> Result<usize>
+    where
+        Self: SerializeInner + Sized,
+    {
+        let mut write_with_pos = WriterWithPos::new(backend);
+        write_header_with_minor::<Self>(
+            &mut write_with_pos,
+            EXTENSIONS_MINOR_VERSION,
+            core::any::type_name::<Self>(),
+        )?;
+        write_with_pos.write("ROOT", self)?;
+        write_with_pos.write("EXTENSIONS_LEN", &extensions.len())?;
+        write_with_pos.write_bytes::<u8>(extensions)?;
+        write_with_pos.flush()?;
+        Ok(write_with_pos.pos())
+    }
+
+    /// Serialize the type using the given [`WriteWithNames`], without
+    /// writing a header.
+    ///
+    /// This method makes it possible to implement custom framing: for
+    /// example, one can write a single header using [`write_header`] and then
+    /// several payloads, provided that the same [`WriteWithNames`] is used
+    /// for all writes (alignment is computed with respect to its
+    /// position). The payloads can be read back with
+    /// [`check_header`](crate::deser::check_header) and
+    /// [`Deserialize::deserialize_full_no_header`](crate::deser::Deserialize::deserialize_full_no_header)
+    /// or [`Deserialize::deserialize_eps_no_header`](crate::deser::Deserialize::deserialize_eps_no_header).
+    ///
+    /// ```rust
+    /// use epserde::prelude::*;
+    /// use maligned::A16;
+    ///
+    /// let mut cursor = <AlignedCursor<A16>>::new();
+    /// let mut writer_with_pos = WriterWithPos::new(&mut cursor);
+    /// ser::write_header::<Vec<u32>>(&mut writer_with_pos).unwrap();
+    /// vec![1_u32, 2].serialize_no_header(&mut writer_with_pos).unwrap();
+    /// vec![3_u32].serialize_no_header(&mut writer_with_pos).unwrap();
+    ///
+    /// let mut backend = SliceWithPos::new(cursor.as_bytes());
+    /// deser::check_header::<Vec<u32>>(&mut backend).unwrap();
+    /// let a = <Vec<u32>>::deserialize_eps_no_header(&mut backend).unwrap();
+    /// let b = <Vec<u32>>::deserialize_eps_no_header(&mut backend).unwrap();
+    /// assert_eq!(a, &[1, 2]);
+    /// assert_eq!(b, &[3]);
+    /// ```
+    fn serialize_no_header(&self, backend: &mut impl WriteWithNames) -> Result<()>
+    where
+        Self: SerializeInner + Sized,
+    {
+        backend.write("ROOT", self)
+    }
+
     /// Serialize the type using the given [`WriteWithNames`].
     fn serialize_on_field_write(&self, backend: &mut impl WriteWithNames) -> Result<()>;
 
@@ -117,23 +254,39 @@ impl<T: SerializeInner + TypeHash + ReprHash> Serialize for T {
 
 /// Write the header.
 ///
+/// This function is useful to implement custom framing (see
+/// [`Serialize::serialize_no_header`]).
+///
 /// Must be kept in sync with [`crate::deser::check_header`].
 pub fn write_header<T: TypeHash + ReprHash>(backend: &mut impl WriteWithNames) -> Result<()> {
+    write_header_with_minor::<T>(
+        backend,
+        EXTENSIONS_MINOR_VERSION - 1,
+        core::any::type_name::<T>(),
+    )
+}
+
+/// Write the header using the given minor version and type name.
+fn write_header_with_minor<T: TypeHash + ReprHash>(
+    backend: &mut impl WriteWithNames,
+    minor: u16,
+    type_name: &str,
+) -> Result<()> {
     backend.write("MAGIC", &MAGIC)?;
     backend.write("VERSION_MAJOR", &VERSION.0)?;
-    backend.write("VERSION_MINOR", &VERSION.1)?;
-    backend.write("USIZE_SIZE", &(core::mem::size_of::<usize>() as u8))?;
+    backend.write("VERSION_MINOR", &minor)?;
+    backend.write("USIZE_SIZE", &(USIZE_SIZE as u8))?;
 
     let mut type_hasher = xxhash_rust::xxh3::Xxh3::new();
     T::type_hash(&mut type_hasher);
 
     let mut repr_hasher = xxhash_rust::xxh3::Xxh3::new();
     let mut offset_of = 0;
-    T::repr_hash(&mut repr_hasher, &mut offset_of);
+    T::repr_hash_isolated(&mut repr_hasher, &mut offset_of);
 
     backend.write("TYPE_HASH", &type_hasher.finish())?;
     backend.write("REPR_HASH", &repr_hasher.finish())?;
-    backend.write("TYPE_NAME", &core::any::type_name::<T>().to_string())
+    backend.write("TYPE_NAME", &type_name.to_string())
 }
 
 /// A helper trait that makes it possible to implement differently
@@ -150,6 +303,9 @@ pub enum Error {
     WriteError,
     /// [`Serialize::store`] could not open the provided file.
     FileOpenError(std::io::Error),
+    /// A [`Mutex`](std::sync::Mutex) or [`RwLock`](std::sync::RwLock) to be
+    /// serialized was poisoned.
+    PoisonedLock,
 }
 
 impl std::error::Error for Error {}
@@ -165,6 +321,9 @@ impl core::fmt::Display for Error {
                     error
                 )
             }
+            Self::PoisonedLock => {
+                write!(f, "Poisoned lock during ε-serde serialization")
+            }
         }
     }
 }
@@ -61,13 +61,31 @@ pub struct WriterWithPos<'a, F: WriteNoStd> {
     backend: &'a mut F,
     /// How many bytes we have written from the start.
     pos: usize,
+    /// Whether containers with unspecified iteration order must be written
+    /// in a deterministic order.
+    pub(crate) deterministic: bool,
 }
 
 impl<'a, F: WriteNoStd> WriterWithPos<'a, F> {
     #[inline(always)]
     /// Create a new [`WriterWithPos`] on top of a generic [`WriteNoStd`] `F`.
     pub fn new(backend: &'a mut F) -> Self {
-        Self { backend, pos: 0 }
+        Self {
+            backend,
+            pos: 0,
+            deterministic: false,
+        }
+    }
+
+    #[inline(always)]
+    /// Create a new [`WriterWithPos`] on top of a generic [`WriteNoStd`] `F`
+    /// that [writes deterministically](crate::ser::WriteWithNames::deterministic).
+    pub fn new_deterministic(backend: &'a mut F) -> Self {
+        Self {
+            backend,
+            pos: 0,
+            deterministic: true,
+        }
     }
 }
 
@@ -91,3 +109,198 @@ impl<'a, F: WriteNoStd> WriteWithPos for WriterWithPos<'a, F> {
         self.pos
     }
 }
+
+/// A wrapper for a [`WriteNoStd`] that counts the bytes written and the
+/// number of calls to [`WriteNoStd::write_all`].
+///
+/// This wrapper is useful for diagnostics: for example, a vector of zero-copy
+/// types is written with a single call, independently of its length.
+///
+/// Since there is a blanket implementation of [`WriteNoStd`] for
+/// [`std::io::Write`], this wrapper can be used with any standard writer.
+#[derive(Debug, Clone, Default, MemDbg, MemSize)]
+pub struct CountingWriter<W: WriteNoStd> {
+    /// The wrapped writer.
+    backend: W,
+    /// How many bytes have been written.
+    bytes_written: usize,
+    /// How many times [`WriteNoStd::write_all`] has been called.
+    write_calls: usize,
+}
+
+impl<W: WriteNoStd> CountingWriter<W> {
+    /// Create a new [`CountingWriter`] on top of a generic [`WriteNoStd`] `W`.
+    pub fn new(backend: W) -> Self {
+        Self {
+            backend,
+            bytes_written: 0,
+            write_calls: 0,
+        }
+    }
+
+    /// Return the number of bytes written.
+    pub fn bytes_written(&self) -> usize {
+        self.bytes_written
+    }
+
+    /// Return the number of calls to [`WriteNoStd::write_all`].
+    pub fn write_calls(&self) -> usize {
+        self.write_calls
+    }
+
+    /// Consume this wrapper, returning the wrapped writer.
+    pub fn into_inner(self) -> W {
+        self.backend
+    }
+}
+
+impl<W: WriteNoStd> WriteNoStd for CountingWriter<W> {
+    #[inline(always)]
+    fn write_all(&mut self, buf: &[u8]) -> ser::Result<()> {
+        self.backend.write_all(buf)?;
+        self.bytes_written += buf.len();
+        self.write_calls += 1;
+        Ok(())
+    }
+
+    #[inline(always)]
+    fn flush(&mut self) -> ser::Result<()> {
+        self.backend.flush()
+    }
+}
+
+/// A wrapper for a [`WriteNoStd`] that feeds the bytes written to a
+/// [`Hasher`](core::hash::Hasher).
+///
+/// This wrapper makes it possible to compute a content hash of the
+/// serialized data while serializing, with no need of a second pass over
+/// the bytes.
+///
+/// Bytes are passed to the hasher in the same chunks in which they are
+/// written: thus, the final hash is the same as the hash of the whole
+/// serialized data only if the hasher is insensitive to chunking, as it
+/// happens, for example, for the streaming hashers of the `xxhash-rust` crate.
+///
+/// ```rust
+/// use core::hash::Hasher;
+/// use epserde::prelude::*;
+/// use epserde::ser::TeeWriter;
+/// use xxhash_rust::xxh3::Xxh3;
+///
+/// let mut writer = TeeWriter::new(Vec::new(), Xxh3::new());
+/// vec![1_u64, 2, 3].serialize(&mut writer).unwrap();
+/// let hash = writer.finish();
+///
+/// let (bytes, _) = writer.into_inner();
+/// let mut hasher = Xxh3::new();
+/// hasher.write(&bytes);
+/// assert_eq!(hash, hasher.finish());
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct TeeWriter<W: WriteNoStd, H: core::hash::Hasher> {
+    /// The wrapped writer.
+    backend: W,
+    /// The hasher fed with the bytes written.
+    hasher: H,
+}
+
+impl<W: WriteNoStd, H: core::hash::Hasher> TeeWriter<W, H> {
+    /// Create a new [`TeeWriter`] on top of a generic [`WriteNoStd`] `W`
+    /// feeding the given hasher.
+    pub fn new(backend: W, hasher: H) -> Self {
+        Self { backend, hasher }
+    }
+
+    /// Return the hash of the bytes written so far.
+    pub fn finish(&self) -> u64 {
+        self.hasher.finish()
+    }
+
+    /// Return a reference to the hasher.
+    pub fn hasher(&self) -> &H {
+        &self.hasher
+    }
+
+    /// Consume this wrapper, returning the wrapped writer and the hasher.
+    pub fn into_inner(self) -> (W, H) {
+        (self.backend, self.hasher)
+    }
+}
+
+impl<W: WriteNoStd, H: core::hash::Hasher> WriteNoStd for TeeWriter<W, H> {
+    #[inline(always)]
+    fn write_all(&mut self, buf: &[u8]) -> ser::Result<()> {
+        self.backend.write_all(buf)?;
+        self.hasher.write(buf);
+        Ok(())
+    }
+
+    #[inline(always)]
+    fn flush(&mut self) -> ser::Result<()> {
+        self.backend.flush()
+    }
+}
+
+/// The number of bytes between two invocations of the callback of a
+/// [`ProgressWriter`].
+pub const PROGRESS_INTERVAL: usize = 1 << 20;
+
+/// A wrapper for a [`WriteNoStd`] that reports the number of bytes written
+/// so far to a callback.
+///
+/// The callback is invoked after a call to [`WriteNoStd::write_all`] if at
+/// least [`PROGRESS_INTERVAL`] bytes have been written since the last
+/// invocation, and at each [flush](WriteNoStd::flush) if some bytes have been
+/// written since the last invocation. It is used by
+/// [`Serialize::serialize_with_progress`](crate::ser::Serialize::serialize_with_progress).
+#[derive(Debug)]
+pub struct ProgressWriter<'a, W: WriteNoStd, F: FnMut(usize)> {
+    /// The wrapped writer.
+    backend: &'a mut W,
+    /// The callback.
+    progress: F,
+    /// How many bytes have been written.
+    bytes_written: usize,
+    /// The number of bytes written at the last invocation of the callback.
+    last_reported: usize,
+}
+
+impl<'a, W: WriteNoStd, F: FnMut(usize)> ProgressWriter<'a, W, F> {
+    /// Create a new [`ProgressWriter`] on top of a generic [`WriteNoStd`] `W`.
+    pub fn new(backend: &'a mut W, progress: F) -> Self {
+        Self {
+            backend,
+            progress,
+            bytes_written: 0,
+            last_reported: 0,
+        }
+    }
+
+    /// Invoke the callback if some bytes have been written since
+    /// the last invocation.
+    pub(crate) fn report(&mut self) {
+        if self.bytes_written != self.last_reported {
+            self.last_reported = self.bytes_written;
+            (self.progress)(self.bytes_written);
+        }
+    }
+}
+
+impl<W: WriteNoStd, F: FnMut(usize)> WriteNoStd for ProgressWriter<'_, W, F> {
+    #[inline(always)]
+    fn write_all(&mut self, buf: &[u8]) -> ser::Result<()> {
+        self.backend.write_all(buf)?;
+        self.bytes_written += buf.len();
+        if self.bytes_written - self.last_reported >= PROGRESS_INTERVAL {
+            self.report();
+        }
+        Ok(())
+    }
+
+    #[inline(always)]
+    fn flush(&mut self) -> ser::Result<()> {
+        self.backend.flush()?;
+        self.report();
+        Ok(())
+    }
+}
@@ -17,6 +17,7 @@ events happening during a serialization.
 
 use super::*;
 use mem_dbg::{MemDbg, MemSize};
+use std::collections::BTreeMap;
 
 /// Trait extending [`WriteWithPos`] with methods providing
 /// alignment, serialization of named data, and writing of byte slices
@@ -64,9 +65,22 @@ pub trait WriteWithNames: WriteWithPos + Sized {
     fn write_bytes<V: SerializeInner + ZeroCopy>(&mut self, value: &[u8]) -> Result<()> {
         self.write_all(value)
     }
+
+    /// Return whether containers with unspecified iteration order (e.g., hash
+    /// maps) must be written in a deterministic order, so that equal values
+    /// are serialized to identical bytes.
+    ///
+    /// The default implementation returns false.
+    fn deterministic(&self) -> bool {
+        false
+    }
 }
 
-impl<F: WriteNoStd> WriteWithNames for WriterWithPos<'_, F> {}
+impl<F: WriteNoStd> WriteWithNames for WriterWithPos<'_, F> {
+    fn deterministic(&self) -> bool {
+        self.deterministic
+    }
+}
 
 /// Information about data written during serialization, either fields or
 /// ancillary data such as option tags and slice lengths.
@@ -135,6 +149,122 @@ impl Schema {
         result
     }
 
+    /// Check that the schema is consistent.
+    ///
+    /// More precisely, this method checks that rows of nonzero size are either
+    /// nested or disjoint, that the rows containing no other row (e.g.,
+    /// primitive fields, padding, and zero-copy data) cover contiguously the
+    /// serialized data, and that every row is exactly covered by the rows it
+    /// contains.
+    ///
+    /// On failure, a description of the first inconsistency found is returned.
+    pub fn validate(&self) -> core::result::Result<(), String> {
+        let mut rows = self
+            .0
+            .iter()
+            .filter(|row| row.size != 0)
+            .collect::<Vec<_>>();
+        // Stable sorting keeps containing rows before contained rows
+        rows.sort_by_key(|row| (row.offset, core::cmp::Reverse(row.size)));
+
+        // Rows containing the current one
+        let mut stack: Vec<&SchemaRow> = vec![];
+        // The position at which the next row containing no other row must start
+        let mut next = rows.first().map(|row| row.offset).unwrap_or(0);
+
+        let check_covered = |row: &SchemaRow, next: usize| {
+            if next != row.offset + row.size {
+                Err(format!(
+                    "Row {} (offset {}, size {}) is covered by its content only up to offset {}",
+                    row.field, row.offset, row.size, next
+                ))
+            } else {
+                Ok(())
+            }
+        };
+
+        for (i, row) in rows.iter().enumerate() {
+            while let Some(top) = stack.last() {
+                if row.offset < top.offset + top.size {
+                    break;
+                }
+                check_covered(top, next)?;
+                stack.pop();
+            }
+
+            if let Some(top) = stack.last() {
+                if row.offset + row.size > top.offset + top.size {
+                    return Err(format!(
+                        "Row {} (offset {}, size {}) overlaps row {} (offset {}, size {})",
+                        row.field, row.offset, row.size, top.field, top.offset, top.size
+                    ));
+                }
+            }
+
+            match rows.get(i + 1) {
+                Some(succ) if succ.offset < row.offset + row.size => stack.push(row),
+                _ => {
+                    if row.offset != next {
+                        return Err(format!(
+                            "Row {} starts at offset {}, but the previous data ends at offset {}",
+                            row.field, row.offset, next
+                        ));
+                    }
+                    next = row.offset + row.size;
+                }
+            }
+        }
+
+        while let Some(top) = stack.pop() {
+            check_covered(top, next)?;
+        }
+
+        Ok(())
+    }
+
+    /// Return the rows of nonzero size containing no other row (e.g.,
+    /// primitive fields, padding, and zero-copy data).
+    ///
+    /// If the schema is [consistent](Schema::validate), these rows cover
+    /// exactly once every byte of the serialized data.
+    fn leaves(&self) -> Vec<&SchemaRow> {
+        let mut rows = self
+            .0
+            .iter()
+            .filter(|row| row.size != 0)
+            .collect::<Vec<_>>();
+        // Stable sorting keeps containing rows before contained rows
+        rows.sort_by_key(|row| (row.offset, core::cmp::Reverse(row.size)));
+        (0..rows.len())
+            .filter(|&i| i + 1 == rows.len() || rows[i + 1].offset >= rows[i].offset + rows[i].size)
+            .map(|i| rows[i])
+            .collect()
+    }
+
+    /// Return the number of bytes described by the schema.
+    ///
+    /// The size is computed by adding the sizes of the rows containing no
+    /// other row, so for a [consistent](Schema::validate) schema it is equal
+    /// to the length of the serialized data.
+    pub fn total_size(&self) -> usize {
+        self.leaves().iter().map(|row| row.size).sum()
+    }
+
+    /// Return a map from types to the number of bytes of data of that type.
+    ///
+    /// Only rows containing no other row are considered, so the type of
+    /// every byte is the innermost one (e.g., the bytes of a structure are
+    /// attributed to the types of its fields, and padding is attributed to
+    /// arrays of bytes). Thus, the values of the map add up to
+    /// [`Schema::total_size`].
+    pub fn size_by_type(&self) -> BTreeMap<String, usize> {
+        let mut sizes = BTreeMap::new();
+        for row in self.leaves() {
+            *sizes.entry(row.ty.clone()).or_insert(0) += row.size;
+        }
+        sizes
+    }
+
     /// Return a CSV representation of the schema, excluding data.
     pub fn to_csv(&self) -> String {
         let mut result = "field,offset,align,size,ty\n".to_string();
@@ -150,6 +280,24 @@ impl Schema {
 
 /// A [`WriteWithNames`] that keeps track of the data written on an underlying
 /// [`WriteWithPos`] in a [`Schema`].
+///
+/// [`Serialize::serialize_with_schema`] uses internally a [`SchemaWriter`],
+/// but one can also be built directly, for example to wrap it in a custom
+/// [`WriteWithNames`] decorator. The schema collected so far is available
+/// in the public field [`schema`](SchemaWriter::schema):
+///
+/// ```rust
+/// use epserde::prelude::*;
+/// use maligned::A16;
+///
+/// let mut cursor = <AlignedCursor<A16>>::new();
+/// let mut writer_with_pos = WriterWithPos::new(&mut cursor);
+/// let mut schema_writer = SchemaWriter::new(&mut writer_with_pos);
+/// vec![1_u32, 2, 3].serialize_on_field_write(&mut schema_writer).unwrap();
+///
+/// let schema = schema_writer.into_schema();
+/// assert!(schema.0.iter().any(|row| row.field == "ROOT.len"));
+/// ```
 #[derive(Debug, MemDbg, MemSize)]
 pub struct SchemaWriter<'a, W> {
     /// The schema so far.
@@ -170,6 +318,11 @@ impl<'a, W: WriteWithPos> SchemaWriter<'a, W> {
             writer: backend,
         }
     }
+
+    /// Consume this [`SchemaWriter`], returning the [`Schema`] collected so far.
+    pub fn into_schema(self) -> Schema {
+        self.schema
+    }
 }
 impl<W: WriteNoStd> WriteNoStd for SchemaWriter<'_, W> {
     fn write_all(&mut self, buf: &[u8]) -> ser::Result<()> {
@@ -204,6 +357,12 @@ impl<W: WriteWithPos> WriteWithNames for SchemaWriter<'_, W> {
             for _ in 0..padding {
                 self.write_all(&[0])?;
             }
+            // The row must end at the current position of the backend
+            debug_assert!(self
+                .schema
+                .0
+                .last()
+                .is_some_and(|row| row.offset + row.size == self.pos()));
         }
 
         Ok(())
@@ -249,6 +408,13 @@ impl<W: WriteWithPos> WriteWithNames for SchemaWriter<'_, W> {
         });
         self.path.pop();
 
-        self.write_all(value)
+        self.write_all(value)?;
+        // The row must end at the current position of the backend
+        debug_assert!(self
+            .schema
+            .0
+            .last()
+            .is_some_and(|row| row.offset + row.size == self.pos()));
+        Ok(())
     }
 }
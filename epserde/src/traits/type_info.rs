@@ -54,14 +54,39 @@ pub trait ReprHash {
     fn repr_hash_val(&self, hasher: &mut impl core::hash::Hasher, offset_of: &mut usize) {
         Self::repr_hash(hasher, offset_of);
     }
+
+    /// Accumulate representional information in `hasher` for a value that
+    /// is serialized in isolation, that is, not as part of zero-copy data
+    /// (e.g., a field of a deep-copy type).
+    ///
+    /// The default implementation calls [`ReprHash::repr_hash`]. The only
+    /// type overriding it is `usize`, which in isolation is written using
+    /// [`USIZE_SIZE`](crate::USIZE_SIZE) bytes.
+    fn repr_hash_isolated(hasher: &mut impl core::hash::Hasher, offset_of: &mut usize) {
+        Self::repr_hash(hasher, offset_of);
+    }
+}
+
+/// Hash a size or a padding.
+///
+/// When [`USIZE_SIZE`](crate::USIZE_SIZE) is eight the value is hashed as a
+/// `u64`, so that with the `portable-usize` feature representation hashes do
+/// not depend on the width of a native `usize`. On 64-bit architectures this
+/// is the same as hashing the `usize`.
+pub(crate) fn hash_usize(hasher: &mut impl core::hash::Hasher, value: usize) {
+    if crate::USIZE_SIZE == core::mem::size_of::<u64>() {
+        (value as u64).hash(hasher);
+    } else {
+        value.hash(hasher);
+    }
 }
 
 /// A function providing a reasonable default
 /// implementation of [`ReprHash::repr_hash`] for basic sized types.
 pub(crate) fn std_repr_hash<T>(hasher: &mut impl core::hash::Hasher, offset_of: &mut usize) {
     let padding = pad_align_to(*offset_of, core::mem::align_of::<T>());
-    padding.hash(hasher);
-    core::mem::size_of::<T>().hash(hasher);
+    hash_usize(hasher, padding);
+    hash_usize(hasher, core::mem::size_of::<T>());
     *offset_of += padding;
     *offset_of += core::mem::size_of::<T>();
 }
@@ -85,3 +110,22 @@ pub(crate) fn std_repr_hash<T>(hasher: &mut impl core::hash::Hasher, offset_of:
 pub trait MaxSizeOf: Sized {
     fn max_size_of() -> usize;
 }
+
+/// Return the value of [`MaxSizeOf::max_size_of`] for a type.
+///
+/// This is the alignment used by ε-serde for the type when it is zero-copy,
+/// that is, the alignment of the type's (zero-copy) data in the serialized
+/// stream (and thus in memory during ε-copy deserialization), and it might be
+/// different from [`core::mem::align_of`]. This function might be useful to
+/// diagnose [alignment errors](crate::deser::Error::AlignmentError), which
+/// happen when the backend is not aligned to the value returned.
+///
+/// ```rust
+/// use epserde::prelude::*;
+///
+/// assert_eq!(max_size_of::<[u64; 3]>(), 8);
+/// assert_eq!(max_size_of::<(u8, u16)>(), 2);
+/// ```
+pub fn max_size_of<T: MaxSizeOf>() -> usize {
+    T::max_size_of()
+}
@@ -0,0 +1,125 @@
+/*
+ * SPDX-FileCopyrightText: 2023 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+use core::ops::Deref;
+
+/// A marker type associating an alignment in bytes with a type
+/// having that alignment.
+///
+/// The only purpose of this type is to make it possible to write
+/// [`AlignedBytes<16>`](AlignedBytes), as `#[repr(align(N))]` cannot be used
+/// with a const parameter.
+pub struct ConstAlignment<const N: usize>;
+
+/// A trait providing, for the supported values of `N`, the
+/// [alignment type](maligned::Alignment) of [`ConstAlignment<N>`].
+pub trait AlignmentType {
+    /// A type whose alignment is `N` bytes.
+    type Type: maligned::Alignment;
+}
+
+macro_rules! impl_alignment_type {
+    ($($n:literal => $ty:ident),*) => {
+        $(
+            impl AlignmentType for ConstAlignment<$n> {
+                type Type = maligned::$ty;
+            }
+        )*
+    };
+}
+
+impl_alignment_type!(
+    2 => A2, 4 => A4, 8 => A8, 16 => A16, 32 => A32, 64 => A64,
+    128 => A128, 256 => A256, 512 => A512
+);
+
+/// Bytes aligned to `N` bytes.
+///
+/// The bytes returned by [`include_bytes!`] have no alignment guarantee, so
+/// they cannot be used for ε-copy deserialization. This type wraps
+/// bytes so that they are aligned to `N` bytes: usually, one embeds serialized
+/// data using the [`include_aligned!`](crate::include_aligned) macro
+/// and a static reference:
+///
+/// ```ignore
+/// use epserde::prelude::*;
+///
+/// static DATA: &AlignedBytes<16> = include_aligned!("data.eps");
+///
+/// let v = <Vec<u64>>::deserialize_eps(&DATA).unwrap();
+/// ```
+///
+/// The type parameter `B` is the type of the bytes: it is an array when
+/// the structure is built, and it is usually coerced to a slice.
+#[repr(C)]
+pub struct AlignedBytes<const N: usize, B: ?Sized = [u8]>
+where
+    ConstAlignment<N>: AlignmentType,
+{
+    _alignment: [<ConstAlignment<N> as AlignmentType>::Type; 0],
+    bytes: B,
+}
+
+impl<const N: usize, const M: usize> AlignedBytes<N, [u8; M]>
+where
+    ConstAlignment<N>: AlignmentType,
+{
+    /// Wrap an array of bytes, aligning it to `N` bytes.
+    pub const fn new(bytes: [u8; M]) -> Self {
+        Self {
+            _alignment: [],
+            bytes,
+        }
+    }
+}
+
+impl<const N: usize, B: ?Sized + AsRef<[u8]>> AlignedBytes<N, B>
+where
+    ConstAlignment<N>: AlignmentType,
+{
+    /// Return the wrapped bytes.
+    pub fn as_bytes(&self) -> &[u8] {
+        self.bytes.as_ref()
+    }
+}
+
+impl<const N: usize, B: ?Sized> Deref for AlignedBytes<N, B>
+where
+    ConstAlignment<N>: AlignmentType,
+{
+    type Target = B;
+    #[inline(always)]
+    fn deref(&self) -> &Self::Target {
+        &self.bytes
+    }
+}
+
+impl<const N: usize, B: ?Sized + core::fmt::Debug> core::fmt::Debug for AlignedBytes<N, B>
+where
+    ConstAlignment<N>: AlignmentType,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("AlignedBytes").field(&&self.bytes).finish()
+    }
+}
+
+/// Include a file as a reference to [`AlignedBytes`].
+///
+/// This macro works like [`include_bytes!`], but the resulting bytes are
+/// aligned as specified by the type of the target, so they can be used
+/// for ε-copy deserialization:
+///
+/// ```ignore
+/// use epserde::prelude::*;
+///
+/// static DATA: &AlignedBytes<16> = include_aligned!("data.eps");
+/// ```
+#[macro_export]
+macro_rules! include_aligned {
+    ($path:expr) => {
+        &$crate::utils::AlignedBytes::new(*include_bytes!($path))
+    };
+}
@@ -0,0 +1,145 @@
+/*
+ * SPDX-FileCopyrightText: 2023 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+use crate::deser::ReadWithPos;
+use crate::prelude::*;
+use crate::ser::WriteWithNames;
+use core::hash::Hash;
+use core::marker::PhantomData;
+use core::ops::{Deref, DerefMut};
+use mem_dbg::{MemDbg, MemSize};
+
+/// A vector of zero-copy elements whose content is serialized aligned to
+/// `N` bytes.
+///
+/// A [`Vec`] of zero-copy elements is serialized [aligned](WriteWithNames::align)
+/// to the [`MaxSizeOf::max_size_of`] of its elements: for example, the content of a
+/// `Vec<f32>` is aligned to four bytes. This wrapper makes it possible to
+/// specify a larger alignment `N`, which must be a power of two: the slice
+/// obtained by ε-copy deserialization will then be aligned to `N` bytes (e.g.,
+/// for SIMD processing), provided that the backend is aligned to `N` bytes.
+///
+/// The alignment is part of the type hash, so data serialized with a given
+/// alignment cannot be deserialized with a different one.
+///
+/// ```rust
+/// use epserde::prelude::*;
+/// use maligned::A64;
+///
+/// let v = <AlignedVec<f32, 32>>::from(vec![1.0, 2.0, 3.0]);
+/// let mut cursor = <AlignedCursor<A64>>::new();
+/// v.serialize(&mut cursor).unwrap();
+///
+/// let s: &[f32] = <AlignedVec<f32, 32>>::deserialize_eps(cursor.as_bytes()).unwrap();
+/// assert_eq!(s.as_ptr() as usize % 32, 0);
+/// assert_eq!(s, &[1.0, 2.0, 3.0]);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default, MemDbg, MemSize)]
+pub struct AlignedVec<T, const N: usize>(pub Vec<T>);
+
+impl<T, const N: usize> AlignedVec<T, N> {
+    /// Return the wrapped vector.
+    pub fn into_inner(self) -> Vec<T> {
+        self.0
+    }
+}
+
+impl<T, const N: usize> From<Vec<T>> for AlignedVec<T, N> {
+    fn from(v: Vec<T>) -> Self {
+        Self(v)
+    }
+}
+
+impl<T, const N: usize> Deref for AlignedVec<T, N> {
+    type Target = Vec<T>;
+    #[inline(always)]
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T, const N: usize> DerefMut for AlignedVec<T, N> {
+    #[inline(always)]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+/// A marker type used to pad the content of an [`AlignedVec`].
+///
+/// Its [`MaxSizeOf::max_size_of`] is the maximum between `N` and
+/// the one of `T`.
+struct Padding<T, const N: usize>(PhantomData<T>);
+
+impl<T: MaxSizeOf, const N: usize> MaxSizeOf for Padding<T, N> {
+    fn max_size_of() -> usize {
+        assert!(
+            N.is_power_of_two(),
+            "The alignment of an AlignedVec must be a power of two, but it is {}",
+            N
+        );
+        N.max(T::max_size_of())
+    }
+}
+
+impl<T, const N: usize> CopyType for AlignedVec<T, N> {
+    type Copy = Deep;
+}
+
+impl<T: TypeHash, const N: usize> TypeHash for AlignedVec<T, N> {
+    fn type_hash(hasher: &mut impl core::hash::Hasher) {
+        "AlignedVec".hash(hasher);
+        N.hash(hasher);
+        T::type_hash(hasher);
+    }
+}
+
+impl<T: ReprHash, const N: usize> ReprHash for AlignedVec<T, N> {
+    fn repr_hash(hasher: &mut impl core::hash::Hasher, offset_of: &mut usize) {
+        N.hash(hasher);
+        *offset_of = 0;
+        T::repr_hash(hasher, offset_of);
+    }
+}
+
+impl<T: ZeroCopy + SerializeInner, const N: usize> SerializeInner for AlignedVec<T, N> {
+    const IS_ZERO_COPY: bool = false;
+    const ZERO_COPY_MISMATCH: bool = false;
+
+    fn _serialize_inner(&self, backend: &mut impl WriteWithNames) -> ser::Result<()> {
+        ser::helpers::check_zero_copy::<T>();
+        let len = self.0.len();
+        backend.write("len", &len)?;
+        let buffer = unsafe {
+            #[allow(clippy::manual_slice_size_calculation)]
+            core::slice::from_raw_parts(
+                self.0.as_ptr() as *const u8,
+                len * core::mem::size_of::<T>(),
+            )
+        };
+        backend.align::<Padding<T, N>>()?;
+        backend.write_bytes::<T>(buffer)
+    }
+}
+
+impl<T: ZeroCopy + DeserializeInner, const N: usize> DeserializeInner for AlignedVec<T, N> {
+    fn _deserialize_full_inner(backend: &mut impl ReadWithPos) -> deser::Result<Self> {
+        let len = usize::_deserialize_full_inner(backend)?;
+        backend.align::<Padding<T, N>>()?;
+        Ok(Self(deser::helpers::read_vec_zero(backend, len)?))
+    }
+
+    type DeserType<'a> = &'a [T];
+
+    fn _deserialize_eps_inner<'a>(
+        backend: &mut SliceWithPos<'a>,
+    ) -> deser::Result<Self::DeserType<'a>> {
+        let len = usize::_deserialize_full_inner(backend)?;
+        // This also checks that the data is aligned to N bytes
+        backend.align::<Padding<T, N>>()?;
+        deser::helpers::slice_zero(backend, len)
+    }
+}
@@ -0,0 +1,241 @@
+/*
+ * SPDX-FileCopyrightText: 2023 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+use crate::deser::helpers::*;
+use crate::deser::{DeserType, ReadWithPos};
+use crate::prelude::*;
+use crate::ser::{WriteNoStd, WriteWithNames, WriteWithPos};
+use core::hash::Hash;
+use core::marker::PhantomData;
+use core::ops::{Deref, DerefMut};
+use mem_dbg::{MemDbg, MemSize};
+
+/// A vector whose elements can be ε-copy deserialized lazily, by index.
+///
+/// A `Vec<T>` of deep-copy elements ε-copy deserializes to a
+/// `Vec<DeserType<T>>`, which requires allocating the outer vector and
+/// deserializing all elements. An [`IndexedVec`] writes instead, before the
+/// elements, a table containing the offsets of the serialized elements (plus
+/// a final offset marking the end of the last element), so it ε-copy
+/// deserializes to a [`VecView`], which just contains two references to the
+/// backend and deserializes elements on demand.
+///
+/// Computing the offsets requires a preliminary pass on the elements, so
+/// serialization is slower than that of a [`Vec`].
+///
+/// ```rust
+/// use epserde::prelude::*;
+/// use maligned::A16;
+///
+/// let v: IndexedVec<String> = vec!["a".to_string(), "bb".to_string()].into();
+/// let mut cursor = <AlignedCursor<A16>>::new();
+/// v.serialize(&mut cursor).unwrap();
+///
+/// let view = <IndexedVec<String>>::deserialize_eps(cursor.as_bytes()).unwrap();
+/// assert_eq!(view.len(), 2);
+/// assert_eq!(view.get(1).unwrap(), "bb");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default, MemDbg, MemSize)]
+pub struct IndexedVec<T>(pub Vec<T>);
+
+impl<T> IndexedVec<T> {
+    /// Return the wrapped vector.
+    pub fn into_inner(self) -> Vec<T> {
+        self.0
+    }
+}
+
+impl<T> From<Vec<T>> for IndexedVec<T> {
+    fn from(v: Vec<T>) -> Self {
+        Self(v)
+    }
+}
+
+impl<T> Deref for IndexedVec<T> {
+    type Target = Vec<T>;
+    #[inline(always)]
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T> DerefMut for IndexedVec<T> {
+    #[inline(always)]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+/// The ε-copy deserialization type of an [`IndexedVec`].
+///
+/// Elements are ε-copy deserialized on demand by [`get`](VecView::get), so
+/// accessing an element does not require deserializing the others.
+#[derive(Debug)]
+pub struct VecView<'a, T> {
+    /// The offsets of the elements, relative to the start of `data`.
+    offsets: &'a [usize],
+    /// The backend, starting from the first element.
+    data: &'a [u8],
+    /// The position of the first element in the backend.
+    pos: usize,
+    _marker: PhantomData<T>,
+}
+
+impl<T> Clone for VecView<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for VecView<'_, T> {}
+
+impl<'a, T: DeserializeInner> VecView<'a, T> {
+    /// Return the number of elements.
+    pub fn len(&self) -> usize {
+        self.offsets.len() - 1
+    }
+
+    /// Return whether there are no elements.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// ε-copy deserialize the element of given index.
+    ///
+    /// # Panics
+    ///
+    /// If `index` is not smaller than the number of elements.
+    pub fn get(&self, index: usize) -> deser::Result<DeserType<'a, T>> {
+        let offset = self.offsets[index];
+        let mut backend = SliceWithPos {
+            data: &self.data[offset..self.offsets[index + 1]],
+            pos: self.pos + offset,
+        };
+        T::_deserialize_eps_inner(&mut backend)
+    }
+
+    /// Return an iterator ε-copy deserializing the elements.
+    pub fn iter(&self) -> impl Iterator<Item = deser::Result<DeserType<'a, T>>> + 'a
+    where
+        T: 'a,
+    {
+        let view = *self;
+        (0..view.len()).map(move |index| view.get(index))
+    }
+}
+
+impl<T> CopyType for IndexedVec<T> {
+    type Copy = Deep;
+}
+
+impl<T: TypeHash> TypeHash for IndexedVec<T> {
+    fn type_hash(hasher: &mut impl core::hash::Hasher) {
+        "IndexedVec".hash(hasher);
+        T::type_hash(hasher);
+    }
+}
+
+impl<T: ReprHash> ReprHash for IndexedVec<T> {
+    fn repr_hash(hasher: &mut impl core::hash::Hasher, offset_of: &mut usize) {
+        *offset_of = 0;
+        usize::repr_hash(hasher, offset_of);
+        *offset_of = 0;
+        T::repr_hash(hasher, offset_of);
+    }
+}
+
+/// A writer discarding data, used to compute the positions of the elements of
+/// an [`IndexedVec`] before writing them.
+struct PosCounter {
+    pos: usize,
+    deterministic: bool,
+}
+
+impl WriteNoStd for PosCounter {
+    fn write_all(&mut self, buf: &[u8]) -> ser::Result<()> {
+        self.pos += buf.len();
+        Ok(())
+    }
+
+    fn flush(&mut self) -> ser::Result<()> {
+        Ok(())
+    }
+}
+
+impl WriteWithPos for PosCounter {
+    fn pos(&self) -> usize {
+        self.pos
+    }
+}
+
+impl WriteWithNames for PosCounter {
+    fn deterministic(&self) -> bool {
+        self.deterministic
+    }
+}
+
+impl<T: SerializeInner> SerializeInner for IndexedVec<T> {
+    const IS_ZERO_COPY: bool = false;
+    const ZERO_COPY_MISMATCH: bool = false;
+
+    fn _serialize_inner(&self, backend: &mut impl WriteWithNames) -> ser::Result<()> {
+        // Padding depends on the absolute position, so we first compute the
+        // position of the first element, and then simulate writing the elements
+        let mut counter = PosCounter {
+            pos: backend.pos(),
+            deterministic: backend.deterministic(),
+        };
+        let mut offsets = vec![0; self.0.len() + 1];
+        counter.write("offsets", &offsets)?;
+        let start = counter.pos;
+        for (i, item) in self.0.iter().enumerate() {
+            counter.write("item", item)?;
+            offsets[i + 1] = counter.pos - start;
+        }
+
+        backend.write("offsets", &offsets)?;
+        for item in &self.0 {
+            backend.write("item", item)?;
+        }
+        Ok(())
+    }
+}
+
+impl<T: DeserializeInner> DeserializeInner for IndexedVec<T> {
+    fn _deserialize_full_inner(backend: &mut impl ReadWithPos) -> deser::Result<Self> {
+        let offsets = deserialize_full_vec_zero::<usize>(backend)?;
+        let len = offsets
+            .len()
+            .checked_sub(1)
+            .ok_or(deser::Error::InvalidOffsets)?;
+        let mut res = Vec::with_capacity(prealloc_len::<T>(backend, len));
+        for _ in 0..len {
+            res.push(T::_deserialize_full_inner(backend)?);
+        }
+        Ok(Self(res))
+    }
+
+    type DeserType<'a> = VecView<'a, T>;
+
+    fn _deserialize_eps_inner<'a>(
+        backend: &mut SliceWithPos<'a>,
+    ) -> deser::Result<Self::DeserType<'a>> {
+        let offsets = deserialize_eps_slice_zero::<usize>(backend)?;
+        // Offsets must also end within the backend
+        let end = check_offsets(offsets)?;
+        if end > backend.data.len() {
+            return Err(deser::Error::InvalidOffsets);
+        }
+        let view = VecView {
+            offsets,
+            data: &backend.data[..end],
+            pos: backend.pos,
+            _marker: PhantomData,
+        };
+        backend.skip(end);
+        Ok(view)
+    }
+}
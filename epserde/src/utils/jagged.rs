@@ -0,0 +1,297 @@
+/*
+ * SPDX-FileCopyrightText: 2023 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+use crate::deser::helpers::*;
+use crate::deser::ReadWithPos;
+use crate::prelude::*;
+use crate::ser::WriteWithNames;
+use core::hash::Hash;
+use mem_dbg::{MemDbg, MemSize};
+
+/// A jagged array of zero-copy elements stored in compressed-row format.
+///
+/// A `Vec<Vec<T>>` ε-copy deserializes to a `Vec<&[T]>`, which requires
+/// allocating the outer vector. A [`Jagged`] stores instead all rows
+/// contiguously in a single data vector, plus a vector of offsets delimiting
+/// the rows (the first offset is always zero, and the last one is the length
+/// of the data vector). Thus, it ε-copy deserializes to a [`JaggedView`], which
+/// just contains two references to the backend, and no allocation is needed.
+///
+/// ```rust
+/// use epserde::prelude::*;
+/// use maligned::A16;
+///
+/// let jagged: Jagged<u32> = vec![vec![1, 2, 3], vec![], vec![4, 5]].into();
+/// let mut cursor = <AlignedCursor<A16>>::new();
+/// jagged.serialize(&mut cursor).unwrap();
+///
+/// let view = <Jagged<u32>>::deserialize_eps(cursor.as_bytes()).unwrap();
+/// assert_eq!(view.len(), 3);
+/// assert_eq!(view.get(0), &[1, 2, 3]);
+/// assert!(view.get(1).is_empty());
+/// assert_eq!(view.get(2), &[4, 5]);
+/// ```
+///
+/// A slice of slices of zero-copy elements can be serialized directly as a
+/// [`Jagged`], without copying its content:
+///
+/// ```rust
+/// use epserde::prelude::*;
+/// use maligned::A16;
+///
+/// let rows: &[&[u32]] = &[&[1, 2, 3], &[], &[4, 5]];
+/// let mut cursor = <AlignedCursor<A16>>::new();
+/// rows.serialize(&mut cursor).unwrap();
+///
+/// let view = <Jagged<u32>>::deserialize_eps(cursor.as_bytes()).unwrap();
+/// assert_eq!(view.get(2), &[4, 5]);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash, MemDbg, MemSize)]
+pub struct Jagged<T> {
+    offsets: Vec<usize>,
+    data: Vec<T>,
+}
+
+impl<T> Default for Jagged<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Jagged<T> {
+    /// Create a new empty jagged array.
+    pub fn new() -> Self {
+        Self {
+            offsets: vec![0],
+            data: Vec::new(),
+        }
+    }
+
+    /// Append a row.
+    pub fn push(&mut self, row: impl IntoIterator<Item = T>) {
+        self.data.extend(row);
+        self.offsets.push(self.data.len());
+    }
+
+    /// Return the number of rows.
+    pub fn len(&self) -> usize {
+        self.offsets.len().saturating_sub(1)
+    }
+
+    /// Return whether there are no rows.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Return the row of given index.
+    ///
+    /// # Panics
+    ///
+    /// If `index` is not smaller than the number of rows.
+    pub fn get(&self, index: usize) -> &[T] {
+        &self.data[self.offsets[index]..self.offsets[index + 1]]
+    }
+
+    /// Return an iterator over the rows.
+    pub fn iter(&self) -> impl Iterator<Item = &[T]> + '_ {
+        self.offsets.windows(2).map(|w| &self.data[w[0]..w[1]])
+    }
+
+    /// Return the offsets delimiting the rows.
+    pub fn offsets(&self) -> &[usize] {
+        &self.offsets
+    }
+
+    /// Return the concatenation of all rows.
+    pub fn data(&self) -> &[T] {
+        &self.data
+    }
+
+    /// Return a view on this jagged array.
+    pub fn as_view(&self) -> JaggedView<'_, T> {
+        JaggedView {
+            offsets: &self.offsets,
+            data: &self.data,
+        }
+    }
+}
+
+impl<T, R: IntoIterator<Item = T>> FromIterator<R> for Jagged<T> {
+    fn from_iter<I: IntoIterator<Item = R>>(iter: I) -> Self {
+        let mut jagged = Self::new();
+        for row in iter {
+            jagged.push(row);
+        }
+        jagged
+    }
+}
+
+impl<T> From<Vec<Vec<T>>> for Jagged<T> {
+    fn from(rows: Vec<Vec<T>>) -> Self {
+        rows.into_iter().collect()
+    }
+}
+
+/// The ε-copy deserialization type of a [`Jagged`] array.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct JaggedView<'a, T> {
+    offsets: &'a [usize],
+    data: &'a [T],
+}
+
+impl<'a, T> JaggedView<'a, T> {
+    /// Return the number of rows.
+    pub fn len(&self) -> usize {
+        self.offsets.len().saturating_sub(1)
+    }
+
+    /// Return whether there are no rows.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Return the row of given index.
+    ///
+    /// # Panics
+    ///
+    /// If `index` is not smaller than the number of rows.
+    pub fn get(&self, index: usize) -> &'a [T] {
+        &self.data[self.offsets[index]..self.offsets[index + 1]]
+    }
+
+    /// Return an iterator over the rows.
+    pub fn iter(&self) -> impl Iterator<Item = &'a [T]> + 'a {
+        let data = self.data;
+        self.offsets.windows(2).map(move |w| &data[w[0]..w[1]])
+    }
+
+    /// Return the offsets delimiting the rows.
+    pub fn offsets(&self) -> &'a [usize] {
+        self.offsets
+    }
+
+    /// Return the concatenation of all rows.
+    pub fn data(&self) -> &'a [T] {
+        self.data
+    }
+}
+
+impl<T: Clone> From<JaggedView<'_, T>> for Jagged<T> {
+    fn from(view: JaggedView<'_, T>) -> Self {
+        Self {
+            offsets: view.offsets.to_vec(),
+            data: view.data.to_vec(),
+        }
+    }
+}
+
+impl<T> CopyType for Jagged<T> {
+    type Copy = Deep;
+}
+
+impl<T: TypeHash> TypeHash for Jagged<T> {
+    fn type_hash(hasher: &mut impl core::hash::Hasher) {
+        "Jagged".hash(hasher);
+        T::type_hash(hasher);
+    }
+}
+
+impl<T: ReprHash> ReprHash for Jagged<T> {
+    fn repr_hash(hasher: &mut impl core::hash::Hasher, offset_of: &mut usize) {
+        *offset_of = 0;
+        usize::repr_hash(hasher, offset_of);
+        *offset_of = 0;
+        T::repr_hash(hasher, offset_of);
+    }
+}
+
+impl<T: ZeroCopy + SerializeInner + TypeHash> SerializeInner for Jagged<T> {
+    const IS_ZERO_COPY: bool = false;
+    const ZERO_COPY_MISMATCH: bool = false;
+
+    fn _serialize_inner(&self, backend: &mut impl WriteWithNames) -> ser::Result<()> {
+        backend.write("offsets", &self.offsets)?;
+        backend.write("data", &self.data)
+    }
+}
+
+impl<T: ZeroCopy + DeserializeInner + 'static> DeserializeInner for Jagged<T> {
+    fn _deserialize_full_inner(backend: &mut impl ReadWithPos) -> deser::Result<Self> {
+        let offsets = deserialize_full_vec_zero::<usize>(backend)?;
+        let data = deserialize_full_vec_zero::<T>(backend)?;
+        check_rows(&offsets, data.len())?;
+        Ok(Self { offsets, data })
+    }
+
+    type DeserType<'a> = JaggedView<'a, T>;
+
+    fn _deserialize_eps_inner<'a>(
+        backend: &mut SliceWithPos<'a>,
+    ) -> deser::Result<Self::DeserType<'a>> {
+        let offsets = deserialize_eps_slice_zero::<usize>(backend)?;
+        let data = deserialize_eps_slice_zero::<T>(backend)?;
+        check_rows(offsets, data.len())?;
+        Ok(JaggedView { offsets, data })
+    }
+}
+
+/// Check that the offsets of a jagged array delimit rows covering exactly
+/// the given number of elements.
+fn check_rows(offsets: &[usize], len: usize) -> deser::Result<()> {
+    let end = check_offsets(offsets)?;
+    if end != len {
+        return Err(deser::Error::InvalidOffsets);
+    }
+    Ok(())
+}
+
+/// The flat data of a slice of slices, serialized as a vector.
+struct FlatRows<'a, T>(&'a [&'a [T]]);
+
+impl<T: ZeroCopy + SerializeInner> SerializeInner for FlatRows<'_, T> {
+    const IS_ZERO_COPY: bool = false;
+    const ZERO_COPY_MISMATCH: bool = false;
+
+    fn _serialize_inner(&self, backend: &mut impl WriteWithNames) -> ser::Result<()> {
+        ser::helpers::check_zero_copy::<T>();
+        let len = self.0.iter().map(|row| row.len()).sum::<usize>();
+        backend.write("len", &len)?;
+        backend.align::<T>()?;
+        for row in self.0 {
+            let buffer = unsafe {
+                #[allow(clippy::manual_slice_size_calculation)]
+                core::slice::from_raw_parts(
+                    row.as_ptr() as *const u8,
+                    row.len() * core::mem::size_of::<T>(),
+                )
+            };
+            backend.write_bytes::<T>(buffer)?;
+        }
+        Ok(())
+    }
+}
+
+// A slice of slices is serialized as a Jagged, so it has the same type hash.
+impl<T: TypeHash> TypeHash for [&[T]] {
+    fn type_hash(hasher: &mut impl core::hash::Hasher) {
+        Jagged::<T>::type_hash(hasher);
+    }
+}
+
+/// Serialize a slice of slices of zero-copy elements as a [`Jagged`].
+impl<T: ZeroCopy + SerializeInner + TypeHash + ReprHash> Serialize for [&[T]] {
+    fn serialize_on_field_write(&self, backend: &mut impl WriteWithNames) -> ser::Result<()> {
+        ser::write_header::<Jagged<T>>(backend)?;
+        let mut offsets = Vec::with_capacity(self.len() + 1);
+        offsets.push(0);
+        for row in self {
+            offsets.push(offsets.last().unwrap() + row.len());
+        }
+        backend.write("offsets", &offsets)?;
+        backend.write("data", &FlatRows(self))?;
+        backend.flush()
+    }
+}
@@ -0,0 +1,229 @@
+/*
+ * SPDX-FileCopyrightText: 2023 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+use super::sorted_map::ZeroSeq;
+use crate::deser::helpers::*;
+use crate::deser::ReadWithPos;
+use crate::prelude::*;
+use crate::ser::WriteWithNames;
+use core::hash::{BuildHasher, Hash};
+use core::ops::{Deref, DerefMut};
+use std::collections::hash_map::{HashMap, RandomState};
+
+/// A [`HashMap`] with zero-copy keys and values whose entries are serialized
+/// as two parallel arrays.
+///
+/// A [`HashMap`] is serialized as a sequence of interleaved key-value pairs,
+/// and it ε-copy deserializes to a new [`HashMap`]. This wrapper serializes
+/// instead the vector of the keys followed by the vector of the
+/// corresponding values, so it ε-copy deserializes to a [`MapSoaView`],
+/// which just contains two references to the backend: keys can thus be
+/// scanned without touching the values, and with no need to rebuild the map.
+/// Lookups in a [`MapSoaView`] are linear.
+///
+/// In [deterministic](crate::ser::WriteWithNames::deterministic) mode
+/// entries are sorted by the serialized bytes of their keys.
+///
+/// ```rust
+/// use epserde::prelude::*;
+/// use maligned::A16;
+/// use std::collections::HashMap;
+///
+/// let map: HashMap<u64, u32> = [(10, 1), (20, 2), (30, 3)].into();
+/// let mut cursor = <AlignedCursor<A16>>::new();
+/// MapSoa::from(map).serialize(&mut cursor).unwrap();
+///
+/// let view = <MapSoa<u64, u32>>::deserialize_eps(cursor.as_bytes()).unwrap();
+/// assert_eq!(view.len(), 3);
+/// assert_eq!(view.keys().iter().max(), Some(&30));
+/// assert_eq!(view.get(&20), Some(&2));
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct MapSoa<K, V, S = RandomState>(pub HashMap<K, V, S>);
+
+impl<K, V, S> MapSoa<K, V, S> {
+    /// Return the wrapped map.
+    pub fn into_inner(self) -> HashMap<K, V, S> {
+        self.0
+    }
+}
+
+impl<K, V, S> From<HashMap<K, V, S>> for MapSoa<K, V, S> {
+    fn from(map: HashMap<K, V, S>) -> Self {
+        Self(map)
+    }
+}
+
+impl<K, V, S> Deref for MapSoa<K, V, S> {
+    type Target = HashMap<K, V, S>;
+    #[inline(always)]
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<K, V, S> DerefMut for MapSoa<K, V, S> {
+    #[inline(always)]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+/// The ε-copy deserialization type of a [`MapSoa`].
+///
+/// The keys and the values are stored in two parallel slices, in the order
+/// in which they were serialized.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct MapSoaView<'a, K, V> {
+    keys: &'a [K],
+    values: &'a [V],
+}
+
+impl<'a, K, V> MapSoaView<'a, K, V> {
+    /// Return the number of entries.
+    pub fn len(&self) -> usize {
+        self.keys.len()
+    }
+
+    /// Return whether there are no entries.
+    pub fn is_empty(&self) -> bool {
+        self.keys.is_empty()
+    }
+
+    /// Return the keys.
+    pub fn keys(&self) -> &'a [K] {
+        self.keys
+    }
+
+    /// Return the values, in the same order of the corresponding keys.
+    pub fn values(&self) -> &'a [V] {
+        self.values
+    }
+
+    /// Return an iterator over the entries.
+    pub fn iter(&self) -> impl Iterator<Item = (&'a K, &'a V)> + 'a {
+        self.keys.iter().zip(self.values)
+    }
+}
+
+impl<'a, K: PartialEq, V> MapSoaView<'a, K, V> {
+    /// Return the value associated with a key, if any.
+    ///
+    /// This method performs a linear scan of the keys.
+    pub fn get(&self, key: &K) -> Option<&'a V> {
+        self.keys
+            .iter()
+            .position(|k| k == key)
+            .map(|index| &self.values[index])
+    }
+
+    /// Return whether the map contains a key.
+    ///
+    /// This method performs a linear scan of the keys.
+    pub fn contains_key(&self, key: &K) -> bool {
+        self.keys.contains(key)
+    }
+}
+
+impl<K: Eq + Hash + Clone, V: Clone, S: BuildHasher + Default> From<MapSoaView<'_, K, V>>
+    for MapSoa<K, V, S>
+{
+    fn from(view: MapSoaView<'_, K, V>) -> Self {
+        Self(
+            view.keys
+                .iter()
+                .cloned()
+                .zip(view.values.iter().cloned())
+                .collect(),
+        )
+    }
+}
+
+impl<K, V, S> CopyType for MapSoa<K, V, S> {
+    type Copy = Deep;
+}
+
+impl<K: TypeHash, V: TypeHash, S: TypeHash> TypeHash for MapSoa<K, V, S> {
+    fn type_hash(hasher: &mut impl core::hash::Hasher) {
+        "MapSoa".hash(hasher);
+        K::type_hash(hasher);
+        V::type_hash(hasher);
+        S::type_hash(hasher);
+    }
+}
+
+impl<K: ReprHash, V: ReprHash, S> ReprHash for MapSoa<K, V, S> {
+    fn repr_hash(hasher: &mut impl core::hash::Hasher, offset_of: &mut usize) {
+        *offset_of = 0;
+        K::repr_hash(hasher, offset_of);
+        *offset_of = 0;
+        V::repr_hash(hasher, offset_of);
+    }
+}
+
+impl<K: ZeroCopy + SerializeInner, V: ZeroCopy + SerializeInner, S> SerializeInner
+    for MapSoa<K, V, S>
+{
+    const IS_ZERO_COPY: bool = false;
+    const ZERO_COPY_MISMATCH: bool = false;
+
+    fn _serialize_inner(&self, backend: &mut impl WriteWithNames) -> ser::Result<()> {
+        let mut entries = self.0.iter().collect::<Vec<_>>();
+        if backend.deterministic() {
+            entries = ser::helpers::sort_by_key_bytes(entries)?;
+        }
+        backend.write(
+            "keys",
+            &ZeroSeq {
+                iter: entries.iter().map(|(key, _)| *key),
+                len: entries.len(),
+            },
+        )?;
+        backend.write(
+            "values",
+            &ZeroSeq {
+                iter: entries.iter().map(|(_, value)| *value),
+                len: entries.len(),
+            },
+        )
+    }
+}
+
+impl<K, V, S> DeserializeInner for MapSoa<K, V, S>
+where
+    K: ZeroCopy + DeserializeInner + Eq + Hash + 'static,
+    V: ZeroCopy + DeserializeInner + 'static,
+    S: BuildHasher + Default,
+{
+    fn _deserialize_full_inner(backend: &mut impl ReadWithPos) -> deser::Result<Self> {
+        let keys = deserialize_full_vec_zero::<K>(backend)?;
+        let values = deserialize_full_vec_zero::<V>(backend)?;
+        // Keys and values must be in one-to-one correspondence
+        if keys.len() != values.len() {
+            return Err(deser::Error::LengthMismatch {
+                expected: keys.len(),
+                got: values.len(),
+            });
+        }
+        Ok(Self(keys.into_iter().zip(values).collect()))
+    }
+
+    type DeserType<'a> = MapSoaView<'a, K, V>;
+
+    fn _deserialize_eps_inner<'a>(
+        backend: &mut SliceWithPos<'a>,
+    ) -> deser::Result<Self::DeserType<'a>> {
+        let keys = deserialize_eps_slice_zero::<K>(backend)?;
+        let values = deserialize_eps_slice_zero::<V>(backend)?;
+        if keys.len() != values.len() {
+            return Err(deser::Error::LengthMismatch {
+                expected: keys.len(),
+                got: values.len(),
+            });
+        }
+        Ok(MapSoaView { keys, values })
+    }
+}
@@ -6,3 +6,19 @@
 
 mod aligned_cursor;
 pub use aligned_cursor::AlignedCursor;
+mod aligned_vec;
+pub use aligned_vec::AlignedVec;
+mod jagged;
+pub use jagged::{Jagged, JaggedView};
+mod aligned_bytes;
+pub use aligned_bytes::{AlignedBytes, AlignmentType, ConstAlignment};
+mod sorted_map;
+pub use sorted_map::SortedMapView;
+mod indexed_vec;
+pub use indexed_vec::{IndexedVec, VecView};
+mod map_soa;
+pub use map_soa::{MapSoa, MapSoaView};
+mod self_describing;
+pub use self_describing::{DynNode, DynValue, SelfDescribing, SelfDescribingView};
+mod record_log;
+pub use record_log::{RecordLogReader, RecordLogWriter, RECORD_ALIGN};
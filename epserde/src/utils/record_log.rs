@@ -0,0 +1,157 @@
+/*
+ * SPDX-FileCopyrightText: 2023 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+use crate::prelude::*;
+use crate::ser::WriteNoStd;
+use mem_dbg::{MemDbg, MemSize};
+
+/// The alignment of the records of a log.
+///
+/// Records start at a multiple of this alignment from the start of the log,
+/// so if the log is aligned in memory, records can be ε-copy deserialized.
+pub const RECORD_ALIGN: usize = 16;
+
+/// The size of the framing of a record: its type hash and its length.
+const FRAME_SIZE: usize = 2 * core::mem::size_of::<u64>();
+
+/// A writer appending records of possibly different types to a log.
+///
+/// Each record is framed by the [type hash](crate::traits::TypeHash) of its
+/// type and by its length, and contains a standard serialization of a value,
+/// header included. Records are padded so that each record starts at a
+/// multiple of [`RECORD_ALIGN`] bytes. The log can be read with a
+/// [`RecordLogReader`].
+///
+/// Since the length of a log is always a multiple of [`RECORD_ALIGN`], an
+/// existing log can be extended by wrapping a writer positioned at its end
+/// (e.g., a file opened in append mode).
+///
+/// ```rust
+/// use epserde::prelude::*;
+/// use maligned::A16;
+///
+/// let mut log = RecordLogWriter::new(<AlignedCursor<A16>>::new());
+/// log.append(&vec![1_u32, 2, 3]).unwrap();
+/// log.append(&"hello".to_string()).unwrap();
+/// let mut cursor = log.into_inner();
+///
+/// let (vec_hash, _) = deser::type_and_repr_hash::<Vec<u32>>();
+/// for record in RecordLogReader::new(cursor.as_bytes()) {
+///     let (type_hash, bytes) = record.unwrap();
+///     if type_hash == vec_hash {
+///         assert_eq!(<Vec<u32>>::deserialize_eps(bytes).unwrap(), &[1, 2, 3]);
+///     } else {
+///         assert_eq!(String::deserialize_eps(bytes).unwrap(), "hello");
+///     }
+/// }
+/// ```
+#[derive(Debug, Clone, Default, MemDbg, MemSize)]
+pub struct RecordLogWriter<W: WriteNoStd> {
+    /// The wrapped writer.
+    backend: W,
+    /// How many records have been written.
+    num_records: usize,
+}
+
+impl<W: WriteNoStd> RecordLogWriter<W> {
+    /// Create a new [`RecordLogWriter`] on top of a generic [`WriteNoStd`] `W`.
+    pub fn new(backend: W) -> Self {
+        Self {
+            backend,
+            num_records: 0,
+        }
+    }
+
+    /// Append a record containing the given value, returning the number of
+    /// bytes written.
+    pub fn append<T: Serialize + ?Sized>(&mut self, value: &T) -> ser::Result<usize> {
+        let mut payload = Vec::new();
+        value.serialize(&mut payload)?;
+        let (type_hash, _) = deser::type_and_repr_hash::<T>();
+        self.backend.write_all(&type_hash.to_ne_bytes())?;
+        self.backend
+            .write_all(&(payload.len() as u64).to_ne_bytes())?;
+        self.backend.write_all(&payload)?;
+        let padding = crate::pad_align_to(payload.len(), RECORD_ALIGN);
+        self.backend.write_all(&[0; RECORD_ALIGN][..padding])?;
+        self.num_records += 1;
+        Ok(FRAME_SIZE + payload.len() + padding)
+    }
+
+    /// Return the number of records appended by this writer.
+    pub fn num_records(&self) -> usize {
+        self.num_records
+    }
+
+    /// Flush the wrapped writer.
+    pub fn flush(&mut self) -> ser::Result<()> {
+        self.backend.flush()
+    }
+
+    /// Consume this writer, returning the wrapped writer.
+    pub fn into_inner(self) -> W {
+        self.backend
+    }
+}
+
+/// An iterator over the records of a log written by a [`RecordLogWriter`].
+///
+/// Each record is returned as a pair given by the type hash of its type,
+/// which the caller can use to dispatch the record (e.g., comparing it with
+/// the first value returned by [`deser::type_and_repr_hash`]), and by the
+/// serialized value. The header of the value is checked at deserialization,
+/// so dispatching to the wrong type results in an error.
+///
+/// The iterator returns a [`Result`](deser::Result) for each record; after
+/// an error (e.g., a truncated record), it returns no further records.
+#[derive(Debug, Clone)]
+pub struct RecordLogReader<'a> {
+    data: &'a [u8],
+    failed: bool,
+}
+
+impl<'a> RecordLogReader<'a> {
+    /// Create a new [`RecordLogReader`] on the given log.
+    pub fn new(data: &'a [u8]) -> Self {
+        Self {
+            data,
+            failed: false,
+        }
+    }
+
+    fn next_record(&mut self) -> deser::Result<(u64, &'a [u8])> {
+        if self.data.len() < FRAME_SIZE {
+            return Err(deser::Error::unexpected_eof());
+        }
+        let (frame, rest) = self.data.split_at(FRAME_SIZE);
+        let type_hash = u64::from_ne_bytes(frame[..8].try_into().unwrap());
+        let len = u64::from_ne_bytes(frame[8..].try_into().unwrap());
+        let len = deser::helpers::usize_from_u64(len)?;
+        if len > rest.len() {
+            return Err(deser::Error::unexpected_eof());
+        }
+        let (payload, rest) = rest.split_at(len);
+        // The padding of the last record might be missing
+        let padding = crate::pad_align_to(len, RECORD_ALIGN).min(rest.len());
+        self.data = &rest[padding..];
+        Ok((type_hash, payload))
+    }
+}
+
+impl<'a> Iterator for RecordLogReader<'a> {
+    type Item = deser::Result<(u64, &'a [u8])>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.failed || self.data.is_empty() {
+            return None;
+        }
+        let res = self.next_record();
+        self.failed = res.is_err();
+        Some(res)
+    }
+}
+
+impl core::iter::FusedIterator for RecordLogReader<'_> {}
@@ -0,0 +1,402 @@
+/*
+ * SPDX-FileCopyrightText: 2023 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+use crate::deser::helpers::*;
+use crate::deser::ReadWithPos;
+use crate::prelude::*;
+use crate::ser::WriteWithNames;
+use core::hash::Hash;
+use mem_dbg::{MemDbg, MemSize};
+
+/// Serialized data together with its [`Schema`], making it possible to
+/// inspect the data without knowing its type.
+///
+/// A [`SelfDescribing`] instance contains the serialization of a value and
+/// the rows of the [schema](Serialize::serialize_with_schema) recorded
+/// during serialization. It is usually created and serialized by
+/// [`Serialize::serialize_self_describing`]; it ε-copy deserializes to a
+/// [`SelfDescribingView`], whose [`root`](SelfDescribingView::root) method
+/// returns a [tree](DynNode) of the serialized fields, keyed by name:
+///
+/// ```rust
+/// use epserde::prelude::*;
+/// use maligned::A64;
+///
+/// let mut cursor = <AlignedCursor<A64>>::new();
+/// vec![1_u32, 2, 3].serialize_self_describing(&mut cursor).unwrap();
+///
+/// let view = SelfDescribing::deserialize_eps(cursor.as_bytes()).unwrap();
+/// let root = view.root().unwrap();
+/// assert_eq!(root.get("len").unwrap().value(), Some(DynValue::Usize(3)));
+/// ```
+///
+/// The serialized value is stored aligned to 64 bytes, so if the backend is
+/// aligned to 64 bytes it can also be ε-copy deserialized using
+/// [`SelfDescribingView::deserialize_eps`], provided that its type is known.
+#[derive(Debug, Clone, MemDbg, MemSize)]
+pub struct SelfDescribing {
+    fields: Vec<String>,
+    types: Vec<String>,
+    offsets: Vec<usize>,
+    sizes: Vec<usize>,
+    aligns: Vec<usize>,
+    data: AlignedVec<u8, 64>,
+}
+
+impl SelfDescribing {
+    /// Serialize a value, recording its schema.
+    pub fn new<T: Serialize + ?Sized>(value: &T) -> ser::Result<Self> {
+        let mut data = Vec::new();
+        let schema = value.serialize_with_schema(&mut data)?;
+        let rows = schema.0;
+        Ok(Self {
+            fields: rows.iter().map(|row| row.field.clone()).collect(),
+            types: rows.iter().map(|row| row.ty.clone()).collect(),
+            offsets: rows.iter().map(|row| row.offset).collect(),
+            sizes: rows.iter().map(|row| row.size).collect(),
+            aligns: rows.iter().map(|row| row.align).collect(),
+            data: data.into(),
+        })
+    }
+
+    /// Return a view on this instance.
+    pub fn as_view(&self) -> SelfDescribingView<'_> {
+        SelfDescribingView {
+            fields: self.fields.iter().map(String::as_str).collect(),
+            types: self.types.iter().map(String::as_str).collect(),
+            offsets: &self.offsets,
+            sizes: &self.sizes,
+            aligns: &self.aligns,
+            data: &self.data,
+        }
+    }
+}
+
+/// The ε-copy deserialization type of a [`SelfDescribing`] instance.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SelfDescribingView<'a> {
+    fields: Vec<&'a str>,
+    types: Vec<&'a str>,
+    offsets: &'a [usize],
+    sizes: &'a [usize],
+    aligns: &'a [usize],
+    data: &'a [u8],
+}
+
+impl<'a> SelfDescribingView<'a> {
+    /// Return the schema of the serialized value.
+    pub fn schema(&self) -> Schema {
+        Schema(
+            (0..self.fields.len())
+                .map(|i| SchemaRow {
+                    field: self.fields[i].to_string(),
+                    ty: self.types[i].to_string(),
+                    offset: self.offsets[i],
+                    size: self.sizes[i],
+                    align: self.aligns[i],
+                })
+                .collect(),
+        )
+    }
+
+    /// Return the serialized value, including its header.
+    pub fn data(&self) -> &'a [u8] {
+        self.data
+    }
+
+    /// ε-copy deserialize the serialized value.
+    pub fn deserialize_eps<T: Deserialize>(&self) -> deser::Result<DeserType<'a, T>> {
+        T::deserialize_eps(self.data)
+    }
+
+    /// Return the tree of the data written during serialization, or `None`
+    /// if there is no root field (e.g., because the value was serialized
+    /// with custom framing).
+    ///
+    /// The returned node is the one of the root field `ROOT`; the fields of
+    /// the header (e.g., `TYPE_NAME`) are available through
+    /// [`header`](SelfDescribingView::header). Padding is omitted.
+    pub fn root(&self) -> Option<DynNode<'a>> {
+        self.tree()
+            .children
+            .into_iter()
+            .find(|node| node.field == "ROOT")
+    }
+
+    /// Return the nodes of the fields of the header.
+    pub fn header(&self) -> Vec<DynNode<'a>> {
+        self.tree()
+            .children
+            .into_iter()
+            .filter(|node| node.field != "ROOT")
+            .collect()
+    }
+
+    /// Build a tree from the rows of the schema, which are in preorder.
+    fn tree(&self) -> DynNode<'a> {
+        let new_node = |i: usize| DynNode {
+            field: self.fields[i],
+            ty: self.types[i],
+            // Ranges are checked at deserialization time
+            bytes: &self.data[self.offsets[i]..self.offsets[i] + self.sizes[i]],
+            children: vec![],
+        };
+        let mut stack = vec![DynNode {
+            field: "",
+            ty: "",
+            bytes: self.data,
+            children: vec![],
+        }];
+        for i in 0..self.fields.len() {
+            if self.fields[i] == "PADDING" {
+                continue;
+            }
+            let depth = self.fields[i].split('.').count();
+            // The stack contains the ancestors of the current node
+            while stack.len() > depth {
+                let node = stack.pop().unwrap();
+                stack.last_mut().unwrap().children.push(node);
+            }
+            stack.push(new_node(i));
+        }
+        while stack.len() > 1 {
+            let node = stack.pop().unwrap();
+            stack.last_mut().unwrap().children.push(node);
+        }
+        stack.pop().unwrap()
+    }
+}
+
+/// A node of the tree of the data written during serialization, as returned
+/// by [`SelfDescribingView::root`].
+///
+/// Each node corresponds to a row of the [`Schema`]: it has a name, a type
+/// name, the serialized bytes, and the nodes of the data written to
+/// serialize it. For example, the node of a vector has a child `len` and
+/// either a child `zero` containing all elements, if they are zero-copy, or a
+/// child `item` for each element.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DynNode<'a> {
+    field: &'a str,
+    ty: &'a str,
+    bytes: &'a [u8],
+    children: Vec<DynNode<'a>>,
+}
+
+impl<'a> DynNode<'a> {
+    /// Return the name of this node.
+    pub fn name(&self) -> &'a str {
+        self.field.rsplit('.').next().unwrap_or(self.field)
+    }
+
+    /// Return the full path of this node (e.g., `ROOT.a.len`).
+    pub fn field(&self) -> &'a str {
+        self.field
+    }
+
+    /// Return the name of the type of this node, as returned by
+    /// [`core::any::type_name`].
+    pub fn ty(&self) -> &'a str {
+        self.ty
+    }
+
+    /// Return the serialized bytes of this node.
+    pub fn bytes(&self) -> &'a [u8] {
+        self.bytes
+    }
+
+    /// Return the children of this node, in serialization order.
+    pub fn children(&self) -> &[DynNode<'a>] {
+        &self.children
+    }
+
+    /// Return the first child with the given name, if any.
+    pub fn get(&self, name: &str) -> Option<&DynNode<'a>> {
+        self.children.iter().find(|node| node.name() == name)
+    }
+
+    /// Return the value of this node, if its type is a primitive type.
+    pub fn value(&self) -> Option<DynValue> {
+        macro_rules! parse {
+            ($variant:ident, $ty:ty) => {
+                self.bytes
+                    .try_into()
+                    .ok()
+                    .map(|bytes| DynValue::$variant(<$ty>::from_ne_bytes(bytes)))
+            };
+        }
+        match self.ty {
+            "bool" => match self.bytes {
+                [byte] => Some(DynValue::Bool(*byte != 0)),
+                _ => None,
+            },
+            "char" => self
+                .bytes
+                .try_into()
+                .ok()
+                .and_then(|bytes| char::from_u32(u32::from_ne_bytes(bytes)))
+                .map(DynValue::Char),
+            "u8" => parse!(U8, u8),
+            "u16" => parse!(U16, u16),
+            "u32" => parse!(U32, u32),
+            "u64" => parse!(U64, u64),
+            "u128" => parse!(U128, u128),
+            "usize" => parse!(Usize, usize),
+            "i8" => parse!(I8, i8),
+            "i16" => parse!(I16, i16),
+            "i32" => parse!(I32, i32),
+            "i64" => parse!(I64, i64),
+            "i128" => parse!(I128, i128),
+            "isize" => parse!(Isize, isize),
+            "f32" => parse!(F32, f32),
+            "f64" => parse!(F64, f64),
+            _ => None,
+        }
+    }
+}
+
+/// The value of a [`DynNode`] of primitive type.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DynValue {
+    Bool(bool),
+    Char(char),
+    U8(u8),
+    U16(u16),
+    U32(u32),
+    U64(u64),
+    U128(u128),
+    Usize(usize),
+    I8(i8),
+    I16(i16),
+    I32(i32),
+    I64(i64),
+    I128(i128),
+    Isize(isize),
+    F32(f32),
+    F64(f64),
+}
+
+impl CopyType for SelfDescribing {
+    type Copy = Deep;
+}
+
+impl TypeHash for SelfDescribing {
+    fn type_hash(hasher: &mut impl core::hash::Hasher) {
+        "SelfDescribing".hash(hasher);
+    }
+}
+
+impl ReprHash for SelfDescribing {
+    fn repr_hash(hasher: &mut impl core::hash::Hasher, offset_of: &mut usize) {
+        *offset_of = 0;
+        usize::repr_hash(hasher, offset_of);
+        *offset_of = 0;
+        u8::repr_hash(hasher, offset_of);
+    }
+}
+
+impl SerializeInner for SelfDescribing {
+    const IS_ZERO_COPY: bool = false;
+    const ZERO_COPY_MISMATCH: bool = false;
+
+    fn _serialize_inner(&self, backend: &mut impl WriteWithNames) -> ser::Result<()> {
+        backend.write("fields", &self.fields)?;
+        backend.write("types", &self.types)?;
+        backend.write("offsets", &self.offsets)?;
+        backend.write("sizes", &self.sizes)?;
+        backend.write("aligns", &self.aligns)?;
+        backend.write("data", &self.data)
+    }
+}
+
+/// Check that the rows have the same number of elements and that they are
+/// within the data.
+fn check_rows(
+    num_fields: usize,
+    num_types: usize,
+    offsets: &[usize],
+    sizes: &[usize],
+    aligns: &[usize],
+    data_len: usize,
+) -> deser::Result<()> {
+    for len in [num_types, offsets.len(), sizes.len(), aligns.len()] {
+        if len != num_fields {
+            return Err(deser::Error::LengthMismatch {
+                expected: num_fields,
+                got: len,
+            });
+        }
+    }
+    for (&offset, &size) in offsets.iter().zip(sizes) {
+        match offset.checked_add(size) {
+            Some(end) if end <= data_len => {}
+            _ => {
+                return Err(deser::Error::InvalidFieldRange {
+                    offset,
+                    size,
+                    data_len,
+                })
+            }
+        }
+    }
+    Ok(())
+}
+
+impl DeserializeInner for SelfDescribing {
+    fn _deserialize_full_inner(backend: &mut impl ReadWithPos) -> deser::Result<Self> {
+        let fields = <Vec<String>>::_deserialize_full_inner(backend)?;
+        let types = <Vec<String>>::_deserialize_full_inner(backend)?;
+        let offsets = deserialize_full_vec_zero::<usize>(backend)?;
+        let sizes = deserialize_full_vec_zero::<usize>(backend)?;
+        let aligns = deserialize_full_vec_zero::<usize>(backend)?;
+        let data = <AlignedVec<u8, 64>>::_deserialize_full_inner(backend)?;
+        check_rows(
+            fields.len(),
+            types.len(),
+            &offsets,
+            &sizes,
+            &aligns,
+            data.len(),
+        )?;
+        Ok(Self {
+            fields,
+            types,
+            offsets,
+            sizes,
+            aligns,
+            data,
+        })
+    }
+
+    type DeserType<'a> = SelfDescribingView<'a>;
+
+    fn _deserialize_eps_inner<'a>(
+        backend: &mut SliceWithPos<'a>,
+    ) -> deser::Result<Self::DeserType<'a>> {
+        let fields = <Vec<String>>::_deserialize_eps_inner(backend)?;
+        let types = <Vec<String>>::_deserialize_eps_inner(backend)?;
+        let offsets = deserialize_eps_slice_zero::<usize>(backend)?;
+        let sizes = deserialize_eps_slice_zero::<usize>(backend)?;
+        let aligns = deserialize_eps_slice_zero::<usize>(backend)?;
+        let data = <AlignedVec<u8, 64>>::_deserialize_eps_inner(backend)?;
+        check_rows(
+            fields.len(),
+            types.len(),
+            offsets,
+            sizes,
+            aligns,
+            data.len(),
+        )?;
+        Ok(SelfDescribingView {
+            fields,
+            types,
+            offsets,
+            sizes,
+            aligns,
+            data,
+        })
+    }
+}
@@ -0,0 +1,217 @@
+/*
+ * SPDX-FileCopyrightText: 2023 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+use crate::deser::helpers::*;
+use crate::deser::ReadWithPos;
+use crate::prelude::*;
+use crate::ser::WriteWithNames;
+use core::hash::Hash;
+use core::ops::{Bound, RangeBounds};
+use std::collections::BTreeMap;
+
+/// The ε-copy deserialization type of a [`BTreeMap`] with zero-copy keys and
+/// values.
+///
+/// A [`BTreeMap`] with zero-copy keys and values is serialized as the vector
+/// of its keys, in increasing order, followed by the vector of the
+/// corresponding values. Thus, it ε-copy deserializes to a [`SortedMapView`],
+/// which just contains two references to the backend, and supports lookups
+/// and range queries by binary search, with no need to rebuild the map.
+///
+/// ```rust
+/// use epserde::prelude::*;
+/// use maligned::A16;
+/// use std::collections::BTreeMap;
+///
+/// let map: BTreeMap<u64, u32> = [(10, 1), (20, 2), (30, 3), (40, 4)].into();
+/// let mut cursor = <AlignedCursor<A16>>::new();
+/// map.serialize(&mut cursor).unwrap();
+///
+/// let view = <BTreeMap<u64, u32>>::deserialize_eps(cursor.as_bytes()).unwrap();
+/// assert_eq!(view.get(&20), Some(&2));
+/// assert_eq!(view.get(&25), None);
+/// assert_eq!(
+///     view.range(15..=30).collect::<Vec<_>>(),
+///     vec![(&20, &2), (&30, &3)]
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SortedMapView<'a, K, V> {
+    keys: &'a [K],
+    values: &'a [V],
+}
+
+impl<'a, K: Ord, V> SortedMapView<'a, K, V> {
+    /// Return the number of entries.
+    pub fn len(&self) -> usize {
+        self.keys.len()
+    }
+
+    /// Return whether there are no entries.
+    pub fn is_empty(&self) -> bool {
+        self.keys.is_empty()
+    }
+
+    /// Return the value associated with a key, if any.
+    pub fn get(&self, key: &K) -> Option<&'a V> {
+        self.keys
+            .binary_search(key)
+            .ok()
+            .map(|index| &self.values[index])
+    }
+
+    /// Return whether the map contains a key.
+    pub fn contains_key(&self, key: &K) -> bool {
+        self.keys.binary_search(key).is_ok()
+    }
+
+    /// Return an iterator over the entries whose keys are in the given range,
+    /// in increasing order of keys.
+    pub fn range(&self, range: impl RangeBounds<K>) -> impl Iterator<Item = (&'a K, &'a V)> + 'a {
+        let start = match range.start_bound() {
+            Bound::Included(key) => self.keys.partition_point(|k| k < key),
+            Bound::Excluded(key) => self.keys.partition_point(|k| k <= key),
+            Bound::Unbounded => 0,
+        };
+        let end = match range.end_bound() {
+            Bound::Included(key) => self.keys.partition_point(|k| k <= key),
+            Bound::Excluded(key) => self.keys.partition_point(|k| k < key),
+            Bound::Unbounded => self.keys.len(),
+        };
+        let end = end.max(start);
+        self.keys[start..end].iter().zip(&self.values[start..end])
+    }
+
+    /// Return an iterator over the entries, in increasing order of keys.
+    pub fn iter(&self) -> impl Iterator<Item = (&'a K, &'a V)> + 'a {
+        self.keys.iter().zip(self.values)
+    }
+
+    /// Return the keys, in increasing order.
+    pub fn keys(&self) -> &'a [K] {
+        self.keys
+    }
+
+    /// Return the values, in increasing order of the corresponding keys.
+    pub fn values(&self) -> &'a [V] {
+        self.values
+    }
+}
+
+impl<K: Ord + Clone, V: Clone> From<SortedMapView<'_, K, V>> for BTreeMap<K, V> {
+    fn from(view: SortedMapView<'_, K, V>) -> Self {
+        view.keys
+            .iter()
+            .cloned()
+            .zip(view.values.iter().cloned())
+            .collect()
+    }
+}
+
+impl<K, V> CopyType for BTreeMap<K, V> {
+    type Copy = Deep;
+}
+
+impl<K: TypeHash, V: TypeHash> TypeHash for BTreeMap<K, V> {
+    fn type_hash(hasher: &mut impl core::hash::Hasher) {
+        "BTreeMap".hash(hasher);
+        K::type_hash(hasher);
+        V::type_hash(hasher);
+    }
+}
+
+impl<K: ReprHash, V: ReprHash> ReprHash for BTreeMap<K, V> {
+    fn repr_hash(hasher: &mut impl core::hash::Hasher, offset_of: &mut usize) {
+        *offset_of = 0;
+        K::repr_hash(hasher, offset_of);
+        *offset_of = 0;
+        V::repr_hash(hasher, offset_of);
+    }
+}
+
+/// A sequence of zero-copy elements, serialized as a vector.
+pub(crate) struct ZeroSeq<I> {
+    pub(crate) iter: I,
+    pub(crate) len: usize,
+}
+
+impl<'a, T: ZeroCopy + SerializeInner + 'a, I: Iterator<Item = &'a T> + Clone> SerializeInner
+    for ZeroSeq<I>
+{
+    const IS_ZERO_COPY: bool = false;
+    const ZERO_COPY_MISMATCH: bool = false;
+
+    fn _serialize_inner(&self, backend: &mut impl WriteWithNames) -> ser::Result<()> {
+        ser::helpers::check_zero_copy::<T>();
+        backend.write("len", &self.len)?;
+        backend.align::<T>()?;
+        for item in self.iter.clone() {
+            let buffer = unsafe {
+                core::slice::from_raw_parts(
+                    item as *const T as *const u8,
+                    core::mem::size_of::<T>(),
+                )
+            };
+            backend.write_bytes::<T>(buffer)?;
+        }
+        Ok(())
+    }
+}
+
+impl<K: ZeroCopy + SerializeInner, V: ZeroCopy + SerializeInner> SerializeInner for BTreeMap<K, V> {
+    const IS_ZERO_COPY: bool = false;
+    const ZERO_COPY_MISMATCH: bool = false;
+
+    fn _serialize_inner(&self, backend: &mut impl WriteWithNames) -> ser::Result<()> {
+        backend.write(
+            "keys",
+            &ZeroSeq {
+                iter: self.keys(),
+                len: self.len(),
+            },
+        )?;
+        backend.write(
+            "values",
+            &ZeroSeq {
+                iter: self.values(),
+                len: self.len(),
+            },
+        )
+    }
+}
+
+impl<K: ZeroCopy + DeserializeInner + Ord + 'static, V: ZeroCopy + DeserializeInner + 'static>
+    DeserializeInner for BTreeMap<K, V>
+{
+    fn _deserialize_full_inner(backend: &mut impl ReadWithPos) -> deser::Result<Self> {
+        let keys = deserialize_full_vec_zero::<K>(backend)?;
+        let values = deserialize_full_vec_zero::<V>(backend)?;
+        // Keys and values must be in one-to-one correspondence
+        if keys.len() != values.len() {
+            return Err(deser::Error::LengthMismatch {
+                expected: keys.len(),
+                got: values.len(),
+            });
+        }
+        Ok(keys.into_iter().zip(values).collect())
+    }
+
+    type DeserType<'a> = SortedMapView<'a, K, V>;
+
+    fn _deserialize_eps_inner<'a>(
+        backend: &mut SliceWithPos<'a>,
+    ) -> deser::Result<Self::DeserType<'a>> {
+        let keys = deserialize_eps_slice_zero::<K>(backend)?;
+        let values = deserialize_eps_slice_zero::<V>(backend)?;
+        if keys.len() != values.len() {
+            return Err(deser::Error::LengthMismatch {
+                expected: keys.len(),
+                got: values.len(),
+            });
+        }
+        Ok(SortedMapView { keys, values })
+    }
+}
@@ -0,0 +1,72 @@
+/*
+ * SPDX-FileCopyrightText: 2023 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+#![cfg(test)]
+
+use epserde::deser::{read_header, ReaderWithPos};
+use epserde::prelude::*;
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+/// A global allocator counting allocations.
+struct CountingAlloc;
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for CountingAlloc {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAlloc = CountingAlloc;
+
+/// A deep-copy structure whose zero-copy fields all need padding.
+#[derive(Epserde, Debug, PartialEq, Eq, Default, Clone)]
+struct Data {
+    a: u8,
+    b: [u64; 2],
+    c: u8,
+    d: [u32; 3],
+    e: u8,
+    f: [u16; 5],
+    g: u8,
+    h: [u128; 1],
+}
+
+#[test]
+fn test_full_align_no_alloc() {
+    let data = Data {
+        a: 1,
+        b: [2, 3],
+        c: 4,
+        d: [5, 6, 7],
+        e: 8,
+        f: [9, 10, 11, 12, 13],
+        g: 14,
+        h: [15],
+    };
+    let mut buffer = Vec::new();
+    data.serialize(&mut buffer).unwrap();
+
+    let mut bytes = buffer.as_slice();
+    let mut backend = ReaderWithPos::new(&mut bytes);
+    // Reading the header allocates the type name
+    read_header(&mut backend).unwrap();
+
+    let before = ALLOCATIONS.load(Ordering::Relaxed);
+    let full_copy = Data::_deserialize_full_inner(&mut backend).unwrap();
+    let after = ALLOCATIONS.load(Ordering::Relaxed);
+
+    assert_eq!(before, after);
+    assert_eq!(data, full_copy);
+}
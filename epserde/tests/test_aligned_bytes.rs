@@ -0,0 +1,32 @@
+/*
+ * SPDX-FileCopyrightText: 2023 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+#![cfg(test)]
+
+use epserde::prelude::*;
+
+// The embedded file contains a Vec<u64> serialized on a
+// little-endian 64-bit architecture.
+#[cfg(all(target_endian = "little", target_pointer_width = "64"))]
+static DATA: &AlignedBytes<16> = include_aligned!("data/vec_u64.eps");
+
+#[cfg(all(target_endian = "little", target_pointer_width = "64"))]
+#[test]
+fn test_include_aligned() {
+    assert_eq!(DATA.as_ptr() as usize % 16, 0);
+    let v = <Vec<u64>>::deserialize_eps(DATA).unwrap();
+    assert_eq!(v, (0..100).collect::<Vec<u64>>());
+
+    let v = <Vec<u64>>::from_static(DATA.as_bytes()).unwrap();
+    assert_eq!(*v, (0..100).collect::<Vec<u64>>());
+}
+
+#[test]
+fn test_aligned_bytes() {
+    static BYTES: &AlignedBytes<64> = &AlignedBytes::new([1, 2, 3]);
+    assert_eq!(BYTES.as_ptr() as usize % 64, 0);
+    assert_eq!(BYTES.as_bytes(), &[1, 2, 3]);
+}
@@ -0,0 +1,53 @@
+/*
+ * SPDX-FileCopyrightText: 2023 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+#![cfg(test)]
+
+use epserde::prelude::*;
+use maligned::A64;
+
+#[derive(Epserde, Debug, PartialEq, Eq, Default, Clone)]
+struct Data<A> {
+    a: u8,
+    b: A,
+}
+
+#[test]
+fn test_aligned_vec() {
+    let v = <AlignedVec<f32, 32>>::from((0..100).map(|x| x as f32).collect::<Vec<_>>());
+    let mut cursor = <AlignedCursor<A64>>::new();
+    let schema = v.serialize_with_schema(&mut cursor).unwrap();
+    println!("{}", schema.to_csv());
+
+    let full_copy = <AlignedVec<f32, 32>>::deserialize_full(&mut cursor.as_bytes()).unwrap();
+    assert_eq!(v, full_copy);
+
+    let eps_copy = <AlignedVec<f32, 32>>::deserialize_eps(cursor.as_bytes()).unwrap();
+    assert_eq!(eps_copy.as_ptr() as usize % 32, 0);
+    assert_eq!(v.as_slice(), eps_copy);
+
+    // The alignment is part of the type hash
+    assert!(<AlignedVec<f32, 16>>::deserialize_eps(cursor.as_bytes()).is_err());
+    assert!(<Vec<f32>>::deserialize_eps(cursor.as_bytes()).is_err());
+}
+
+#[test]
+fn test_aligned_vec_in_struct() {
+    let d = Data {
+        a: 1,
+        b: vec![1, 2, 3, 4].into(),
+    };
+    let mut cursor = <AlignedCursor<A64>>::new();
+    d.serialize(&mut cursor).unwrap();
+
+    let full_copy = Data::<AlignedVec<u32, 32>>::deserialize_full(&mut cursor.as_bytes()).unwrap();
+    assert_eq!(d, full_copy);
+
+    let eps_copy = Data::<AlignedVec<u32, 32>>::deserialize_eps(cursor.as_bytes()).unwrap();
+    assert_eq!(eps_copy.a, 1);
+    assert_eq!(eps_copy.b.as_ptr() as usize % 32, 0);
+    assert_eq!(eps_copy.b, &[1, 2, 3, 4]);
+}
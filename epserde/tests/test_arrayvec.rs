@@ -0,0 +1,79 @@
+/*
+ * SPDX-FileCopyrightText: 2023 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+#![cfg(test)]
+#![cfg(feature = "arrayvec")]
+
+use arrayvec::ArrayVec;
+use epserde::prelude::*;
+use maligned::A16;
+
+#[test]
+fn test_arrayvec_zero() {
+    for len in [0, 5, 16] {
+        let v = (0..len).collect::<ArrayVec<u32, 16>>();
+        let mut cursor = <AlignedCursor<A16>>::new();
+        v.serialize(&mut cursor).unwrap();
+
+        cursor.set_position(0);
+        let full = <ArrayVec<u32, 16>>::deserialize_full(&mut cursor).unwrap();
+        assert_eq!(full, v);
+        let eps = <ArrayVec<u32, 16>>::deserialize_eps(cursor.as_bytes()).unwrap();
+        assert_eq!(eps, v.as_slice());
+    }
+}
+
+#[test]
+fn test_arrayvec_deep() {
+    let v = (0..3)
+        .map(|i| i.to_string())
+        .collect::<ArrayVec<String, 4>>();
+    let mut cursor = <AlignedCursor<A16>>::new();
+    v.serialize(&mut cursor).unwrap();
+
+    cursor.set_position(0);
+    let full = <ArrayVec<String, 4>>::deserialize_full(&mut cursor).unwrap();
+    assert_eq!(full, v);
+    let eps = <ArrayVec<String, 4>>::deserialize_eps(cursor.as_bytes()).unwrap();
+    assert_eq!(eps, v.as_slice());
+}
+
+#[test]
+fn test_arrayvec_capacity_exceeded() {
+    let v = (0..10).collect::<ArrayVec<u32, 16>>();
+    let mut cursor = <AlignedCursor<A16>>::new();
+    v.serialize(&mut cursor).unwrap();
+
+    cursor.set_position(0);
+    assert!(matches!(
+        <ArrayVec<u32, 8>>::deserialize_full(&mut cursor),
+        Err(deser::Error::CapacityExceeded {
+            len: 10,
+            capacity: 8
+        })
+    ));
+    assert!(matches!(
+        <ArrayVec<u32, 8>>::deserialize_eps(cursor.as_bytes()),
+        Err(deser::Error::CapacityExceeded {
+            len: 10,
+            capacity: 8
+        })
+    ));
+
+    let v = (0..3)
+        .map(|i| i.to_string())
+        .collect::<ArrayVec<String, 4>>();
+    let mut cursor = <AlignedCursor<A16>>::new();
+    v.serialize(&mut cursor).unwrap();
+    cursor.set_position(0);
+    assert!(matches!(
+        <ArrayVec<String, 2>>::deserialize_full(&mut cursor),
+        Err(deser::Error::CapacityExceeded {
+            len: 3,
+            capacity: 2
+        })
+    ));
+}
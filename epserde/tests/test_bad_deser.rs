@@ -6,7 +6,7 @@
 
 #![cfg(test)]
 
-use core::hash::Hasher;
+use core::hash::{Hash, Hasher};
 use epserde::prelude::*;
 use epserde::*;
 use maligned::A16;
@@ -130,3 +130,315 @@ fn test_wrong_endianess() {
         panic!("wrong error type: {:?}", err);
     }
 }
+
+#[test]
+fn test_invalid_utf8() {
+    let data = "Hello, world!".to_string();
+
+    let mut cursor = <AlignedCursor<A16>>::new();
+    data.serialize(&mut cursor).unwrap();
+
+    // The string bytes are at the end of the serialized data
+    let len = cursor.len();
+    cursor.as_bytes_mut()[len - 1] = 0xFF;
+
+    let err = <String>::deserialize_full(&mut std::io::Cursor::new(cursor.as_bytes()));
+    assert!(matches!(err.unwrap_err(), deser::Error::InvalidUtf8(_)));
+
+    let err = <String>::deserialize_eps(cursor.as_bytes());
+    assert!(matches!(err.unwrap_err(), deser::Error::InvalidUtf8(_)));
+
+    let err = deser::deserialize_str_eps(cursor.as_bytes());
+    assert!(matches!(err.unwrap_err(), deser::Error::InvalidUtf8(_)));
+}
+
+/// Overwrite the length of the serialized vector.
+fn set_len(cursor: &mut AlignedCursor<A16>, schema: &ser::Schema, len: usize) {
+    let row = schema.0.iter().find(|row| row.field == "ROOT.len").unwrap();
+    cursor.as_bytes_mut()[row.offset..row.offset + row.size].copy_from_slice(&len.to_ne_bytes());
+}
+
+#[test]
+fn test_length_too_large() {
+    let data = vec![1_u8, 2, 3, 4];
+    let mut cursor = <AlignedCursor<A16>>::new();
+    let schema = data.serialize_with_schema(&mut cursor).unwrap();
+
+    set_len(&mut cursor, &schema, usize::MAX);
+    let err = <Vec<u8>>::deserialize_full(&mut std::io::Cursor::new(cursor.as_bytes()));
+    assert!(matches!(err, Err(deser::Error::LengthTooLarge(usize::MAX))));
+    let err = <Vec<u8>>::deserialize_eps(cursor.as_bytes());
+    assert!(matches!(err, Err(deser::Error::LengthTooLarge(usize::MAX))));
+
+    // A large, allocatable length: full-copy deserialization must
+    // fail when the data ends, without preallocating the whole vector.
+    let len = isize::MAX as usize;
+    set_len(&mut cursor, &schema, len);
+    let err = <Vec<u8>>::deserialize_full(&mut std::io::Cursor::new(cursor.as_bytes()));
+    assert!(matches!(err, Err(deser::Error::ReadError(_))));
+    let err = <Vec<u8>>::deserialize_eps(cursor.as_bytes());
+    assert!(matches!(err, Err(deser::Error::LengthTooLarge(l)) if l == len));
+
+    // Deep-copy vectors
+    let data = vec!["a".to_string(), "b".to_string()];
+    let mut cursor = <AlignedCursor<A16>>::new();
+    let schema = data.serialize_with_schema(&mut cursor).unwrap();
+    set_len(&mut cursor, &schema, usize::MAX);
+    let err = <Vec<String>>::deserialize_full(&mut std::io::Cursor::new(cursor.as_bytes()));
+    assert!(matches!(err, Err(deser::Error::ReadError(_))));
+    let err = <Vec<String>>::deserialize_eps(cursor.as_bytes());
+    assert!(err.is_err());
+}
+
+/// A reader failing after a given number of bytes.
+struct FailingReader<'a> {
+    data: &'a [u8],
+    kind: std::io::ErrorKind,
+}
+
+impl std::io::Read for FailingReader<'_> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        if self.data.is_empty() {
+            return Err(std::io::Error::new(self.kind, "failing reader"));
+        }
+        let len = buf.len().min(self.data.len());
+        buf[..len].copy_from_slice(&self.data[..len]);
+        self.data = &self.data[len..];
+        Ok(len)
+    }
+}
+
+#[test]
+fn test_read_error_source() {
+    use std::error::Error;
+    let mut cursor = <AlignedCursor<A16>>::new();
+    vec![0_u64; 100].serialize(&mut cursor).unwrap();
+    let bytes = cursor.as_bytes();
+
+    let mut reader = FailingReader {
+        data: &bytes[..bytes.len() / 2],
+        kind: std::io::ErrorKind::ConnectionReset,
+    };
+    let err = <Vec<u64>>::deserialize_full(&mut reader).unwrap_err();
+    let source = err
+        .source()
+        .unwrap()
+        .downcast_ref::<std::io::Error>()
+        .unwrap();
+    assert_eq!(source.kind(), std::io::ErrorKind::ConnectionReset);
+
+    // Truncated ε-copy header
+    let err = <Vec<u64>>::deserialize_eps(&bytes[..10]).unwrap_err();
+    match err {
+        deser::Error::ReadError(e) => assert_eq!(e.kind(), std::io::ErrorKind::UnexpectedEof),
+        _ => panic!("Unexpected error {:?}", err),
+    }
+}
+
+#[test]
+fn test_invalid_duration() {
+    let mut cursor = <AlignedCursor<A16>>::new();
+    core::time::Duration::new(u64::MAX, 0)
+        .serialize(&mut cursor)
+        .unwrap();
+    // The nanoseconds are the last bytes
+    let len = cursor.len();
+    cursor.as_bytes_mut()[len - 4..].copy_from_slice(&1_000_000_000_u32.to_ne_bytes());
+    assert!(matches!(
+        core::time::Duration::deserialize_eps(cursor.as_bytes()),
+        Err(deser::Error::InvalidDuration {
+            secs: u64::MAX,
+            nanos: 1_000_000_000
+        })
+    ));
+}
+
+#[test]
+fn test_usize_value_overflow() {
+    assert_eq!(deser::usize_from_u64(42).unwrap(), 42);
+    // A length written by a 64-bit architecture that does not fit in the
+    // usize of a 32-bit architecture
+    let too_large = u32::MAX as u64 + 1;
+
+    #[cfg(target_pointer_width = "64")]
+    assert_eq!(
+        deser::usize_from_u64(too_large).unwrap(),
+        too_large as usize
+    );
+    #[cfg(target_pointer_width = "32")]
+    assert!(matches!(
+        deser::usize_from_u64(too_large),
+        Err(deser::Error::UsizeValueOverflow(value)) if value == too_large
+    ));
+}
+
+/// Deserializing a usize value written as a u64 value checks that it fits.
+#[cfg(all(feature = "portable-usize", target_pointer_width = "32"))]
+#[test]
+fn test_usize_value_overflow_deser() {
+    let mut cursor = <AlignedCursor<A16>>::new();
+    let mut writer_with_pos = WriterWithPos::new(&mut cursor);
+    ser::write_header::<usize>(&mut writer_with_pos).unwrap();
+    (u32::MAX as u64 + 1)
+        .serialize_no_header(&mut writer_with_pos)
+        .unwrap();
+    assert!(matches!(
+        usize::deserialize_eps(cursor.as_bytes()),
+        Err(deser::Error::UsizeValueOverflow(_))
+    ));
+}
+
+#[cfg(feature = "validate")]
+#[test]
+fn test_invalid_bool() {
+    let data = [true, false, true, true];
+
+    let mut cursor = <AlignedCursor<A16>>::new();
+    data.serialize(&mut cursor).unwrap();
+    cursor.set_position(0);
+    assert_eq!(<[bool; 4]>::deserialize_full(&mut cursor).unwrap(), data);
+    // The booleans are the last bytes
+    let len = cursor.len();
+    cursor.as_bytes_mut()[len - 2] = 2;
+    cursor.set_position(0);
+    assert!(matches!(
+        <[bool; 4]>::deserialize_full(&mut cursor),
+        Err(deser::Error::InvalidBool(2))
+    ));
+
+    let mut cursor = <AlignedCursor<A16>>::new();
+    data.to_vec().serialize(&mut cursor).unwrap();
+    cursor.set_position(0);
+    assert_eq!(<Vec<bool>>::deserialize_full(&mut cursor).unwrap(), data);
+    let len = cursor.len();
+    cursor.as_bytes_mut()[len - 1] = 2;
+    cursor.set_position(0);
+    assert!(matches!(
+        <Vec<bool>>::deserialize_full(&mut cursor),
+        Err(deser::Error::InvalidBool(2))
+    ));
+}
+
+#[cfg(feature = "validate")]
+#[test]
+fn test_invalid_nonzero() {
+    use core::num::NonZeroU32;
+    let data = [1, 2, 3, 4].map(|x| NonZeroU32::new(x).unwrap());
+
+    let mut cursor = <AlignedCursor<A16>>::new();
+    data.serialize(&mut cursor).unwrap();
+    cursor.set_position(0);
+    assert_eq!(
+        <[NonZeroU32; 4]>::deserialize_full(&mut cursor).unwrap(),
+        data
+    );
+    // The elements are the last bytes
+    let len = cursor.len();
+    cursor.as_bytes_mut()[len - 8..len - 4].fill(0);
+    cursor.set_position(0);
+    assert!(matches!(
+        <[NonZeroU32; 4]>::deserialize_full(&mut cursor),
+        Err(deser::Error::InvalidNonZero)
+    ));
+
+    let mut cursor = <AlignedCursor<A16>>::new();
+    data.to_vec().serialize(&mut cursor).unwrap();
+    cursor.set_position(0);
+    assert_eq!(
+        <Vec<NonZeroU32>>::deserialize_full(&mut cursor).unwrap(),
+        data
+    );
+    let len = cursor.len();
+    cursor.as_bytes_mut()[len - 4..].fill(0);
+    cursor.set_position(0);
+    assert!(matches!(
+        <Vec<NonZeroU32>>::deserialize_full(&mut cursor),
+        Err(deser::Error::InvalidNonZero)
+    ));
+}
+
+#[test]
+fn test_is_aligned_for() {
+    let v = vec![1_u64, 2, 3];
+    let mut cursor = <AlignedCursor<A16>>::new();
+    v.serialize(&mut cursor).unwrap();
+    let bytes = cursor.as_bytes();
+    assert!(deser::is_aligned_for::<u64>(bytes));
+
+    // Copy the data one byte after an aligned position
+    let mut words = vec![0_u64; bytes.len() / 8 + 2];
+    let buffer =
+        unsafe { core::slice::from_raw_parts_mut(words.as_mut_ptr() as *mut u8, words.len() * 8) };
+    buffer[1..=bytes.len()].copy_from_slice(bytes);
+    let offset = &buffer[1..=bytes.len()];
+    assert!(!deser::is_aligned_for::<u64>(offset));
+    assert!(deser::is_aligned_for::<u8>(offset));
+    assert!(matches!(
+        <Vec<u64>>::deserialize_eps(offset),
+        Err(deser::Error::AlignmentError)
+    ));
+}
+
+#[test]
+fn test_u128_repr_hash() {
+    // The representation hash of a Vec<u128> contains the alignment of
+    // u128, the padding before the element (zero), and its size
+    let repr_hash = |align: usize| {
+        let mut repr_hasher = Xxh3::new();
+        align.hash(&mut repr_hasher);
+        0_usize.hash(&mut repr_hasher);
+        16_usize.hash(&mut repr_hasher);
+        repr_hasher.finish()
+    };
+    let align = core::mem::align_of::<u128>();
+    let (_, vec_repr_hash) = deser::type_and_repr_hash::<Vec<u128>>();
+    assert_eq!(vec_repr_hash, repr_hash(align));
+
+    // Data written on an architecture on which u128 has a different
+    // alignment is rejected
+    let v = vec![1_u128, 2, 3];
+    let mut cursor = <AlignedCursor<A16>>::new();
+    v.serialize(&mut cursor).unwrap();
+    let other_repr_hash = repr_hash(if align == 16 { 8 } else { 16 });
+    let bytes = cursor.as_bytes_mut();
+    let pos = bytes
+        .windows(8)
+        .position(|w| w == vec_repr_hash.to_ne_bytes())
+        .unwrap();
+    bytes[pos..pos + 8].copy_from_slice(&other_repr_hash.to_ne_bytes());
+    assert!(matches!(
+        <Vec<u128>>::deserialize_eps(cursor.as_bytes()),
+        Err(deser::Error::WrongTypeReprHash { .. })
+    ));
+}
+
+#[test]
+fn test_short_input() {
+    for len in [0, 3] {
+        let bytes = vec![0_u8; len];
+        assert!(matches!(
+            <Vec<u64>>::deserialize_eps(&bytes),
+            Err(deser::Error::ReadError(_))
+        ));
+        assert!(matches!(
+            <Vec<u64>>::deserialize_full(&mut std::io::Cursor::new(&bytes)),
+            Err(deser::Error::ReadError(_))
+        ));
+    }
+}
+
+#[test]
+fn test_length_exceeds_data() {
+    let data = vec![1_u32, 2, 3, 4];
+    let mut cursor = <AlignedCursor<A16>>::new();
+    let schema = data.serialize_with_schema(&mut cursor).unwrap();
+
+    // The serialized data is padded, so we need a length exceeding
+    // the length of the whole buffer
+    let len = cursor.as_bytes().len() / core::mem::size_of::<u32>() + 1;
+    set_len(&mut cursor, &schema, len);
+    let err = <Vec<u32>>::deserialize_full(&mut std::io::Cursor::new(cursor.as_bytes()));
+    assert!(matches!(err, Err(deser::Error::ReadError(_))));
+    let err = <Vec<u32>>::deserialize_eps(cursor.as_bytes());
+    assert!(matches!(err, Err(deser::Error::LengthTooLarge(l)) if l == len));
+}
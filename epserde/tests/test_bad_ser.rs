@@ -6,6 +6,12 @@
 
 #![cfg(test)]
 
+use core::hash::Hash;
+use epserde::deser::{ReadWithPos, SliceWithPos};
+use epserde::prelude::*;
+use epserde::ser::WriteWithNames;
+use maligned::A16;
+
 /*
 
 // This test should not compile, as the field of a zero-copy structure is not zero-copy.
@@ -43,3 +49,74 @@ fn test_fake_zero() {
     assert!(result.is_err());
 }
 */
+
+/// A zero-copy type whose implementation of MaxSizeOf is wrong.
+#[derive(Debug, Clone, Copy)]
+#[repr(C)]
+struct BadAlign {
+    x: u64,
+}
+
+impl CopyType for BadAlign {
+    type Copy = Zero;
+}
+
+impl TypeHash for BadAlign {
+    fn type_hash(hasher: &mut impl core::hash::Hasher) {
+        "BadAlign".hash(hasher);
+    }
+}
+
+impl ReprHash for BadAlign {
+    fn repr_hash(hasher: &mut impl core::hash::Hasher, offset_of: &mut usize) {
+        u64::repr_hash(hasher, offset_of);
+    }
+}
+
+impl MaxSizeOf for BadAlign {
+    fn max_size_of() -> usize {
+        // Should be 8
+        1
+    }
+}
+
+impl SerializeInner for BadAlign {
+    const IS_ZERO_COPY: bool = true;
+    const ZERO_COPY_MISMATCH: bool = false;
+
+    fn _serialize_inner(&self, backend: &mut impl WriteWithNames) -> ser::Result<()> {
+        ser::helpers::serialize_zero(backend, self)
+    }
+}
+
+impl DeserializeInner for BadAlign {
+    fn _deserialize_full_inner(backend: &mut impl ReadWithPos) -> deser::Result<Self> {
+        deser::helpers::deserialize_full_zero(backend)
+    }
+
+    type DeserType<'a> = &'a BadAlign;
+
+    fn _deserialize_eps_inner<'a>(
+        backend: &mut SliceWithPos<'a>,
+    ) -> deser::Result<Self::DeserType<'a>> {
+        deser::helpers::deserialize_eps_zero(backend)
+    }
+}
+
+#[derive(Epserde, Debug, Clone)]
+struct Misaligned {
+    a: u8,
+    b: BadAlign,
+}
+
+#[cfg(debug_assertions)]
+#[test]
+#[should_panic(expected = "MaxSizeOf")]
+fn test_wrong_max_size_of() {
+    let data = Misaligned {
+        a: 1,
+        b: BadAlign { x: 2 },
+    };
+    let mut cursor = <AlignedCursor<A16>>::new();
+    let _ = data.serialize(&mut cursor);
+}
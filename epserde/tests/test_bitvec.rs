@@ -0,0 +1,78 @@
+/*
+ * SPDX-FileCopyrightText: 2023 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+#![cfg(test)]
+#![cfg(feature = "bitvec")]
+
+use bitvec::prelude::*;
+use epserde::prelude::*;
+use maligned::A16;
+
+#[test]
+fn test_bitvec() {
+    for len in [0, 1, 63, 64, 65, 1000] {
+        let bits = (0..len).map(|i| i % 3 == 0).collect::<BitVec<u64, Lsb0>>();
+        let mut cursor = <AlignedCursor<A16>>::new();
+        bits.serialize(&mut cursor).unwrap();
+
+        cursor.set_position(0);
+        let full = <BitVec<u64, Lsb0>>::deserialize_full(&mut cursor).unwrap();
+        assert_eq!(full.len(), len);
+        assert_eq!(full, bits);
+
+        let eps = <BitVec<u64, Lsb0>>::deserialize_eps(cursor.as_bytes()).unwrap();
+        assert_eq!(eps.len(), len);
+        assert_eq!(eps, bits.as_bitslice());
+    }
+
+    // Storage type and order are part of the type hash
+    let bits = bitvec![u8, Msb0; 1, 0, 1, 1, 0];
+    let mut cursor = <AlignedCursor<A16>>::new();
+    bits.serialize(&mut cursor).unwrap();
+    let eps = <BitVec<u8, Msb0>>::deserialize_eps(cursor.as_bytes()).unwrap();
+    assert_eq!(eps, bits.as_bitslice());
+    assert!(<BitVec<u8, Lsb0>>::deserialize_eps(cursor.as_bytes()).is_err());
+    assert!(<BitVec<u16, Msb0>>::deserialize_eps(cursor.as_bytes()).is_err());
+
+    // Bits not starting at the beginning of the first word
+    let all = bitvec![usize, Lsb0; 1, 1, 0, 1, 0, 0, 1];
+    let bits = BitVec::from_bitslice(&all[3..]);
+    assert_ne!(bits.as_bitptr().bit().into_inner(), 0);
+    let mut cursor = <AlignedCursor<A16>>::new();
+    bits.serialize(&mut cursor).unwrap();
+    cursor.set_position(0);
+    let full = <BitVec<usize, Lsb0>>::deserialize_full(&mut cursor).unwrap();
+    assert_eq!(full, bits);
+    assert_eq!(full.len(), 4);
+}
+
+#[test]
+fn test_bitvec_bad_len() {
+    // Write by hand a bit vector with too few storage words
+    let mut cursor = <AlignedCursor<A16>>::new();
+    let mut writer_with_pos = WriterWithPos::new(&mut cursor);
+    ser::write_header::<BitVec<u64, Lsb0>>(&mut writer_with_pos).unwrap();
+    200_usize.serialize_no_header(&mut writer_with_pos).unwrap();
+    vec![0_u64; 3]
+        .serialize_no_header(&mut writer_with_pos)
+        .unwrap();
+
+    assert!(matches!(
+        <BitVec<u64, Lsb0>>::deserialize_eps(cursor.as_bytes()),
+        Err(deser::Error::InvalidBitLength {
+            bit_len: 200,
+            num_words: 3
+        })
+    ));
+    cursor.set_position(0);
+    assert!(matches!(
+        <BitVec<u64, Lsb0>>::deserialize_full(&mut cursor),
+        Err(deser::Error::InvalidBitLength {
+            bit_len: 200,
+            num_words: 3
+        })
+    ));
+}
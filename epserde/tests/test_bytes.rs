@@ -0,0 +1,51 @@
+/*
+ * SPDX-FileCopyrightText: 2023 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+#![cfg(test)]
+#![cfg(feature = "bytes")]
+
+use bytes::Bytes;
+use epserde::prelude::*;
+use maligned::A16;
+
+/// Serialize a value into a `'static`, 16-byte aligned [`Bytes`] buffer.
+fn to_bytes<T: Serialize>(value: &T) -> Bytes {
+    let mut cursor = <AlignedCursor<A16>>::new();
+    value.serialize(&mut cursor).unwrap();
+    let len = cursor.len();
+    let (vec, _) = cursor.into_parts();
+    let words: &'static [A16] = vec.leak();
+    let bytes = unsafe {
+        core::slice::from_raw_parts(words.as_ptr() as *const u8, core::mem::size_of_val(words))
+    };
+    Bytes::from_static(&bytes[..len])
+}
+
+#[test]
+fn test_deserialize_eps_bytes() {
+    let v = (0..100_u64).collect::<Vec<_>>();
+    let bytes = to_bytes(&v);
+    let s = <Vec<u64>>::deserialize_eps_bytes(&bytes).unwrap();
+    assert_eq!(s, v.as_slice());
+}
+
+#[test]
+fn test_from_bytes() {
+    let v = (0..100_u64).collect::<Vec<_>>();
+    let bytes = to_bytes(&v);
+    let mem_case = <Vec<u64>>::from_bytes(bytes.clone()).unwrap();
+    // The MemCase keeps its own reference to the buffer
+    drop(bytes);
+    assert_eq!(*mem_case, v.as_slice());
+    let backend = mem_case.backend_bytes().unwrap();
+    assert!(backend
+        .as_ptr_range()
+        .contains(&(mem_case.as_ptr() as *const u8)));
+
+    // Misaligned buffers are rejected
+    let bytes = to_bytes(&v);
+    assert!(<Vec<u64>>::from_bytes(bytes.slice(1..)).is_err());
+}
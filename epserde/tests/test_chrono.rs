@@ -0,0 +1,62 @@
+/*
+ * SPDX-FileCopyrightText: 2023 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+#![cfg(test)]
+#![cfg(feature = "chrono")]
+
+use chrono::{DateTime, NaiveDate, NaiveDateTime};
+use epserde::prelude::*;
+use maligned::A16;
+
+#[test]
+fn test_naive_date_time() {
+    let v: Vec<NaiveDateTime> = vec![
+        NaiveDate::from_ymd_opt(2023, 10, 1)
+            .unwrap()
+            .and_hms_nano_opt(12, 34, 56, 789_000_000)
+            .unwrap(),
+        DateTime::UNIX_EPOCH.naive_utc(),
+        NaiveDate::from_ymd_opt(1900, 1, 1)
+            .unwrap()
+            .and_hms_opt(0, 0, 0)
+            .unwrap(),
+        NaiveDateTime::MAX,
+        NaiveDateTime::MIN,
+    ];
+    let mut cursor = <AlignedCursor<A16>>::new();
+    v.serialize(&mut cursor).unwrap();
+
+    cursor.set_position(0);
+    let full = <Vec<NaiveDateTime>>::deserialize_full(&mut cursor).unwrap();
+    assert_eq!(full, v);
+    let eps = <Vec<NaiveDateTime>>::deserialize_eps(cursor.as_bytes()).unwrap();
+    assert_eq!(eps, v);
+}
+
+#[test]
+fn test_naive_date_time_out_of_range() {
+    let mut cursor = <AlignedCursor<A16>>::new();
+    let schema = NaiveDateTime::MAX
+        .serialize_with_schema(&mut cursor)
+        .unwrap();
+    let row = schema
+        .0
+        .iter()
+        .find(|row| row.field == "ROOT.secs")
+        .unwrap();
+    cursor.as_bytes_mut()[row.offset..row.offset + row.size]
+        .copy_from_slice(&i64::MAX.to_ne_bytes());
+
+    cursor.set_position(0);
+    assert!(matches!(
+        NaiveDateTime::deserialize_full(&mut cursor),
+        Err(deser::Error::InvalidTimestamp { secs: i64::MAX, .. })
+    ));
+    assert!(matches!(
+        NaiveDateTime::deserialize_eps(cursor.as_bytes()),
+        Err(deser::Error::InvalidTimestamp { secs: i64::MAX, .. })
+    ));
+}
@@ -0,0 +1,69 @@
+/*
+ * SPDX-FileCopyrightText: 2023 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+#![cfg(test)]
+
+use epserde::prelude::*;
+use epserde::ser::{CountingWriter, TeeWriter};
+use maligned::A16;
+
+/// Serialize a vector of given length, returning the number of
+/// write calls and the number of bytes written.
+fn count(len: u64) -> (usize, usize) {
+    let data = (0..len).collect::<Vec<u64>>();
+    let mut writer = CountingWriter::new(Vec::new());
+    let bytes = data.serialize(&mut writer).unwrap();
+    assert_eq!(bytes, writer.bytes_written());
+    let calls = writer.write_calls();
+    assert_eq!(bytes, writer.into_inner().len());
+    (calls, bytes)
+}
+
+#[test]
+fn test_counting_writer() {
+    let (calls_small, bytes_small) = count(10);
+    let (calls_large, bytes_large) = count(10_000);
+    // The content of the vector is written with a single call
+    assert_eq!(calls_small, calls_large);
+    assert_eq!(bytes_large - bytes_small, (10_000 - 10) * 8);
+}
+
+#[test]
+fn test_serialize_with_progress() {
+    let data: Vec<String> = (0..200_000).map(|i| i.to_string()).collect();
+    let mut cursor = <AlignedCursor<A16>>::new();
+    let mut reports = vec![];
+    let bytes_written = data
+        .serialize_with_progress(&mut cursor, |bytes| reports.push(bytes))
+        .unwrap();
+
+    assert!(reports.len() > 1);
+    assert!(reports.windows(2).all(|w| w[0] < w[1]));
+    assert_eq!(*reports.last().unwrap(), bytes_written);
+    assert_eq!(bytes_written, cursor.len());
+
+    cursor.set_position(0);
+    assert_eq!(<Vec<String>>::deserialize_full(&mut cursor).unwrap(), data);
+}
+
+#[test]
+fn test_tee_writer() {
+    use core::hash::Hasher;
+    use xxhash_rust::xxh3::Xxh3;
+
+    let data: Vec<String> = (0..1000).map(|i| i.to_string()).collect();
+    let path = std::env::temp_dir().join("test_tee_writer.bin");
+    let file = std::io::BufWriter::new(std::fs::File::create(&path).unwrap());
+    let mut writer = TeeWriter::new(file, Xxh3::new());
+    data.serialize(&mut writer).unwrap();
+    let hash = writer.finish();
+    drop(writer);
+
+    let mut hasher = Xxh3::new();
+    hasher.write(&std::fs::read(&path).unwrap());
+    assert_eq!(hash, hasher.finish());
+    std::fs::remove_file(&path).unwrap();
+}
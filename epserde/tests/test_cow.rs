@@ -0,0 +1,61 @@
+/*
+ * SPDX-FileCopyrightText: 2023 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+#![cfg(test)]
+
+use epserde::prelude::*;
+use maligned::A16;
+use std::borrow::Cow;
+
+#[derive(Epserde, Debug, PartialEq, Eq, Clone, Copy)]
+#[repr(C)]
+#[zero_copy]
+struct Point {
+    x: u32,
+    y: u32,
+}
+
+#[derive(Epserde, Debug, PartialEq, Eq, Clone)]
+struct Data {
+    name: String,
+    values: Vec<u64>,
+}
+
+#[test]
+fn test_cow_zero() {
+    let point = Point { x: 1, y: 2 };
+    let cow: Cow<Point> = Cow::Borrowed(&point);
+    let mut cursor = <AlignedCursor<A16>>::new();
+    cow.serialize(&mut cursor).unwrap();
+
+    cursor.set_position(0);
+    let full = <Cow<Point>>::deserialize_full(&mut cursor).unwrap();
+    assert!(matches!(full, Cow::Owned(_)));
+    assert_eq!(*full, point);
+
+    let eps = <Cow<Point>>::deserialize_eps(cursor.as_bytes()).unwrap();
+    assert!(matches!(eps, Cow::Borrowed(_)));
+    assert_eq!(*eps, point);
+}
+
+#[test]
+fn test_cow_deep() {
+    let data = Data {
+        name: "data".to_string(),
+        values: vec![1, 2, 3],
+    };
+    let cow: Cow<Data> = Cow::Borrowed(&data);
+    let mut cursor = <AlignedCursor<A16>>::new();
+    cow.serialize(&mut cursor).unwrap();
+
+    cursor.set_position(0);
+    let full = <Cow<Data>>::deserialize_full(&mut cursor).unwrap();
+    assert!(matches!(full, Cow::Owned(_)));
+    assert_eq!(*full, data);
+
+    let eps = <Cow<Data>>::deserialize_eps(cursor.as_bytes()).unwrap();
+    assert_eq!(eps, data);
+}
@@ -0,0 +1,83 @@
+/*
+ * SPDX-FileCopyrightText: 2023 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+#![cfg(test)]
+
+use epserde::deser::DeserConfig;
+use epserde::prelude::*;
+use maligned::A16;
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+/// An allocator recording the largest allocation.
+struct MaxAlloc;
+
+static MAX_ALLOC: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for MaxAlloc {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        MAX_ALLOC.fetch_max(layout.size(), Ordering::SeqCst);
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        MAX_ALLOC.fetch_max(new_size, Ordering::SeqCst);
+        System.realloc(ptr, layout, new_size)
+    }
+}
+
+#[global_allocator]
+static GLOBAL: MaxAlloc = MaxAlloc;
+
+#[test]
+fn test_max_preallocate() {
+    let v = vec!["a".to_string(), "b".to_string()];
+    let mut cursor = <AlignedCursor<A16>>::new();
+    let schema = v.serialize_with_schema(&mut cursor).unwrap();
+
+    let config = DeserConfig {
+        max_preallocate: 1024,
+    };
+    cursor.set_position(0);
+    assert_eq!(
+        <Vec<String>>::deserialize_full_with_config(&mut cursor, config).unwrap(),
+        v
+    );
+
+    // Declare a huge length
+    let row = schema.0.iter().find(|row| row.field == "ROOT.len").unwrap();
+    let len = 1_usize << 40;
+    cursor.as_bytes_mut()[row.offset..row.offset + row.size].copy_from_slice(&len.to_ne_bytes());
+    let bytes = cursor.as_bytes().to_vec();
+
+    MAX_ALLOC.store(0, Ordering::SeqCst);
+    assert!(matches!(
+        <Vec<String>>::deserialize_full_with_config(&mut std::io::Cursor::new(&bytes), config),
+        Err(deser::Error::ReadError(_))
+    ));
+    // The vector preallocates at most max_preallocate bytes
+    assert!(MAX_ALLOC.load(Ordering::SeqCst) <= 1024);
+
+    // The same holds for vectors of zero-copy elements
+    let mut cursor = <AlignedCursor<A16>>::new();
+    let schema = vec![1_u64, 2, 3]
+        .serialize_with_schema(&mut cursor)
+        .unwrap();
+    let row = schema.0.iter().find(|row| row.field == "ROOT.len").unwrap();
+    cursor.as_bytes_mut()[row.offset..row.offset + row.size].copy_from_slice(&len.to_ne_bytes());
+    let bytes = cursor.as_bytes().to_vec();
+
+    MAX_ALLOC.store(0, Ordering::SeqCst);
+    assert!(matches!(
+        <Vec<u64>>::deserialize_full_with_config(&mut std::io::Cursor::new(&bytes), config),
+        Err(deser::Error::ReadError(_))
+    ));
+    assert!(MAX_ALLOC.load(Ordering::SeqCst) <= 2048);
+}
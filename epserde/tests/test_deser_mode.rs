@@ -0,0 +1,51 @@
+/*
+ * SPDX-FileCopyrightText: 2023 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+#![cfg(test)]
+
+use epserde::prelude::*;
+use maligned::A16;
+
+#[derive(Epserde, Debug, PartialEq, Eq, Clone)]
+struct Data {
+    a: Vec<u64>,
+    b: String,
+}
+
+/// Return the sum of the fields of a deserialized [`Data`], whatever the
+/// deserialization mode.
+fn sum<M: deser::DeserMode>(bytes: &[u8]) -> u64
+where
+    for<'a> M::Output<'a, Data>: AsRef<Data>,
+{
+    let data = Data::deserialize_with_mode::<M>(bytes).unwrap();
+    let data = data.as_ref();
+    data.a.iter().sum::<u64>() + data.b.len() as u64
+}
+
+impl AsRef<Data> for Data {
+    fn as_ref(&self) -> &Data {
+        self
+    }
+}
+
+#[test]
+fn test_deserialize_with_mode() {
+    let data = Data {
+        a: vec![1, 2, 3],
+        b: "four".to_string(),
+    };
+    let mut cursor = <AlignedCursor<A16>>::new();
+    data.serialize(&mut cursor).unwrap();
+    assert_eq!(sum::<deser::Full>(cursor.as_bytes()), 10);
+    assert_eq!(sum::<deser::Eps>(cursor.as_bytes()), 10);
+
+    let full: Data = Data::deserialize_with_mode::<deser::Full>(cursor.as_bytes()).unwrap();
+    assert_eq!(full, data);
+    let eps = Data::deserialize_with_mode::<deser::Eps>(cursor.as_bytes()).unwrap();
+    assert_eq!(eps.a, data.a);
+    assert_eq!(eps.b, data.b);
+}
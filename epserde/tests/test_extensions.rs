@@ -0,0 +1,94 @@
+/*
+ * SPDX-FileCopyrightText: 2023 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+#![cfg(test)]
+
+use epserde::deser::{check_header, ReaderWithPos};
+use epserde::prelude::*;
+use maligned::A16;
+
+#[derive(Epserde, Debug, PartialEq, Eq, Clone)]
+struct Data {
+    a: Vec<u32>,
+    b: String,
+}
+
+#[test]
+fn test_extensions() {
+    let data = Data {
+        a: vec![1, 2, 3],
+        b: "hello".to_string(),
+    };
+
+    // A newer writer appends some data an older reader does not know about
+    let mut cursor = <AlignedCursor<A16>>::new();
+    let bytes_written = data
+        .serialize_with_extensions(&mut cursor, b"future data")
+        .unwrap();
+    // A second structure follows in the same stream
+    let bytes_written_2 = 42_u64.serialize(&mut cursor).unwrap();
+    assert_eq!(cursor.len(), bytes_written + bytes_written_2);
+
+    cursor.set_position(0);
+    let full = Data::deserialize_full(&mut cursor).unwrap();
+    assert_eq!(full, data);
+    assert_eq!(u64::deserialize_full(&mut cursor).unwrap(), 42);
+
+    let (eps, pos) = Data::deserialize_eps_with_pos(cursor.as_bytes()).unwrap();
+    assert_eq!(eps.a, data.a);
+    assert_eq!(eps.b, data.b);
+    assert_eq!(pos, bytes_written);
+
+    // Extensions do not change the serialization of the payload
+    let mut plain = <AlignedCursor<A16>>::new();
+    let plain_bytes_written = data.serialize(&mut plain).unwrap();
+    assert_eq!(
+        plain_bytes_written + core::mem::size_of::<usize>() + b"future data".len(),
+        bytes_written
+    );
+    // Only the minor version in the header differs
+    assert_eq!(&plain.as_bytes()[..10], &cursor.as_bytes()[..10]);
+    assert_eq!(
+        &plain.as_bytes()[12..plain_bytes_written],
+        &cursor.as_bytes()[12..plain_bytes_written]
+    );
+}
+
+#[test]
+fn test_extensions_check_header() {
+    let data = Data {
+        a: vec![1, 2, 3],
+        b: "hello".to_string(),
+    };
+    let mut cursor = <AlignedCursor<A16>>::new();
+    data.serialize_with_extensions(&mut cursor, b"future data")
+        .unwrap();
+
+    // A reader unaware of extension blocks checks the header and
+    // deserializes the payload, ignoring the following bytes
+    let mut backend = SliceWithPos::new(cursor.as_bytes());
+    check_header::<Data>(&mut backend).unwrap();
+    let eps = Data::deserialize_eps_no_header(&mut backend).unwrap();
+    assert_eq!(eps.a, data.a);
+    assert_eq!(eps.b, data.b);
+
+    cursor.set_position(0);
+    let mut backend = ReaderWithPos::new(&mut cursor);
+    check_header::<Data>(&mut backend).unwrap();
+    let full = Data::deserialize_full_no_header(&mut backend).unwrap();
+    assert_eq!(full, data);
+}
+
+#[test]
+fn test_truncated_extensions() {
+    let mut cursor = <AlignedCursor<A16>>::new();
+    let bytes_written = vec![1_u8, 2, 3]
+        .serialize_with_extensions(&mut cursor, &[0; 100])
+        .unwrap();
+    let bytes = &cursor.as_bytes()[..bytes_written - 1];
+    assert!(<Vec<u8>>::deserialize_eps(bytes).is_err());
+    assert!(<Vec<u8>>::deserialize_full(&mut std::io::Cursor::new(bytes)).is_err());
+}
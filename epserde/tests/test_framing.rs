@@ -0,0 +1,90 @@
+/*
+ * SPDX-FileCopyrightText: 2023 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+#![cfg(test)]
+
+use epserde::deser::{check_header, ReaderWithPos};
+use epserde::prelude::*;
+use epserde::ser::write_header;
+use maligned::A16;
+
+#[derive(Epserde, Debug, PartialEq, Eq, Clone)]
+struct Data {
+    a: Vec<u64>,
+    b: String,
+}
+
+#[test]
+fn test_framing() {
+    let first = Data {
+        a: vec![1, 2, 3],
+        b: "first".to_string(),
+    };
+    let second = Data {
+        a: vec![4],
+        b: "second".to_string(),
+    };
+
+    let mut cursor = <AlignedCursor<A16>>::new();
+    let mut writer_with_pos = WriterWithPos::new(&mut cursor);
+    write_header::<Data>(&mut writer_with_pos).unwrap();
+    first.serialize_no_header(&mut writer_with_pos).unwrap();
+    second.serialize_no_header(&mut writer_with_pos).unwrap();
+
+    // Full-copy
+    cursor.set_position(0);
+    let mut reader = ReaderWithPos::new(&mut cursor);
+    check_header::<Data>(&mut reader).unwrap();
+    assert_eq!(
+        Data::deserialize_full_no_header(&mut reader).unwrap(),
+        first
+    );
+    assert_eq!(
+        Data::deserialize_full_no_header(&mut reader).unwrap(),
+        second
+    );
+
+    // ε-copy
+    let mut backend = SliceWithPos::new(cursor.as_bytes());
+    check_header::<Data>(&mut backend).unwrap();
+    let eps = Data::deserialize_eps_no_header(&mut backend).unwrap();
+    assert_eq!(eps.a, first.a);
+    assert_eq!(eps.b, first.b);
+    let eps = Data::deserialize_eps_no_header(&mut backend).unwrap();
+    assert_eq!(eps.a, second.a);
+    assert_eq!(eps.b, second.b);
+    assert!(backend.data.is_empty());
+
+    // The header is checked
+    let mut backend = SliceWithPos::new(cursor.as_bytes());
+    assert!(check_header::<Vec<u64>>(&mut backend).is_err());
+}
+
+#[test]
+fn test_trailer() {
+    let data = Data {
+        a: vec![1, 2, 3],
+        b: "data".to_string(),
+    };
+    let mut cursor = <AlignedCursor<A16>>::new();
+    data.serialize(&mut cursor).unwrap();
+    // Append a custom footer
+    let footer = b"footer\x00\x01";
+    std::io::Write::write_all(&mut cursor, footer).unwrap();
+
+    cursor.set_position(0);
+    let (full, trailer) = Data::deserialize_full_with_trailer(&mut cursor).unwrap();
+    assert_eq!(full, data);
+    assert_eq!(trailer, footer);
+
+    // No trailer
+    let mut cursor = <AlignedCursor<A16>>::new();
+    data.serialize(&mut cursor).unwrap();
+    cursor.set_position(0);
+    let (full, trailer) = Data::deserialize_full_with_trailer(&mut cursor).unwrap();
+    assert_eq!(full, data);
+    assert!(trailer.is_empty());
+}
@@ -0,0 +1,96 @@
+/*
+ * SPDX-FileCopyrightText: 2023 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+#![cfg(test)]
+
+//! Feeds corrupted serialized data to deserialization, checking that
+//! errors are reported and that there are no panics. The fuzz targets in
+//! the `fuzz` directory do the same using coverage-guided fuzzing.
+
+use epserde::prelude::*;
+use maligned::A64;
+use std::collections::HashMap;
+
+#[derive(Epserde, Debug, Clone, Copy, PartialEq)]
+#[repr(C)]
+#[zero_copy]
+struct Point {
+    x: u32,
+    y: f64,
+}
+
+#[derive(Epserde, Debug, Clone, PartialEq)]
+enum Shape {
+    Empty,
+    Polygon(Vec<Point>),
+    Named { name: String, center: Point },
+}
+
+/// A xorshift64* pseudorandom number generator.
+struct Rng(u64);
+
+impl Rng {
+    fn next(&mut self) -> u64 {
+        self.0 ^= self.0 >> 12;
+        self.0 ^= self.0 << 25;
+        self.0 ^= self.0 >> 27;
+        self.0.wrapping_mul(0x2545F4914F6CDD1D)
+    }
+
+    fn below(&mut self, n: usize) -> usize {
+        (self.next() % n as u64) as usize
+    }
+}
+
+/// Deserialize mutations of the serialization of `value`, checking that
+/// there are no panics.
+fn fuzz<T: Serialize + Deserialize>(value: &T) {
+    let mut cursor = <AlignedCursor<A64>>::new();
+    value.serialize(&mut cursor).unwrap();
+    let original = cursor.as_bytes().to_vec();
+    let mut rng = Rng(0x9E3779B97F4A7C15);
+
+    for _ in 0..2000 {
+        let mut data = original.clone();
+        for _ in 0..1 + rng.below(4) {
+            let pos = rng.below(data.len());
+            data[pos] = rng.next() as u8;
+        }
+        if rng.below(4) == 0 {
+            data.truncate(rng.below(data.len()));
+        }
+
+        // Copy the data in an aligned buffer
+        let mut aligned = <AlignedCursor<A64>>::new();
+        std::io::Write::write_all(&mut aligned, &data).unwrap();
+        let _ = T::deserialize_eps(aligned.as_bytes());
+        let _ = T::deserialize_full(&mut std::io::Cursor::new(&data));
+    }
+}
+
+#[test]
+fn test_fuzz() {
+    fuzz(&(0..100).collect::<Vec<u32>>());
+    fuzz(&"ε-serde".to_string());
+    fuzz(&(0..20).map(|i| i.to_string()).collect::<Vec<_>>());
+    fuzz(&(0..10).map(|i| vec![i as u8; i]).collect::<Vec<_>>());
+    fuzz(&Some((0..10).collect::<Vec<u64>>()));
+    fuzz(&Point { x: 1, y: 2.0 });
+    fuzz(&vec![Point { x: 1, y: 2.0 }; 10]);
+    fuzz(
+        &(0..10_u32)
+            .map(|i| (i, i.to_string()))
+            .collect::<HashMap<_, _>>(),
+    );
+    fuzz(&vec![
+        Shape::Empty,
+        Shape::Polygon(vec![Point { x: 1, y: 2.0 }; 3]),
+        Shape::Named {
+            name: "center".to_string(),
+            center: Point { x: 3, y: 4.0 },
+        },
+    ]);
+}
@@ -99,3 +99,84 @@ fn test_consts() {
     let eps = <Data3<12>>::deserialize_eps(cursor.as_bytes());
     assert!(eps.is_err());
 }
+
+mod old {
+    use epserde::prelude::*;
+
+    #[derive(Epserde, Debug, PartialEq, Eq, Clone)]
+    #[epserde(stable_names)]
+    pub struct Data<const N: usize> {
+        pub a: [u32; N],
+    }
+
+    #[derive(Epserde, Debug, PartialEq, Eq, Clone)]
+    pub struct Names<const N: usize> {
+        pub a: [u32; N],
+    }
+}
+
+mod new {
+    use epserde::prelude::*;
+
+    #[derive(Epserde, Debug, PartialEq, Eq, Clone)]
+    #[epserde(stable_names)]
+    pub struct Data<const LEN: usize> {
+        pub a: [u32; LEN],
+    }
+
+    #[derive(Epserde, Debug, PartialEq, Eq, Clone)]
+    pub struct Names<const LEN: usize> {
+        pub a: [u32; LEN],
+    }
+}
+
+#[test]
+fn test_stable_names() {
+    let data = old::Data::<3> { a: [1, 2, 3] };
+    let mut cursor = <AlignedCursor<A16>>::new();
+    data.serialize(&mut cursor).unwrap();
+
+    // Renaming a const parameter does not change the type hash
+    let eps = <new::Data<3>>::deserialize_eps(cursor.as_bytes()).unwrap();
+    assert_eq!(data.a, eps.a);
+    cursor.set_position(0);
+    let full = <new::Data<3>>::deserialize_full(&mut cursor).unwrap();
+    assert_eq!(data.a, full.a);
+
+    // The value of const parameters is still part of the type hash
+    assert!(<new::Data<4>>::deserialize_eps(cursor.as_bytes()).is_err());
+
+    // Without the attribute, the type hash depends on the parameter names
+    let data = old::Names::<3> { a: [1, 2, 3] };
+    let mut cursor = <AlignedCursor<A16>>::new();
+    data.serialize(&mut cursor).unwrap();
+    assert!(<old::Names<3>>::deserialize_eps(cursor.as_bytes()).is_ok());
+    assert!(matches!(
+        <new::Names<3>>::deserialize_eps(cursor.as_bytes()),
+        Err(deser::Error::WrongTypeHash { .. })
+    ));
+}
+
+#[derive(Epserde, Debug, PartialEq, Eq, Clone, Copy)]
+#[repr(C)]
+#[zero_copy]
+struct Buf<const N: usize> {
+    data: [u8; N],
+}
+
+#[test]
+fn test_const_size_repr_hash() {
+    let (type_hash_16, repr_hash_16) = deser::type_and_repr_hash::<Buf<16>>();
+    let (type_hash_32, repr_hash_32) = deser::type_and_repr_hash::<Buf<32>>();
+    // Both the value of the parameter and the size of the type are hashed
+    assert_ne!(type_hash_16, type_hash_32);
+    assert_ne!(repr_hash_16, repr_hash_32);
+
+    let buf = Buf::<16> { data: [42; 16] };
+    let mut cursor = <AlignedCursor<A16>>::new();
+    buf.serialize(&mut cursor).unwrap();
+    assert_eq!(*<Buf<16>>::deserialize_eps(cursor.as_bytes()).unwrap(), buf);
+    assert!(<Buf<32>>::deserialize_eps(cursor.as_bytes()).is_err());
+    cursor.set_position(0);
+    assert!(<Buf<32>>::deserialize_full(&mut cursor).is_err());
+}
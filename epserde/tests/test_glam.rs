@@ -0,0 +1,44 @@
+/*
+ * SPDX-FileCopyrightText: 2023 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+#![cfg(test)]
+#![cfg(feature = "glam")]
+
+use epserde::prelude::*;
+use glam::{Mat4, Vec3};
+use maligned::A16;
+
+#[test]
+fn test_glam_vec3() {
+    let v: Vec<Vec3> = (0..100)
+        .map(|i| Vec3::new(i as f32, 2.0 * i as f32, 3.0 * i as f32))
+        .collect();
+    let mut cursor = <AlignedCursor<A16>>::new();
+    v.serialize(&mut cursor).unwrap();
+
+    cursor.set_position(0);
+    let full = <Vec<Vec3>>::deserialize_full(&mut cursor).unwrap();
+    assert_eq!(full, v);
+    let eps: &[Vec3] = <Vec<Vec3>>::deserialize_eps(cursor.as_bytes()).unwrap();
+    assert_eq!(eps, v.as_slice());
+}
+
+#[test]
+fn test_glam_mat4() {
+    let m = Mat4::from_cols_array(&core::array::from_fn(|i| i as f32));
+    let mut cursor = <AlignedCursor<A16>>::new();
+    m.serialize(&mut cursor).unwrap();
+
+    cursor.set_position(0);
+    assert_eq!(Mat4::deserialize_full(&mut cursor).unwrap(), m);
+    assert_eq!(*Mat4::deserialize_eps(cursor.as_bytes()).unwrap(), m);
+
+    let v = vec![m, m.transpose(), Mat4::IDENTITY];
+    let mut cursor = <AlignedCursor<A16>>::new();
+    v.serialize(&mut cursor).unwrap();
+    let eps: &[Mat4] = <Vec<Mat4>>::deserialize_eps(cursor.as_bytes()).unwrap();
+    assert_eq!(eps, v.as_slice());
+}
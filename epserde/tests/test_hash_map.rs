@@ -0,0 +1,113 @@
+/*
+ * SPDX-FileCopyrightText: 2023 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+#![cfg(test)]
+
+use core::hash::{BuildHasherDefault, Hash, Hasher};
+use epserde::prelude::*;
+use maligned::A16;
+use std::collections::HashMap;
+
+/// A simple non-default hasher (FNV-1a).
+#[derive(Default)]
+struct Fnv(u64);
+
+impl Hasher for Fnv {
+    fn finish(&self) -> u64 {
+        self.0
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        for &b in bytes {
+            self.0 = (self.0 ^ b as u64).wrapping_mul(0x100000001b3);
+        }
+    }
+}
+
+impl TypeHash for Fnv {
+    fn type_hash(hasher: &mut impl Hasher) {
+        "Fnv".hash(hasher);
+    }
+}
+
+type FnvBuildHasher = BuildHasherDefault<Fnv>;
+
+#[test]
+fn test_hash_map() {
+    let map: HashMap<u32, String> = (0..100).map(|i| (i, i.to_string())).collect();
+    let mut cursor = <AlignedCursor<A16>>::new();
+    map.serialize(&mut cursor).unwrap();
+
+    cursor.set_position(0);
+    let full = <HashMap<u32, String>>::deserialize_full(&mut cursor).unwrap();
+    assert_eq!(full, map);
+
+    let eps = <HashMap<u32, String>>::deserialize_eps(cursor.as_bytes()).unwrap();
+    assert_eq!(eps.len(), map.len());
+    for (k, v) in &map {
+        assert_eq!(eps[k], v.as_str());
+    }
+}
+
+#[test]
+fn test_hash_map_hasher() {
+    let mut map = HashMap::<String, Vec<u64>, FnvBuildHasher>::default();
+    map.insert("a".to_string(), vec![1, 2, 3]);
+    map.insert("b".to_string(), vec![]);
+    map.insert("c".to_string(), vec![4]);
+
+    let mut cursor = <AlignedCursor<A16>>::new();
+    map.serialize(&mut cursor).unwrap();
+
+    cursor.set_position(0);
+    let full = <HashMap<String, Vec<u64>, FnvBuildHasher>>::deserialize_full(&mut cursor).unwrap();
+    assert_eq!(full, map);
+
+    let eps =
+        <HashMap<String, Vec<u64>, FnvBuildHasher>>::deserialize_eps(cursor.as_bytes()).unwrap();
+    assert_eq!(eps.len(), 3);
+    assert_eq!(eps["a"], &[1, 2, 3]);
+    assert!(eps["b"].is_empty());
+    assert_eq!(eps["c"], &[4]);
+
+    // The hasher is part of the type hash
+    cursor.set_position(0);
+    assert!(<HashMap<String, Vec<u64>>>::deserialize_full(&mut cursor).is_err());
+}
+
+#[test]
+fn test_hash_map_deterministic() {
+    let mut a = HashMap::<String, Vec<u32>>::new();
+    let mut b = HashMap::<String, Vec<u32>>::new();
+    for i in 0..100 {
+        a.insert(i.to_string(), vec![i; i as usize % 5]);
+    }
+    for i in (0..100).rev() {
+        b.insert(i.to_string(), vec![i; i as usize % 5]);
+    }
+    assert_eq!(a, b);
+
+    let mut cursor_a = <AlignedCursor<A16>>::new();
+    let mut cursor_b = <AlignedCursor<A16>>::new();
+    let bytes_a = a.serialize_deterministic(&mut cursor_a).unwrap();
+    let bytes_b = b.serialize_deterministic(&mut cursor_b).unwrap();
+    assert_eq!(bytes_a, bytes_b);
+    assert_eq!(cursor_a.as_bytes(), cursor_b.as_bytes());
+
+    // Nested maps are deterministic, too
+    let nested_a: Vec<HashMap<String, Vec<u32>>> = vec![a.clone(), b.clone()];
+    let nested_b: Vec<HashMap<String, Vec<u32>>> = vec![b, a.clone()];
+    let mut cursor_a = <AlignedCursor<A16>>::new();
+    let mut cursor_b = <AlignedCursor<A16>>::new();
+    nested_a.serialize_deterministic(&mut cursor_a).unwrap();
+    nested_b.serialize_deterministic(&mut cursor_b).unwrap();
+    assert_eq!(cursor_a.as_bytes(), cursor_b.as_bytes());
+
+    // The output can be deserialized as usual
+    cursor_a.set_position(0);
+    let full = <Vec<HashMap<String, Vec<u32>>>>::deserialize_full(&mut cursor_a).unwrap();
+    assert_eq!(full, nested_a);
+}
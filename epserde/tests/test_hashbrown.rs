@@ -0,0 +1,41 @@
+/*
+ * SPDX-FileCopyrightText: 2023 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+#![cfg(test)]
+#![cfg(feature = "hashbrown")]
+
+use epserde::prelude::*;
+use hashbrown::HashMap;
+use maligned::A16;
+
+#[test]
+fn test_hashbrown() {
+    let map: HashMap<u32, u32> = (0..100).map(|i| (i, i * i)).collect();
+    let mut cursor = <AlignedCursor<A16>>::new();
+    map.serialize(&mut cursor).unwrap();
+
+    cursor.set_position(0);
+    let full = <HashMap<u32, u32>>::deserialize_full(&mut cursor).unwrap();
+    assert_eq!(full, map);
+
+    let eps = <HashMap<u32, u32>>::deserialize_eps(cursor.as_bytes()).unwrap();
+    assert_eq!(eps, map);
+}
+
+#[test]
+fn test_hashbrown_deterministic() {
+    let mut a = HashMap::<u32, u32>::new();
+    let mut b = HashMap::<u32, u32>::new();
+    for i in 0..100 {
+        a.insert(i, i);
+        b.insert(99 - i, 99 - i);
+    }
+    let mut bytes_a = vec![];
+    let mut bytes_b = vec![];
+    a.serialize_deterministic(&mut bytes_a).unwrap();
+    b.serialize_deterministic(&mut bytes_b).unwrap();
+    assert_eq!(bytes_a, bytes_b);
+}
@@ -0,0 +1,79 @@
+/*
+ * SPDX-FileCopyrightText: 2023 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+#![cfg(test)]
+
+use epserde::deser::check_compatible;
+use epserde::prelude::*;
+use maligned::A16;
+
+#[derive(Epserde, Debug, PartialEq, Eq, Clone)]
+struct Data {
+    a: Vec<u64>,
+    b: String,
+}
+
+#[test]
+fn test_check_compatible() {
+    let data = Data {
+        a: vec![1, 2, 3],
+        b: "data".to_string(),
+    };
+    let mut cursor = <AlignedCursor<A16>>::new();
+    data.serialize(&mut cursor).unwrap();
+    cursor.set_position(0);
+
+    // Peek at the header without consuming the reader
+    let mut reader = std::io::BufReader::new(&mut cursor);
+    let header = std::io::BufRead::fill_buf(&mut reader).unwrap();
+    check_compatible::<Data>(header).unwrap();
+    assert!(matches!(
+        check_compatible::<Vec<u64>>(header),
+        Err(deser::Error::WrongTypeHash { .. })
+    ));
+    assert!(matches!(
+        check_compatible::<Data>(&header[..10]),
+        Err(deser::Error::ReadError(_))
+    ));
+    assert_eq!(Data::deserialize_full(&mut reader).unwrap(), data);
+}
+
+#[test]
+fn test_compact() {
+    let data = vec![Data {
+        a: vec![1, 2, 3],
+        b: "data".to_string(),
+    }];
+    let mut cursor = <AlignedCursor<A16>>::new();
+    let len = data.serialize(&mut cursor).unwrap();
+    let mut compact = <AlignedCursor<A16>>::new();
+    let compact_len = data.serialize_compact(&mut compact).unwrap();
+    assert!(compact_len < len);
+
+    // Only the type name is omitted from the header
+    let mut backend = SliceWithPos::new(cursor.as_bytes());
+    deser::read_header(&mut backend).unwrap();
+    let header_len = backend.pos();
+    let mut backend = SliceWithPos::new(compact.as_bytes());
+    let (_, _, type_name) = deser::read_header(&mut backend).unwrap();
+    assert!(type_name.is_empty());
+    assert_eq!(
+        header_len - backend.pos(),
+        core::any::type_name::<Vec<Data>>().len()
+    );
+
+    compact.set_position(0);
+    assert_eq!(<Vec<Data>>::deserialize_full(&mut compact).unwrap(), data);
+    let eps = <Vec<Data>>::deserialize_eps(compact.as_bytes()).unwrap();
+    assert_eq!(eps[0].a, data[0].a);
+    assert_eq!(eps[0].b, data[0].b);
+
+    // Hashes are still checked
+    assert!(matches!(
+        <Vec<u64>>::deserialize_eps(compact.as_bytes()),
+        Err(deser::Error::WrongTypeHash { .. })
+    ));
+}
@@ -0,0 +1,73 @@
+/*
+ * SPDX-FileCopyrightText: 2023 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+#![cfg(test)]
+
+use epserde::prelude::*;
+use maligned::A16;
+
+#[derive(Epserde, Debug, PartialEq, Clone)]
+struct Index {
+    name: String,
+    #[epserde(independent)]
+    offsets: Vec<u64>,
+    #[epserde(independent)]
+    count: usize,
+}
+
+#[derive(Epserde, Debug, PartialEq, Clone)]
+struct Data<A> {
+    #[epserde(independent)]
+    data: A,
+    version: u32,
+}
+
+#[test]
+fn test_independent_field() {
+    let mut index = Index {
+        name: "index".to_string(),
+        offsets: vec![0, 10, 20, 30],
+        count: 4,
+    };
+
+    let mut cursor = <AlignedCursor<A16>>::new();
+    index.serialize(&mut cursor).unwrap();
+    let full = Index::deserialize_eps(cursor.as_bytes()).unwrap();
+    assert_eq!(full, index);
+
+    // Update a field and serialize it independently
+    index.offsets.push(40);
+    let mut offsets_cursor = <AlignedCursor<A16>>::new();
+    let len = index.serialize_field_offsets(&mut offsets_cursor).unwrap();
+    assert_eq!(len, offsets_cursor.len());
+    let offsets = <Vec<u64>>::deserialize_eps(offsets_cursor.as_bytes()).unwrap();
+    assert_eq!(offsets, index.offsets.as_slice());
+    offsets_cursor.set_position(0);
+    let offsets = <Vec<u64>>::deserialize_full(&mut offsets_cursor).unwrap();
+    assert_eq!(offsets, index.offsets);
+
+    // The field has the same serialization as a standalone value
+    let mut expected = <AlignedCursor<A16>>::new();
+    index.offsets.serialize(&mut expected).unwrap();
+    assert_eq!(offsets_cursor.as_bytes(), expected.as_bytes());
+
+    let mut count_cursor = <AlignedCursor<A16>>::new();
+    index.serialize_field_count(&mut count_cursor).unwrap();
+    count_cursor.set_position(0);
+    assert_eq!(usize::deserialize_full(&mut count_cursor).unwrap(), 4);
+}
+
+#[test]
+fn test_independent_generic_field() {
+    let data = Data {
+        data: vec![1_u32, 2, 3],
+        version: 1,
+    };
+    let mut cursor = <AlignedCursor<A16>>::new();
+    data.serialize_field_data(&mut cursor).unwrap();
+    let field = <Vec<u32>>::deserialize_eps(cursor.as_bytes()).unwrap();
+    assert_eq!(field, &[1, 2, 3]);
+}
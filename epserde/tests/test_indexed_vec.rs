@@ -0,0 +1,127 @@
+/*
+ * SPDX-FileCopyrightText: 2023 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+#![cfg(test)]
+
+use epserde::prelude::*;
+use maligned::A16;
+
+#[derive(Epserde, Debug, Clone, PartialEq)]
+struct Deep {
+    id: u8,
+    name: String,
+    data: Vec<u64>,
+}
+
+#[derive(Epserde, Debug, Clone, Copy, PartialEq)]
+#[repr(C)]
+#[zero_copy]
+struct Zero {
+    a: u16,
+    b: u64,
+}
+
+fn deep(i: usize) -> Deep {
+    Deep {
+        id: i as u8,
+        name: i.to_string(),
+        data: (0..i as u64 % 7).collect(),
+    }
+}
+
+#[test]
+fn test_indexed_vec_deep() {
+    let v: IndexedVec<Deep> = (0..1000).map(deep).collect::<Vec<_>>().into();
+    let mut cursor = <AlignedCursor<A16>>::new();
+    v.serialize(&mut cursor).unwrap();
+
+    cursor.set_position(0);
+    let full = <IndexedVec<Deep>>::deserialize_full(&mut cursor).unwrap();
+    assert_eq!(full, v);
+
+    let view = <IndexedVec<Deep>>::deserialize_eps(cursor.as_bytes()).unwrap();
+    assert_eq!(view.len(), 1000);
+    // Only element 500 is deserialized
+    let item = view.get(500).unwrap();
+    assert_eq!(item.id, 500_usize as u8);
+    assert_eq!(item.name, "500");
+    assert_eq!(item.data, &[0, 1, 2][..]);
+
+    for (i, item) in view.iter().enumerate() {
+        let item = item.unwrap();
+        assert_eq!(item.name, i.to_string());
+        assert_eq!(item.data, v[i].data.as_slice());
+    }
+}
+
+#[test]
+fn test_indexed_vec_zero() {
+    let v: IndexedVec<Zero> = (0..100)
+        .map(|i| Zero {
+            a: i,
+            b: i as u64 * 3,
+        })
+        .collect::<Vec<_>>()
+        .into();
+    let mut cursor = <AlignedCursor<A16>>::new();
+    v.serialize(&mut cursor).unwrap();
+
+    let view = <IndexedVec<Zero>>::deserialize_eps(cursor.as_bytes()).unwrap();
+    assert_eq!(*view.get(42).unwrap(), v[42]);
+}
+
+#[derive(Epserde, Debug, Clone, PartialEq)]
+struct Outer<A> {
+    a: A,
+    b: A,
+    c: u32,
+}
+
+#[test]
+fn test_indexed_vec_in_struct() {
+    // The view must skip the elements to access the following fields
+    let data = Outer {
+        a: IndexedVec::from(vec!["a".to_string(), "bcd".to_string()]),
+        b: IndexedVec::from(Vec::<String>::new()),
+        c: 7,
+    };
+    let mut cursor = <AlignedCursor<A16>>::new();
+    data.serialize(&mut cursor).unwrap();
+    let eps = <Outer<IndexedVec<String>>>::deserialize_eps(cursor.as_bytes()).unwrap();
+    assert_eq!(eps.a.get(1).unwrap(), "bcd");
+    assert!(eps.b.is_empty());
+    assert_eq!(eps.c, 7);
+}
+
+#[test]
+fn test_indexed_vec_bad_offsets() {
+    for offsets in [vec![], vec![1_usize, 8], vec![0, 8, 0], vec![0, 1000]] {
+        // Write by hand an indexed vector with the given offsets
+        let mut cursor = <AlignedCursor<A16>>::new();
+        let mut writer_with_pos = WriterWithPos::new(&mut cursor);
+        ser::write_header::<IndexedVec<u64>>(&mut writer_with_pos).unwrap();
+        offsets.serialize_no_header(&mut writer_with_pos).unwrap();
+        42_u64.serialize_no_header(&mut writer_with_pos).unwrap();
+
+        assert!(matches!(
+            <IndexedVec<u64>>::deserialize_eps(cursor.as_bytes()),
+            Err(deser::Error::InvalidOffsets)
+        ));
+    }
+
+    // Full-copy deserialization uses just the number of offsets
+    let mut cursor = <AlignedCursor<A16>>::new();
+    let mut writer_with_pos = WriterWithPos::new(&mut cursor);
+    ser::write_header::<IndexedVec<u64>>(&mut writer_with_pos).unwrap();
+    Vec::<usize>::new()
+        .serialize_no_header(&mut writer_with_pos)
+        .unwrap();
+    cursor.set_position(0);
+    assert!(matches!(
+        <IndexedVec<u64>>::deserialize_full(&mut cursor),
+        Err(deser::Error::InvalidOffsets)
+    ));
+}
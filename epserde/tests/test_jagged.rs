@@ -0,0 +1,95 @@
+/*
+ * SPDX-FileCopyrightText: 2023 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+#![cfg(test)]
+
+use epserde::prelude::*;
+use maligned::A16;
+
+#[test]
+fn test_jagged() {
+    let rows: Vec<Vec<u32>> = (0..10).map(|i| (0..i).collect()).collect();
+    let jagged: Jagged<u32> = rows.clone().into();
+    assert_eq!(jagged.len(), rows.len());
+
+    let mut cursor = <AlignedCursor<A16>>::new();
+    let schema = jagged.serialize_with_schema(&mut cursor).unwrap();
+    println!("{}", schema.to_csv());
+
+    cursor.set_position(0);
+    let full_copy = <Jagged<u32>>::deserialize_full(&mut cursor).unwrap();
+    assert_eq!(jagged, full_copy);
+
+    let view = <Jagged<u32>>::deserialize_eps(cursor.as_bytes()).unwrap();
+    assert_eq!(view.len(), rows.len());
+    for (i, row) in rows.iter().enumerate() {
+        assert_eq!(view.get(i), row.as_slice());
+    }
+    assert!(view.iter().eq(rows.iter().map(|r| r.as_slice())));
+    assert_eq!(view, jagged.as_view());
+}
+
+#[test]
+fn test_jagged_empty() {
+    let jagged = <Jagged<u64>>::new();
+    let mut cursor = <AlignedCursor<A16>>::new();
+    jagged.serialize(&mut cursor).unwrap();
+
+    let view = <Jagged<u64>>::deserialize_eps(cursor.as_bytes()).unwrap();
+    assert!(view.is_empty());
+    assert_eq!(view.iter().count(), 0);
+}
+
+#[test]
+fn test_jagged_slices() {
+    let rows: Vec<Vec<u32>> = (0..10).map(|i| (0..i).collect()).collect();
+    let slices = rows.iter().map(|r| r.as_slice()).collect::<Vec<_>>();
+    let slices: &[&[u32]] = &slices;
+
+    let mut cursor = <AlignedCursor<A16>>::new();
+    let schema = slices.serialize_with_schema(&mut cursor).unwrap();
+    println!("{}", schema.to_csv());
+
+    let view = <Jagged<u32>>::deserialize_eps(cursor.as_bytes()).unwrap();
+    assert_eq!(view.len(), rows.len());
+    for (i, row) in rows.iter().enumerate() {
+        assert_eq!(view.get(i), row.as_slice());
+    }
+
+    cursor.set_position(0);
+    let full_copy = <Jagged<u32>>::deserialize_full(&mut cursor).unwrap();
+    assert_eq!(full_copy, Jagged::from(rows));
+}
+
+#[test]
+fn test_jagged_bad_offsets() {
+    for offsets in [
+        vec![],
+        vec![1_usize, 3],
+        vec![0, 2, 1, 3],
+        vec![0, 2],
+        vec![0, 4],
+    ] {
+        // Write by hand a jagged array with the given offsets
+        let mut cursor = <AlignedCursor<A16>>::new();
+        let mut writer_with_pos = WriterWithPos::new(&mut cursor);
+        ser::write_header::<Jagged<u32>>(&mut writer_with_pos).unwrap();
+        offsets.serialize_no_header(&mut writer_with_pos).unwrap();
+        vec![1_u32, 2, 3]
+            .serialize_no_header(&mut writer_with_pos)
+            .unwrap();
+
+        assert!(matches!(
+            <Jagged<u32>>::deserialize_eps(cursor.as_bytes()),
+            Err(deser::Error::InvalidOffsets)
+        ));
+        cursor.set_position(0);
+        assert!(matches!(
+            <Jagged<u32>>::deserialize_full(&mut cursor),
+            Err(deser::Error::InvalidOffsets)
+        ));
+    }
+}
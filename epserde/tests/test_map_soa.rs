@@ -0,0 +1,91 @@
+/*
+ * SPDX-FileCopyrightText: 2023 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+#![cfg(test)]
+
+use epserde::prelude::*;
+use maligned::A16;
+use std::collections::HashMap;
+
+#[test]
+fn test_map_soa() {
+    let map: HashMap<u64, (u32, f64)> = (0..100).map(|i| (i * 3, (i as u32, i as f64))).collect();
+    let soa = MapSoa::from(map.clone());
+    let mut cursor = <AlignedCursor<A16>>::new();
+    soa.serialize(&mut cursor).unwrap();
+
+    cursor.set_position(0);
+    let full = <MapSoa<u64, (u32, f64)>>::deserialize_full(&mut cursor).unwrap();
+    assert_eq!(full.into_inner(), map);
+
+    let view = <MapSoa<u64, (u32, f64)>>::deserialize_eps(cursor.as_bytes()).unwrap();
+    assert_eq!(view.len(), map.len());
+    // Scan the keys only
+    let keys: &[u64] = view.keys();
+    assert_eq!(keys.iter().sum::<u64>(), map.keys().sum::<u64>());
+    assert_eq!(keys.iter().filter(|&&k| k % 2 == 0).count(), 50);
+
+    for (k, v) in &map {
+        assert_eq!(view.get(k), Some(v));
+    }
+    assert_eq!(view.get(&1), None);
+    assert!(!view.contains_key(&2));
+    assert_eq!(view.iter().count(), map.len());
+    assert_eq!(<MapSoa<u64, (u32, f64)>>::from(view).into_inner(), map);
+}
+
+#[test]
+fn test_map_soa_empty() {
+    let soa = MapSoa::<u32, u8>::default();
+    let mut cursor = <AlignedCursor<A16>>::new();
+    soa.serialize(&mut cursor).unwrap();
+
+    let view = <MapSoa<u32, u8>>::deserialize_eps(cursor.as_bytes()).unwrap();
+    assert!(view.is_empty());
+    assert!(view.keys().is_empty());
+    assert!(view.values().is_empty());
+}
+
+#[test]
+fn test_map_soa_deterministic() {
+    let a: MapSoa<u32, u64> = MapSoa((0..1000).map(|i| (i, i as u64 * 2)).collect());
+    let b: MapSoa<u32, u64> = MapSoa((0..1000).rev().map(|i| (i, i as u64 * 2)).collect());
+    let mut cursor_a = <AlignedCursor<A16>>::new();
+    let mut cursor_b = <AlignedCursor<A16>>::new();
+    a.serialize_deterministic(&mut cursor_a).unwrap();
+    b.serialize_deterministic(&mut cursor_b).unwrap();
+    assert_eq!(cursor_a.as_bytes(), cursor_b.as_bytes());
+}
+
+#[test]
+fn test_map_soa_length_mismatch() {
+    // Two keys, but a single value
+    let mut cursor = <AlignedCursor<A16>>::new();
+    let mut writer_with_pos = WriterWithPos::new(&mut cursor);
+    ser::write_header::<MapSoa<u64, u32>>(&mut writer_with_pos).unwrap();
+    vec![1_u64, 2]
+        .serialize_no_header(&mut writer_with_pos)
+        .unwrap();
+    vec![1_u32]
+        .serialize_no_header(&mut writer_with_pos)
+        .unwrap();
+
+    assert!(matches!(
+        <MapSoa<u64, u32>>::deserialize_eps(cursor.as_bytes()),
+        Err(deser::Error::LengthMismatch {
+            expected: 2,
+            got: 1
+        })
+    ));
+    cursor.set_position(0);
+    assert!(matches!(
+        <MapSoa<u64, u32>>::deserialize_full(&mut cursor),
+        Err(deser::Error::LengthMismatch {
+            expected: 2,
+            got: 1
+        })
+    ));
+}
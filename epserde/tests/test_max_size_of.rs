@@ -68,3 +68,125 @@ fn test_max_size_of_align() {
     let eps = <MyStruct64>::deserialize_eps(cursor.as_bytes()).unwrap();
     assert_eq!(x, *eps);
 }
+
+mod plain {
+    use epserde::prelude::*;
+
+    #[derive(Epserde, Debug, Clone, Copy, PartialEq, Eq)]
+    #[repr(C)]
+    #[zero_copy]
+    pub struct Line {
+        pub data: [u8; 64],
+    }
+}
+
+mod aligned {
+    use epserde::prelude::*;
+
+    #[derive(Epserde, Debug, Clone, Copy, PartialEq, Eq)]
+    #[repr(C, align(64))]
+    #[zero_copy]
+    pub struct Line {
+        pub data: [u8; 64],
+    }
+}
+
+#[derive(Epserde, Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(align(64))]
+#[repr(C)]
+#[zero_copy]
+struct LineSplit {
+    data: [u8; 64],
+}
+
+#[derive(Epserde, Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(C, align(64))]
+#[zero_copy]
+struct LineJoined {
+    data: [u8; 64],
+}
+
+fn repr_hash<T: ReprHash>() -> u64 {
+    use core::hash::Hasher;
+    let mut hasher = xxhash_rust::xxh3::Xxh3::new();
+    let mut offset_of = 0;
+    T::repr_hash(&mut hasher, &mut offset_of);
+    hasher.finish()
+}
+
+#[test]
+/// Check that an explicit alignment is reflected in max_size_of and in the
+/// representation hash
+fn test_repr_align() {
+    assert_eq!(64, aligned::Line::max_size_of());
+    assert_eq!(1, plain::Line::max_size_of());
+    assert_ne!(repr_hash::<plain::Line>(), repr_hash::<aligned::Line>());
+    // The position of align hints is irrelevant
+    assert_eq!(repr_hash::<LineSplit>(), repr_hash::<LineJoined>());
+
+    let x = aligned::Line { data: [0x89; 64] };
+    let mut cursor = <AlignedCursor<A64>>::new();
+    x.serialize(&mut cursor).unwrap();
+
+    let eps = <aligned::Line>::deserialize_eps(cursor.as_bytes()).unwrap();
+    assert_eq!(x, *eps);
+
+    let err = <plain::Line>::deserialize_eps(cursor.as_bytes());
+    assert!(matches!(err, Err(deser::Error::WrongTypeReprHash { .. })));
+    cursor.set_position(0);
+    let err = <plain::Line>::deserialize_full(&mut cursor);
+    assert!(matches!(err, Err(deser::Error::WrongTypeReprHash { .. })));
+
+    let x = plain::Line { data: [0x89; 64] };
+    let mut cursor = <AlignedCursor<A64>>::new();
+    x.serialize(&mut cursor).unwrap();
+    let err = <aligned::Line>::deserialize_eps(cursor.as_bytes());
+    assert!(matches!(err, Err(deser::Error::WrongTypeReprHash { .. })));
+}
+
+#[test]
+fn test_max_size_of_helper() {
+    assert_eq!(max_size_of::<[u64; 3]>(), core::mem::size_of::<u64>());
+    assert_eq!(max_size_of::<MyStruct64>(), 64);
+    assert_eq!(max_size_of::<[MyStruct; 4]>(), MyStruct::max_size_of());
+}
+
+#[derive(Epserde, Debug, Clone, PartialEq)]
+struct Layouts {
+    a: u8,
+    b: MyStruct64,
+    c: u8,
+    d: MyStruct2,
+    e: u8,
+    f: (u16, u64),
+    g: u8,
+    h: aligned::Line,
+    i: u8,
+    j: Vec<aligned::Line>,
+    k: Vec<(u16, u64)>,
+}
+
+#[test]
+/// Check that full-copy deserialization of zero-copy types and vectors
+/// passes the alignment self-test, which is active in debug builds
+fn test_layout_self_test() {
+    let x = Layouts {
+        a: 1,
+        b: MyStruct64 { u: 2 },
+        c: 3,
+        d: MyStruct2 { u: 4 },
+        e: 5,
+        f: (6, 7),
+        g: 8,
+        h: aligned::Line { data: [9; 64] },
+        i: 10,
+        j: vec![aligned::Line { data: [11; 64] }; 2],
+        k: vec![(12, 13), (14, 15)],
+    };
+    let mut cursor = <AlignedCursor<A64>>::new();
+    x.serialize(&mut cursor).unwrap();
+
+    cursor.set_position(0);
+    let full = Layouts::deserialize_full(&mut cursor).unwrap();
+    assert_eq!(x, full);
+}
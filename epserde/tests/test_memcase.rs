@@ -82,3 +82,134 @@ fn test_mem_case() {
     // cleanup the file
     std::fs::remove_file("test.bin").unwrap();
 }
+
+#[test]
+fn test_from_static() {
+    let person = Person {
+        a: vec![0x89; 6],
+        b: Data {
+            a: vec![0x42; 7],
+            b: vec![0xbadf00d; 2],
+        },
+        test: -0xbadf00d,
+    };
+    let mut cursor = <AlignedCursor<maligned::A16>>::new();
+    person.serialize(&mut cursor).unwrap();
+
+    // Leak the aligned storage to obtain 'static bytes
+    let (storage, len) = cursor.into_parts();
+    let storage: &'static [maligned::A16] = storage.leak();
+    let bytes: &'static [u8] =
+        unsafe { core::slice::from_raw_parts(storage.as_ptr() as *const u8, len) };
+
+    let res = Person::from_static(bytes).unwrap();
+    assert_eq!(person.test, res.test);
+    assert_eq!(person.a, res.a);
+    assert_eq!(person.b.a, res.b.a);
+    assert_eq!(person.b.b, res.b.b);
+}
+
+#[test]
+fn test_mmap_mut() {
+    let path = std::env::temp_dir().join("test_mmap_mut.bin");
+    0x1234_5678_u32.store(&path).unwrap();
+
+    let mut case = u32::mmap_mut(&path, Flags::empty()).unwrap();
+    assert_eq!(*case, 0x1234_5678);
+    *case = 0xdead_beef;
+    case.flush().unwrap();
+    drop(case);
+
+    assert_eq!(u32::load_full(&path).unwrap(), 0xdead_beef);
+    let case = u32::mmap(&path, Flags::empty()).unwrap();
+    assert_eq!(*case, 0xdead_beef);
+    drop(case);
+
+    // Deep-copy headers are rejected
+    vec![0_u32; 4].store(&path).unwrap();
+    assert!(u32::mmap_mut(&path, Flags::empty()).is_err());
+    std::fs::remove_file(&path).unwrap();
+}
+
+#[derive(Epserde, Debug, PartialEq, Eq, Clone, Copy)]
+#[repr(C, align(128))]
+#[zero_copy]
+struct Aligned128 {
+    a: u64,
+    b: u32,
+}
+
+#[test]
+fn test_load_mem_aligned() {
+    let data = Aligned128 { a: 1, b: 2 };
+    let path = std::env::temp_dir().join("test_load_mem_aligned.bin");
+    data.store(&path).unwrap();
+
+    let res = Aligned128::load_mem(&path).unwrap();
+    assert_eq!(**res, data);
+    assert_eq!(*res as *const Aligned128 as usize % 128, 0);
+    std::fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn test_backend_bytes() {
+    let data = vec![1_u32, 2, 3, 4];
+    let path = std::env::temp_dir().join("test_backend_bytes.bin");
+    data.store(&path).unwrap();
+    let file_len = std::fs::metadata(&path).unwrap().len() as usize;
+
+    let mem_case = <Vec<u32>>::load_mem(&path).unwrap();
+    let bytes = mem_case.backend_bytes().unwrap();
+    // The memory is padded to the alignment of the backend
+    assert!(bytes.len() >= file_len);
+    assert_eq!(bytes[..file_len], std::fs::read(&path).unwrap()[..]);
+
+    let mem_case = <Vec<u32>>::mmap(&path, Flags::empty()).unwrap();
+    assert_eq!(mem_case.backend_bytes().unwrap().len(), file_len);
+    std::fs::remove_file(&path).unwrap();
+
+    assert!(MemCase::encase(data).backend_bytes().is_none());
+}
+
+#[test]
+fn test_to_owned_value() {
+    let data = (0..100_u32).collect::<Vec<_>>();
+    let file = std::env::temp_dir().join("test_to_owned_value.bin");
+    data.store(&file).unwrap();
+
+    let mem_case = <Vec<u32>>::load_mem(&file).unwrap();
+    let owned: Vec<u32> = mem_case.to_owned_value();
+    assert_eq!(owned, data);
+
+    let mem_case = <Vec<u32>>::mmap(&file, Flags::empty()).unwrap();
+    let owned: Vec<u32> = mem_case.to_owned_value();
+    assert_eq!(owned, data);
+    std::fs::remove_file(file).unwrap();
+}
+
+#[test]
+fn test_mem_case_send_sync() {
+    fn assert_send_sync<T: Send + Sync>() {}
+    assert_send_sync::<MemCase<&[u32]>>();
+    assert_send_sync::<MemCase<Person>>();
+
+    let data = (0..1000_u32).collect::<Vec<_>>();
+    let file = std::env::temp_dir().join("test_mem_case_send_sync.bin");
+    data.store(&file).unwrap();
+
+    let mem_case = <Vec<u32>>::load_mmap(&file, Flags::empty()).unwrap();
+    let sum = std::thread::spawn(move || mem_case.iter().sum::<u32>())
+        .join()
+        .unwrap();
+    assert_eq!(sum, data.iter().sum());
+
+    // Shared among threads
+    let mem_case = <Vec<u32>>::load_mem(&file).unwrap();
+    std::thread::scope(|s| {
+        for i in 0..4 {
+            let mem_case = &mem_case;
+            s.spawn(move || assert_eq!(mem_case[i], i as u32));
+        }
+    });
+    std::fs::remove_file(file).unwrap();
+}
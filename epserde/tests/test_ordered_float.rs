@@ -0,0 +1,36 @@
+/*
+ * SPDX-FileCopyrightText: 2023 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+#![cfg(test)]
+#![cfg(feature = "ordered-float")]
+
+use epserde::prelude::*;
+use maligned::A16;
+use ordered_float::OrderedFloat;
+
+#[test]
+fn test_ordered_float() {
+    let v: Vec<OrderedFloat<f64>> = [1.5, -0.0, f64::NAN, f64::INFINITY, 1E-300]
+        .into_iter()
+        .map(OrderedFloat)
+        .collect();
+    let mut cursor = <AlignedCursor<A16>>::new();
+    v.serialize(&mut cursor).unwrap();
+
+    cursor.set_position(0);
+    let full = <Vec<OrderedFloat<f64>>>::deserialize_full(&mut cursor).unwrap();
+    assert_eq!(full, v);
+    let eps: &[OrderedFloat<f64>] =
+        <Vec<OrderedFloat<f64>>>::deserialize_eps(cursor.as_bytes()).unwrap();
+    assert_eq!(eps, v.as_slice());
+
+    // The type hash depends on the wrapped type
+    let mut cursor = <AlignedCursor<A16>>::new();
+    vec![OrderedFloat(1.0_f32)].serialize(&mut cursor).unwrap();
+    assert!(<Vec<OrderedFloat<f64>>>::deserialize_eps(cursor.as_bytes()).is_err());
+    let eps = <Vec<OrderedFloat<f32>>>::deserialize_eps(cursor.as_bytes()).unwrap();
+    assert_eq!(eps, [OrderedFloat(1.0)]);
+}
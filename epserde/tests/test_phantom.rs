@@ -129,3 +129,139 @@ fn test_only_phantom() {
     let eps = <OnlyPhantom<ZeroCopyType>>::deserialize_eps(cursor.as_bytes()).unwrap();
     assert_eq!(obj.a, eps.a);
 }
+
+#[derive(Epserde, Debug, PartialEq, Eq, Clone, Default)]
+struct Covariant<T> {
+    data: Vec<usize>,
+    _marker: PhantomData<fn() -> T>,
+}
+
+/// Test that we can use a function pointer as a variance marker.
+#[test]
+fn test_phantom_fn() {
+    let obj = Covariant::<NotSerializableType> {
+        data: vec![1, 2, 3],
+        _marker: PhantomData,
+    };
+
+    let mut cursor = <AlignedCursor<A16>>::new();
+    let _bytes_written = obj.serialize(&mut cursor).unwrap();
+
+    cursor.set_position(0);
+    let full = <Covariant<NotSerializableType>>::deserialize_full(&mut cursor).unwrap();
+    assert_eq!(obj, full);
+
+    let eps = <Covariant<NotSerializableType>>::deserialize_eps(cursor.as_bytes()).unwrap();
+    assert_eq!(obj.data, eps.data);
+
+    // The signature is part of the type hash
+    let mut cursor = <AlignedCursor<A16>>::new();
+    PhantomData::<fn() -> usize>.serialize(&mut cursor).unwrap();
+    assert!(<PhantomData<fn() -> usize>>::deserialize_eps(cursor.as_bytes()).is_ok());
+    assert!(<PhantomData<fn(usize)>>::deserialize_eps(cursor.as_bytes()).is_err());
+}
+
+#[derive(Epserde, Debug, PartialEq, Eq, Clone, Default)]
+struct Borrowing<'a, T> {
+    data: Vec<usize>,
+    _marker: PhantomData<&'a T>,
+}
+
+#[derive(Epserde, Debug, PartialEq, Eq, Clone, Default)]
+struct RawPtr<T> {
+    data: Vec<usize>,
+    _marker: PhantomData<*const T>,
+    _mut_marker: PhantomData<*mut T>,
+}
+
+#[derive(Epserde, Debug, PartialEq, Eq, Clone, Default)]
+struct Nested<A> {
+    a: A,
+    b: u32,
+}
+
+#[derive(Epserde, Debug, PartialEq, Eq, Clone, Copy, Default)]
+#[repr(C)]
+#[zero_copy]
+struct ZeroBorrowing<'a, T: Default + ZeroCopy> {
+    a: usize,
+    _marker: PhantomData<&'a T>,
+}
+
+/// Test that references and raw pointers can be used as markers.
+#[test]
+fn test_phantom_ref_ptr() {
+    let obj = Borrowing::<NotSerializableType> {
+        data: vec![1, 2, 3],
+        _marker: PhantomData,
+    };
+    let mut cursor = <AlignedCursor<A16>>::new();
+    obj.serialize(&mut cursor).unwrap();
+    cursor.set_position(0);
+    let full = <Borrowing<NotSerializableType>>::deserialize_full(&mut cursor).unwrap();
+    assert_eq!(obj, full);
+    let eps = <Borrowing<NotSerializableType>>::deserialize_eps(cursor.as_bytes()).unwrap();
+    assert_eq!(obj.data, eps.data);
+
+    let obj = RawPtr::<NotSerializableType> {
+        data: vec![4, 5],
+        _marker: PhantomData,
+        _mut_marker: PhantomData,
+    };
+    let mut cursor = <AlignedCursor<A16>>::new();
+    obj.serialize(&mut cursor).unwrap();
+    cursor.set_position(0);
+    let full = <RawPtr<NotSerializableType>>::deserialize_full(&mut cursor).unwrap();
+    assert_eq!(obj, full);
+    let eps = <RawPtr<NotSerializableType>>::deserialize_eps(cursor.as_bytes()).unwrap();
+    assert_eq!(obj.data, eps.data);
+
+    // Markers do not change the serialized data, but they are part of the type hash
+    assert!(<Borrowing<NotSerializableType>>::deserialize_eps(cursor.as_bytes()).is_err());
+
+    let obj = ZeroBorrowing::<'static, ZeroCopyType> {
+        a: 7,
+        _marker: PhantomData,
+    };
+    let mut cursor = <AlignedCursor<A16>>::new();
+    obj.serialize(&mut cursor).unwrap();
+    cursor.set_position(0);
+    let full = <ZeroBorrowing<ZeroCopyType>>::deserialize_full(&mut cursor).unwrap();
+    assert_eq!(obj, full);
+    let eps = <ZeroBorrowing<ZeroCopyType>>::deserialize_eps(cursor.as_bytes()).unwrap();
+    assert_eq!(obj, *eps);
+}
+
+/// Test that markers work in nested generics.
+#[test]
+fn test_phantom_nested() {
+    let obj = Nested {
+        a: Borrowing::<'static, NotSerializableType> {
+            data: vec![1, 2, 3],
+            _marker: PhantomData,
+        },
+        b: 8,
+    };
+    let mut cursor = <AlignedCursor<A16>>::new();
+    obj.serialize(&mut cursor).unwrap();
+    cursor.set_position(0);
+    let full = <Nested<Borrowing<NotSerializableType>>>::deserialize_full(&mut cursor).unwrap();
+    assert_eq!(obj, full);
+    let eps = <Nested<Borrowing<NotSerializableType>>>::deserialize_eps(cursor.as_bytes()).unwrap();
+    assert_eq!(eps.a.data, obj.a.data);
+    assert_eq!(eps.b, 8);
+
+    let obj = Nested {
+        a: RawPtr::<Vec<u8>> {
+            data: vec![9],
+            _marker: PhantomData,
+            _mut_marker: PhantomData,
+        },
+        b: 10,
+    };
+    let mut cursor = <AlignedCursor<A16>>::new();
+    obj.serialize(&mut cursor).unwrap();
+    let eps = <Nested<RawPtr<Vec<u8>>>>::deserialize_eps(cursor.as_bytes()).unwrap();
+    assert_eq!(eps.a.data, &[9]);
+    assert_eq!(eps.b, 10);
+}
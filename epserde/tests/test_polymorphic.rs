@@ -0,0 +1,52 @@
+/*
+ * SPDX-FileCopyrightText: 2023 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+#![cfg(test)]
+
+use epserde::prelude::*;
+use maligned::A16;
+
+#[derive(Epserde, Debug, PartialEq, Eq, Clone, Copy)]
+#[repr(C)]
+#[zero_copy]
+struct Point {
+    x: u32,
+    y: u32,
+}
+
+#[derive(Epserde, Debug, PartialEq, Eq, Clone)]
+#[epserde(polymorphic)]
+enum Shape<V> {
+    Point(Point),
+    Name(String),
+    Path(V),
+}
+
+#[test]
+fn test_polymorphic() {
+    let shapes: [Shape<Vec<u32>>; 3] = [
+        Point { x: 1, y: 2 }.into(),
+        String::from("origin").into(),
+        Shape::Path(vec![1_u32, 2, 3]),
+    ];
+
+    for shape in &shapes {
+        let mut cursor = <AlignedCursor<A16>>::new();
+        shape.serialize(&mut cursor).unwrap();
+
+        cursor.set_position(0);
+        let full = <Shape<Vec<u32>>>::deserialize_full(&mut cursor).unwrap();
+        assert_eq!(&full, shape);
+
+        let eps = <Shape<Vec<u32>>>::deserialize_eps(cursor.as_bytes()).unwrap();
+        match (shape, eps) {
+            (Shape::Point(p), Shape::Point(q)) => assert_eq!(p, &q),
+            (Shape::Name(s), Shape::Name(t)) => assert_eq!(s, &t),
+            (Shape::Path(v), Shape::Path(w)) => assert_eq!(v.as_slice(), w),
+            _ => panic!("Wrong variant"),
+        }
+    }
+}
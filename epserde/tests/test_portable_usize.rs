@@ -0,0 +1,152 @@
+/*
+ * SPDX-FileCopyrightText: 2023 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+#![cfg(test)]
+#![cfg(feature = "portable-usize")]
+
+use core::mem::size_of;
+use epserde::deser::Error;
+use epserde::prelude::*;
+use maligned::A16;
+
+fn serialize(data: &impl Serialize) -> Vec<u8> {
+    let mut cursor = <AlignedCursor<A16>>::new();
+    data.serialize(&mut cursor).unwrap();
+    cursor.as_bytes().to_vec()
+}
+
+/// Return the payload of some serialized data, that is, the bytes after the
+/// header.
+fn payload(bytes: &[u8]) -> &[u8] {
+    let mut backend = SliceWithPos::new(bytes);
+    deser::read_header(&mut backend).unwrap();
+    &bytes[backend.pos..]
+}
+
+/// Offset of the size of a usize in the header, after the magic cookie and
+/// the version.
+const USIZE_SIZE_OFFSET: usize = 8 + 2 + 2;
+
+#[test]
+fn test_usize_as_u64() {
+    assert_eq!(epserde::USIZE_SIZE, 8);
+    for value in [0_usize, 1, 0xDEAD, usize::MAX] {
+        let bytes = serialize(&value);
+        assert_eq!(bytes[USIZE_SIZE_OFFSET], 8);
+        // A usize is written exactly as the corresponding u64
+        assert_eq!(payload(&bytes), payload(&serialize(&(value as u64))));
+        assert_eq!(
+            usize::deserialize_full(&mut bytes.as_slice()).unwrap(),
+            value
+        );
+        assert_eq!(usize::deserialize_eps(&bytes).unwrap(), value);
+    }
+}
+
+#[test]
+fn test_cross_width() -> anyhow::Result<()> {
+    // Lengths are written as u64 values, so the data written on a 64-bit
+    // architecture is what a 32-bit architecture would write, too.
+    let data = vec![String::from("a"), String::from("bc"), String::new()];
+    let bytes = serialize(&data);
+    let payload = payload(&bytes);
+    assert_eq!(&payload[..8], &3_u64.to_ne_bytes());
+    assert_eq!(&payload[8..16], &1_u64.to_ne_bytes());
+    assert_eq!(&payload[16..17], b"a");
+    assert_eq!(&payload[17..25], &2_u64.to_ne_bytes());
+
+    let full = <Vec<String>>::deserialize_full(&mut bytes.as_slice())?;
+    assert_eq!(full, data);
+    let eps = <Vec<String>>::deserialize_eps(&bytes)?;
+    assert_eq!(eps, vec!["a", "bc", ""]);
+
+    let data = vec![vec![1_u32, 2, 3], vec![], vec![4]];
+    let bytes = serialize(&data);
+    let eps = <Vec<Vec<u32>>>::deserialize_eps(&bytes)?;
+    assert_eq!(eps, vec![&[1, 2, 3][..], &[], &[4]]);
+
+    Ok(())
+}
+
+#[test]
+fn test_native_width_rejected() {
+    // Simulate data written with native usize values on a 32-bit
+    // architecture without the portable-usize feature
+    let mut bytes = serialize(&vec![String::from("a")]);
+    bytes[USIZE_SIZE_OFFSET] = 4;
+    assert!(matches!(
+        <Vec<String>>::deserialize_full(&mut bytes.as_slice()),
+        Err(Error::UsizeSizeMismatch(4))
+    ));
+    assert!(matches!(
+        <Vec<String>>::deserialize_eps(&bytes),
+        Err(Error::UsizeSizeMismatch(4))
+    ));
+}
+
+#[test]
+fn test_vec_usize_native() -> anyhow::Result<()> {
+    // usize values inside zero-copy data are written using the native size,
+    // so, differently from the length, they are not portable
+    let data = vec![1_usize, 2, 3];
+    let bytes = serialize(&data);
+    let payload = payload(&bytes);
+    assert_eq!(&payload[..8], &3_u64.to_ne_bytes());
+    let elements = &payload[payload.len() - 3 * size_of::<usize>()..];
+    assert_eq!(elements[..size_of::<usize>()], 1_usize.to_ne_bytes());
+
+    assert_eq!(<Vec<usize>>::deserialize_full(&mut bytes.as_slice())?, data);
+    assert_eq!(<Vec<usize>>::deserialize_eps(&bytes)?, data.as_slice());
+    Ok(())
+}
+
+fn repr_hash<T: ReprHash>() -> u64 {
+    let mut hasher = std::hash::DefaultHasher::new();
+    let mut offset_of = 0;
+    T::repr_hash_isolated(&mut hasher, &mut offset_of);
+    core::hash::Hasher::finish(&hasher)
+}
+
+#[test]
+fn test_repr_hash_width_independent() {
+    #[derive(Epserde, Debug)]
+    struct WithUsize {
+        len: usize,
+        value: u32,
+    }
+
+    #[derive(Epserde, Debug)]
+    struct WithU64 {
+        len: u64,
+        value: u32,
+    }
+
+    #[derive(Epserde, Debug)]
+    enum EnumWithUsize {
+        A(usize, u32),
+    }
+
+    #[derive(Epserde, Debug)]
+    enum EnumWithU64 {
+        A(u64, u32),
+    }
+
+    // A usize in isolation is written as a u64, so its representation hash
+    // is that of a u64 whatever the size of a native usize
+    assert_eq!(repr_hash::<usize>(), repr_hash::<u64>());
+    assert_eq!(repr_hash::<WithUsize>(), repr_hash::<WithU64>());
+    assert_eq!(repr_hash::<EnumWithUsize>(), repr_hash::<EnumWithU64>());
+    assert_eq!(repr_hash::<Option<usize>>(), repr_hash::<Option<u64>>());
+    assert_eq!(
+        repr_hash::<core::ops::Range<usize>>(),
+        repr_hash::<core::ops::Range<u64>>()
+    );
+    // Inside zero-copy data the native size is used
+    assert_eq!(
+        repr_hash::<Vec<usize>>() == repr_hash::<Vec<u64>>(),
+        size_of::<usize>() == size_of::<u64>()
+    );
+}
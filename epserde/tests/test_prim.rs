@@ -178,3 +178,27 @@ fn test_box_str() {
         }
     }
 }
+
+#[test]
+fn test_deserialize_str_eps() {
+    for test_str in TEST_STRS {
+        let mut v = vec![];
+        test_str.to_string().serialize(&mut v).unwrap();
+        assert_eq!(deser::deserialize_str_eps(&v).unwrap(), *test_str);
+
+        let mut v = vec![];
+        test_str
+            .to_string()
+            .into_boxed_str()
+            .serialize(&mut v)
+            .unwrap();
+        assert_eq!(deser::deserialize_str_eps(&v).unwrap(), *test_str);
+    }
+
+    let mut v = vec![];
+    1337_usize.serialize(&mut v).unwrap();
+    assert!(matches!(
+        deser::deserialize_str_eps(&v),
+        Err(deser::Error::WrongTypeHash { .. })
+    ));
+}
@@ -0,0 +1,69 @@
+/*
+ * SPDX-FileCopyrightText: 2023 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+#![cfg(test)]
+
+use epserde::prelude::*;
+use maligned::A16;
+
+#[derive(Epserde, Debug, PartialEq, Eq, Clone)]
+struct Event {
+    id: u64,
+    payload: Vec<u8>,
+}
+
+#[test]
+fn test_record_log() {
+    let event = Event {
+        id: 3,
+        payload: vec![1, 2, 3],
+    };
+    let mut log = RecordLogWriter::new(<AlignedCursor<A16>>::new());
+    let mut written = log.append(&vec![1_u32, 2, 3]).unwrap();
+    written += log.append(&"hello".to_string()).unwrap();
+    written += log.append(&event).unwrap();
+    assert_eq!(log.num_records(), 3);
+    let mut cursor = log.into_inner();
+    let bytes = cursor.as_bytes();
+    assert_eq!(written, bytes.len());
+    assert_eq!(bytes.len() % RECORD_ALIGN, 0);
+
+    let (vec_hash, _) = deser::type_and_repr_hash::<Vec<u32>>();
+    let (string_hash, _) = deser::type_and_repr_hash::<String>();
+    let (event_hash, _) = deser::type_and_repr_hash::<Event>();
+
+    let records = RecordLogReader::new(bytes)
+        .collect::<deser::Result<Vec<_>>>()
+        .unwrap();
+    assert_eq!(
+        records.iter().map(|(hash, _)| *hash).collect::<Vec<_>>(),
+        vec![vec_hash, string_hash, event_hash]
+    );
+    for (type_hash, record) in records {
+        if type_hash == vec_hash {
+            assert_eq!(<Vec<u32>>::deserialize_eps(record).unwrap(), &[1, 2, 3]);
+        } else if type_hash == string_hash {
+            assert_eq!(String::deserialize_eps(record).unwrap(), "hello");
+        } else {
+            let eps = Event::deserialize_eps(record).unwrap();
+            assert_eq!(eps.id, 3);
+            assert_eq!(eps.payload, &[1, 2, 3]);
+            let full = Event::deserialize_full(&mut std::io::Cursor::new(record)).unwrap();
+            assert_eq!(full, event);
+        }
+    }
+
+    // A truncated log returns an error and then stops
+    let mut reader = RecordLogReader::new(&bytes[..bytes.len() - 20]);
+    assert!(reader.next().unwrap().is_ok());
+    assert!(reader.next().unwrap().is_ok());
+    assert!(matches!(
+        reader.next(),
+        Some(Err(deser::Error::ReadError(err)))
+            if err.kind() == std::io::ErrorKind::UnexpectedEof
+    ));
+    assert!(reader.next().is_none());
+}
@@ -0,0 +1,73 @@
+/*
+ * SPDX-FileCopyrightText: 2023 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+#![cfg(test)]
+
+use epserde::prelude::*;
+use maligned::A16;
+
+#[derive(Epserde, Debug, Clone, PartialEq, Eq)]
+enum Tree {
+    Leaf(u32),
+    Node(Box<Tree>, Box<Tree>),
+}
+
+impl Tree {
+    /// Return a balanced tree of the given depth whose leaves are numbered
+    /// starting from `first`.
+    fn balanced(depth: usize, first: u32) -> Self {
+        if depth == 0 {
+            Tree::Leaf(first)
+        } else {
+            Tree::Node(
+                Box::new(Tree::balanced(depth - 1, first)),
+                Box::new(Tree::balanced(depth - 1, first + (1 << (depth - 1)))),
+            )
+        }
+    }
+
+    fn sum(&self) -> u64 {
+        match self {
+            Tree::Leaf(x) => *x as u64,
+            Tree::Node(left, right) => left.sum() + right.sum(),
+        }
+    }
+}
+
+#[test]
+fn test_recursive_tree() {
+    let tree = Tree::balanced(10, 0);
+    let mut cursor = <AlignedCursor<A16>>::new();
+    tree.serialize(&mut cursor).unwrap();
+
+    cursor.set_position(0);
+    let full = Tree::deserialize_full(&mut cursor).unwrap();
+    assert_eq!(full, tree);
+    assert_eq!(full.sum(), (0..1 << 10).sum::<u64>());
+
+    let eps = Tree::deserialize_eps(cursor.as_bytes()).unwrap();
+    assert_eq!(eps, tree);
+}
+
+#[test]
+fn test_box() {
+    let b = Box::new(vec![1_u32, 2, 3]);
+    let mut cursor = <AlignedCursor<A16>>::new();
+    b.serialize(&mut cursor).unwrap();
+
+    cursor.set_position(0);
+    let full = <Box<Vec<u32>>>::deserialize_full(&mut cursor).unwrap();
+    assert_eq!(full, b);
+
+    let eps = <Box<Vec<u32>>>::deserialize_eps(cursor.as_bytes()).unwrap();
+    assert_eq!(*eps, b.as_slice());
+
+    // Boxing changes the hashes
+    assert_ne!(
+        deser::type_and_repr_hash::<Box<Tree>>(),
+        deser::type_and_repr_hash::<Tree>()
+    );
+}
@@ -0,0 +1,83 @@
+/*
+ * SPDX-FileCopyrightText: 2023 Inria
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+#![cfg(test)]
+
+use epserde::prelude::*;
+use maligned::A16;
+
+trait Node {
+    fn weight(&self) -> usize;
+}
+
+#[derive(Epserde, Debug, PartialEq, Clone, Copy)]
+#[repr(C)]
+#[zero_copy]
+struct Leaf {
+    value: usize,
+}
+
+impl Node for Leaf {
+    fn weight(&self) -> usize {
+        self.value
+    }
+}
+
+#[derive(Epserde, Debug, PartialEq, Clone)]
+struct Inner {
+    children: Vec<usize>,
+    name: String,
+}
+
+impl Node for Inner {
+    fn weight(&self) -> usize {
+        self.children.iter().sum()
+    }
+}
+
+fn registry() -> TypeRegistry<dyn Node> {
+    let mut registry = TypeRegistry::<dyn Node>::new();
+    registry
+        .register::<Leaf>(|leaf| Box::new(leaf))
+        .register::<Inner>(|inner| Box::new(inner));
+    registry
+}
+
+#[test]
+fn test_registry() {
+    let registry = registry();
+    assert!(registry.is_registered::<Leaf>());
+    assert!(registry.is_registered::<Inner>());
+    assert!(!registry.is_registered::<usize>());
+
+    let leaf = Leaf { value: 42 };
+    let mut cursor = <AlignedCursor<A16>>::new();
+    leaf.serialize(&mut cursor).unwrap();
+    let node = registry.deserialize_dyn(cursor.as_bytes()).unwrap();
+    assert_eq!(node.weight(), 42);
+
+    let inner = Inner {
+        children: vec![1, 2, 3, 4],
+        name: "inner".into(),
+    };
+    let mut cursor = <AlignedCursor<A16>>::new();
+    inner.serialize(&mut cursor).unwrap();
+    let node = registry.deserialize_dyn(cursor.as_bytes()).unwrap();
+    assert_eq!(node.weight(), 10);
+}
+
+#[test]
+fn test_registry_unregistered() {
+    let registry = registry();
+    let mut cursor = <AlignedCursor<A16>>::new();
+    1337_usize.serialize(&mut cursor).unwrap();
+    let err = registry.deserialize_dyn(cursor.as_bytes());
+    if let Err(deser::Error::UnregisteredType { type_name, .. }) = err {
+        assert_eq!(type_name, "usize");
+    } else {
+        panic!("wrong error type: {:?}", err.map(|_| ()));
+    }
+}
@@ -0,0 +1,115 @@
+/*
+ * SPDX-FileCopyrightText: 2023 Inria
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+#![cfg(test)]
+
+use epserde::prelude::*;
+use maligned::A16;
+
+#[derive(Epserde, Debug, PartialEq, Clone, Copy)]
+#[repr(C)]
+#[zero_copy]
+struct Point {
+    x: u8,
+    y: u64,
+}
+
+#[derive(Epserde, Debug, PartialEq, Clone)]
+struct Inner {
+    tag: u8,
+    points: Vec<Point>,
+    point: Point,
+}
+
+#[derive(Epserde, Debug, PartialEq, Clone)]
+struct Outer {
+    a: u16,
+    inner: Inner,
+    name: Option<String>,
+    data: Vec<u32>,
+}
+
+fn outer() -> Outer {
+    Outer {
+        a: 1,
+        inner: Inner {
+            tag: 2,
+            points: vec![Point { x: 3, y: 4 }; 3],
+            point: Point { x: 5, y: 6 },
+        },
+        name: Some("name".into()),
+        data: vec![7, 8, 9],
+    }
+}
+
+#[test]
+fn test_schema_validate() {
+    let mut cursor = <AlignedCursor<A16>>::new();
+    let schema = outer().serialize_with_schema(&mut cursor).unwrap();
+    assert!(schema.0.iter().any(|row| row.field == "PADDING"));
+    schema.validate().unwrap();
+
+    // The last row must end at the end of the data
+    let end = schema
+        .0
+        .iter()
+        .map(|row| row.offset + row.size)
+        .max()
+        .unwrap();
+    assert_eq!(end, cursor.len());
+}
+
+#[test]
+fn test_schema_validate_corrupted() {
+    let mut cursor = <AlignedCursor<A16>>::new();
+    let mut schema = outer().serialize_with_schema(&mut cursor).unwrap();
+    let row = schema
+        .0
+        .iter_mut()
+        .find(|row| row.field == "PADDING")
+        .unwrap();
+    row.size += 1;
+    assert!(schema.validate().is_err());
+}
+
+#[test]
+fn test_schema_writer() {
+    let data = outer();
+    let mut cursor = <AlignedCursor<A16>>::new();
+    let mut writer_with_pos = WriterWithPos::new(&mut cursor);
+    let mut schema_writer = SchemaWriter::new(&mut writer_with_pos);
+    data.serialize_on_field_write(&mut schema_writer).unwrap();
+    let schema: Schema = schema_writer.into_schema();
+    schema.validate().unwrap();
+
+    // Same schema and bytes as serialize_with_schema
+    let mut cursor2 = <AlignedCursor<A16>>::new();
+    let schema2 = data.serialize_with_schema(&mut cursor2).unwrap();
+    assert_eq!(schema.to_csv(), schema2.to_csv());
+    assert_eq!(cursor.as_bytes(), cursor2.as_bytes());
+
+    let row: &SchemaRow = schema
+        .0
+        .iter()
+        .find(|row| row.field == "ROOT.inner.tag")
+        .unwrap();
+    assert_eq!(row.size, 1);
+    assert_eq!(cursor.as_bytes()[row.offset], 2);
+}
+
+#[test]
+fn test_schema_size_by_type() {
+    let mut cursor = <AlignedCursor<A16>>::new();
+    let schema = outer().serialize_with_schema(&mut cursor).unwrap();
+    assert_eq!(schema.total_size(), cursor.len());
+
+    let sizes = schema.size_by_type();
+    assert_eq!(sizes.values().sum::<usize>(), schema.total_size());
+    // The three u32 in data
+    assert_eq!(sizes["u32"], 3 * 4);
+    // Structures contain other rows, so they are not reported
+    assert!(!sizes.contains_key("Outer"));
+}
@@ -0,0 +1,149 @@
+/*
+ * SPDX-FileCopyrightText: 2023 Inria
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+#![cfg(test)]
+
+use epserde::prelude::*;
+use maligned::A64;
+
+#[derive(Epserde, Debug, PartialEq, Clone)]
+struct Data {
+    id: u64,
+    flag: bool,
+    scores: Vec<u32>,
+    name: String,
+}
+
+#[test]
+fn test_self_describing() {
+    let data = Data {
+        id: 42,
+        flag: true,
+        scores: vec![1, 2, 3],
+        name: "test".to_string(),
+    };
+    let mut cursor = <AlignedCursor<A64>>::new();
+    let written = data.serialize_self_describing(&mut cursor).unwrap();
+    assert_eq!(written, cursor.as_bytes().len());
+
+    // Read back field names and values without using the type
+    let bytes = cursor.as_bytes();
+    let view = SelfDescribing::deserialize_eps(bytes).unwrap();
+    let root = view.root().unwrap();
+    assert_eq!(
+        root.children()
+            .iter()
+            .map(|node| node.name())
+            .collect::<Vec<_>>(),
+        vec!["id", "flag", "scores", "name"]
+    );
+    assert_eq!(root.get("id").unwrap().value(), Some(DynValue::U64(42)));
+    assert_eq!(
+        root.get("flag").unwrap().value(),
+        Some(DynValue::Bool(true))
+    );
+    let scores = root.get("scores").unwrap();
+    assert_eq!(scores.get("len").unwrap().value(), Some(DynValue::Usize(3)));
+    let zero = scores.get("zero").unwrap();
+    assert_eq!(zero.ty(), "u32");
+    assert_eq!(zero.bytes().len(), 12);
+    assert_eq!(zero.value(), None);
+    assert!(root.get("missing").is_none());
+    assert!(view.header().iter().any(|node| node.field() == "TYPE_NAME"));
+
+    // The schema is the one of the plain serialization
+    let mut plain = <AlignedCursor<A64>>::new();
+    let schema = data.serialize_with_schema(&mut plain).unwrap();
+    assert_eq!(view.schema().to_csv(), schema.to_csv());
+
+    // If the type is known, the data can still be deserialized
+    let eps = view.deserialize_eps::<Data>().unwrap();
+    assert_eq!(eps.id, 42);
+    assert_eq!(eps.scores, &[1, 2, 3]);
+    assert_eq!(eps.name, "test");
+
+    // Full-copy deserialization
+    let full = SelfDescribing::deserialize_full(&mut std::io::Cursor::new(bytes)).unwrap();
+    assert_eq!(full.as_view(), view);
+}
+
+/// Write self-describing data with the given rows and data.
+fn write_rows(
+    fields: Vec<String>,
+    types: Vec<String>,
+    offsets: Vec<usize>,
+    sizes: Vec<usize>,
+    aligns: Vec<usize>,
+    data: Vec<u8>,
+) -> AlignedCursor<A64> {
+    let mut cursor = <AlignedCursor<A64>>::new();
+    let mut writer_with_pos = WriterWithPos::new(&mut cursor);
+    ser::write_header::<SelfDescribing>(&mut writer_with_pos).unwrap();
+    fields.serialize_no_header(&mut writer_with_pos).unwrap();
+    types.serialize_no_header(&mut writer_with_pos).unwrap();
+    offsets.serialize_no_header(&mut writer_with_pos).unwrap();
+    sizes.serialize_no_header(&mut writer_with_pos).unwrap();
+    aligns.serialize_no_header(&mut writer_with_pos).unwrap();
+    <AlignedVec<u8, 64>>::from(data)
+        .serialize_no_header(&mut writer_with_pos)
+        .unwrap();
+    cursor
+}
+
+#[test]
+fn test_self_describing_bad_rows() {
+    // A field with no type
+    let mut cursor = write_rows(
+        vec!["ROOT".to_string()],
+        vec![],
+        vec![0],
+        vec![1],
+        vec![1],
+        vec![0],
+    );
+    assert!(matches!(
+        SelfDescribing::deserialize_eps(cursor.as_bytes()),
+        Err(deser::Error::LengthMismatch {
+            expected: 1,
+            got: 0
+        })
+    ));
+    cursor.set_position(0);
+    assert!(matches!(
+        SelfDescribing::deserialize_full(&mut cursor),
+        Err(deser::Error::LengthMismatch {
+            expected: 1,
+            got: 0
+        })
+    ));
+
+    // A field extending beyond the data
+    let mut cursor = write_rows(
+        vec!["ROOT".to_string()],
+        vec!["u16".to_string()],
+        vec![0],
+        vec![2],
+        vec![2],
+        vec![0],
+    );
+    assert!(matches!(
+        SelfDescribing::deserialize_eps(cursor.as_bytes()),
+        Err(deser::Error::InvalidFieldRange {
+            offset: 0,
+            size: 2,
+            data_len: 1
+        })
+    ));
+    cursor.set_position(0);
+    assert!(matches!(
+        SelfDescribing::deserialize_full(&mut cursor),
+        Err(deser::Error::InvalidFieldRange {
+            offset: 0,
+            size: 2,
+            data_len: 1
+        })
+    ));
+}
@@ -0,0 +1,61 @@
+/*
+ * SPDX-FileCopyrightText: 2023 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+#![cfg(test)]
+
+use epserde::deser::SeqReader;
+use epserde::prelude::*;
+use maligned::A16;
+
+#[test]
+fn test_seq_reader() {
+    let v = (0..100_000).map(|i| i.to_string()).collect::<Vec<_>>();
+    let mut cursor = <AlignedCursor<A16>>::new();
+    v.serialize(&mut cursor).unwrap();
+
+    let reader = SeqReader::<String>::new(cursor.as_bytes()).unwrap();
+    assert_eq!(reader.len(), v.len());
+    let first = reader.take(3).collect::<deser::Result<Vec<_>>>().unwrap();
+    assert_eq!(first, ["0", "1", "2"]);
+
+    let reader = SeqReader::<String>::new(cursor.as_bytes()).unwrap();
+    let found = reader
+        .map(Result::unwrap)
+        .find(|s| s.ends_with("777"))
+        .unwrap();
+    assert_eq!(found, "777");
+
+    let reader = SeqReader::<String>::new(cursor.as_bytes()).unwrap();
+    assert!(reader.map(Result::unwrap).eq(v.iter().map(String::as_str)));
+
+    // Nested vectors of zero-copy elements are returned as slices
+    let v = (0..100_u32)
+        .map(|i| vec![i; i as usize])
+        .collect::<Vec<_>>();
+    let mut cursor = <AlignedCursor<A16>>::new();
+    v.serialize(&mut cursor).unwrap();
+    let mut reader = SeqReader::<Vec<u32>>::new(cursor.as_bytes()).unwrap();
+    let third: &[u32] = reader.nth(2).unwrap().unwrap();
+    assert_eq!(third, [2, 2]);
+
+    // Wrong type
+    assert!(SeqReader::<Vec<u64>>::new(cursor.as_bytes()).is_err());
+}
+
+#[test]
+fn test_seq_reader_truncated() {
+    let v = vec!["a".to_string(), "b".to_string(), "c".to_string()];
+    let mut cursor = <AlignedCursor<A16>>::new();
+    v.serialize(&mut cursor).unwrap();
+    let len = cursor.len();
+
+    let bytes = &cursor.as_bytes()[..len - 1];
+    let mut reader = SeqReader::<String>::new(bytes).unwrap();
+    assert_eq!(reader.next().unwrap().unwrap(), "a");
+    assert_eq!(reader.next().unwrap().unwrap(), "b");
+    assert!(reader.next().unwrap().is_err());
+    assert!(reader.next().is_none());
+}
@@ -29,3 +29,43 @@ fn test_cheaty_serialize() -> Result<()> {
     assert_eq!(a, b);
     Ok(())
 }
+
+#[test]
+fn test_slice_zero() -> Result<()> {
+    let a = vec![1_u32, 2, 3, 4];
+    let s: &[u32] = a.as_slice();
+
+    let mut cursor = <AlignedCursor<A16>>::new();
+    s.serialize(&mut cursor)?;
+    // Same bytes as the vector
+    let mut vec_cursor = <AlignedCursor<A16>>::new();
+    a.serialize(&mut vec_cursor)?;
+    assert_eq!(cursor.as_bytes(), vec_cursor.as_bytes());
+
+    cursor.set_position(0);
+    let full: Vec<u32> = <Vec<u32>>::deserialize_full(&mut cursor)?;
+    assert_eq!(full, a);
+    let eps: &[u32] = <Vec<u32>>::deserialize_eps(cursor.as_bytes())?;
+    assert_eq!(eps, s);
+    Ok(())
+}
+
+#[test]
+fn test_slice_deep() -> Result<()> {
+    let a = vec!["a".to_string(), "".to_string(), "bcd".to_string()];
+    let s: &[String] = a.as_slice();
+
+    let mut cursor = <AlignedCursor<A16>>::new();
+    s.serialize(&mut cursor)?;
+    // Same bytes as the vector
+    let mut vec_cursor = <AlignedCursor<A16>>::new();
+    a.serialize(&mut vec_cursor)?;
+    assert_eq!(cursor.as_bytes(), vec_cursor.as_bytes());
+
+    cursor.set_position(0);
+    let full: Vec<String> = <Vec<String>>::deserialize_full(&mut cursor)?;
+    assert_eq!(full, a);
+    let eps: Vec<&str> = <Vec<String>>::deserialize_eps(cursor.as_bytes())?;
+    assert_eq!(eps, a);
+    Ok(())
+}
@@ -0,0 +1,101 @@
+/*
+ * SPDX-FileCopyrightText: 2023 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+#![cfg(test)]
+
+use epserde::prelude::*;
+use maligned::A16;
+use std::collections::BTreeMap;
+
+#[derive(Epserde, Debug, PartialEq, Eq, Clone, Copy)]
+#[repr(C)]
+#[zero_copy]
+struct Posting {
+    doc: u32,
+    freq: u32,
+}
+
+#[test]
+fn test_sorted_map() {
+    let map = (0..100_u64)
+        .map(|i| {
+            (
+                i * 3,
+                Posting {
+                    doc: i as u32,
+                    freq: 1,
+                },
+            )
+        })
+        .collect::<BTreeMap<_, _>>();
+
+    let mut cursor = <AlignedCursor<A16>>::new();
+    let schema = map.serialize_with_schema(&mut cursor).unwrap();
+    schema.validate().unwrap();
+
+    cursor.set_position(0);
+    let full = <BTreeMap<u64, Posting>>::deserialize_full(&mut cursor).unwrap();
+    assert_eq!(full, map);
+
+    let view = <BTreeMap<u64, Posting>>::deserialize_eps(cursor.as_bytes()).unwrap();
+    assert_eq!(view.len(), 100);
+    assert_eq!(view.get(&30).unwrap().doc, 10);
+    assert!(view.get(&31).is_none());
+    assert!(view.contains_key(&297));
+
+    for (from, to) in [(0, 10), (1, 2), (31, 150), (290, 1000), (50, 40)] {
+        assert_eq!(
+            view.range(from..to).collect::<Vec<_>>(),
+            map.range(from..to.max(from)).collect::<Vec<_>>()
+        );
+        assert_eq!(
+            view.range(from..=to).collect::<Vec<_>>(),
+            map.range(from..=to.max(from)).collect::<Vec<_>>()
+        );
+    }
+    assert_eq!(view.range(..).count(), 100);
+    assert_eq!(BTreeMap::from(view), map);
+}
+
+#[test]
+fn test_sorted_map_empty() {
+    let map = BTreeMap::<u32, u64>::new();
+    let mut cursor = <AlignedCursor<A16>>::new();
+    map.serialize(&mut cursor).unwrap();
+    let view = <BTreeMap<u32, u64>>::deserialize_eps(cursor.as_bytes()).unwrap();
+    assert!(view.is_empty());
+    assert_eq!(view.range(1..10).count(), 0);
+}
+
+#[test]
+fn test_sorted_map_length_mismatch() {
+    // Two keys, but a single value
+    let mut cursor = <AlignedCursor<A16>>::new();
+    let mut writer_with_pos = WriterWithPos::new(&mut cursor);
+    ser::write_header::<BTreeMap<u64, u32>>(&mut writer_with_pos).unwrap();
+    vec![1_u64, 2]
+        .serialize_no_header(&mut writer_with_pos)
+        .unwrap();
+    vec![1_u32]
+        .serialize_no_header(&mut writer_with_pos)
+        .unwrap();
+
+    assert!(matches!(
+        <BTreeMap<u64, u32>>::deserialize_eps(cursor.as_bytes()),
+        Err(deser::Error::LengthMismatch {
+            expected: 2,
+            got: 1
+        })
+    ));
+    cursor.set_position(0);
+    assert!(matches!(
+        <BTreeMap<u64, u32>>::deserialize_full(&mut cursor),
+        Err(deser::Error::LengthMismatch {
+            expected: 2,
+            got: 1
+        })
+    ));
+}
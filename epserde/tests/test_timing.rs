@@ -0,0 +1,59 @@
+/*
+ * SPDX-FileCopyrightText: 2023 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+#![cfg(test)]
+#![cfg(feature = "timing")]
+
+use epserde::prelude::*;
+use maligned::A16;
+
+#[derive(Epserde, Debug, PartialEq, Clone)]
+struct Inner {
+    a: Vec<u8>,
+    b: u32,
+}
+
+#[derive(Epserde, Debug, PartialEq, Clone)]
+struct Data {
+    values: Vec<u64>,
+    names: Vec<String>,
+    inner: Inner,
+}
+
+#[test]
+fn test_deserialize_full_timed() {
+    let data = Data {
+        values: (0..1_000_000).collect(),
+        names: (0..1000).map(|i| i.to_string()).collect(),
+        inner: Inner {
+            a: vec![1, 2, 3],
+            b: 4,
+        },
+    };
+    let mut cursor = <AlignedCursor<A16>>::new();
+    data.serialize(&mut cursor).unwrap();
+
+    cursor.set_position(0);
+    let (full, timings) = Data::deserialize_full_timed(&mut cursor).unwrap();
+    assert_eq!(full, data);
+    // Fields of the inner structure are not timed
+    assert_eq!(
+        timings
+            .iter()
+            .map(|(name, _)| name.as_str())
+            .collect::<Vec<_>>(),
+        ["values", "names", "inner"]
+    );
+    assert!(timings[0].1 > std::time::Duration::ZERO);
+
+    // Types without derived implementations have no timings
+    let mut cursor = <AlignedCursor<A16>>::new();
+    data.values.serialize(&mut cursor).unwrap();
+    cursor.set_position(0);
+    let (full, timings) = <Vec<u64>>::deserialize_full_timed(&mut cursor).unwrap();
+    assert_eq!(full, data.values);
+    assert!(timings.is_empty());
+}
@@ -0,0 +1,48 @@
+/*
+ * SPDX-FileCopyrightText: 2023 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+#![cfg(test)]
+#![cfg(feature = "tinyvec")]
+
+use epserde::prelude::*;
+use maligned::A16;
+use tinyvec::TinyVec;
+
+#[test]
+fn test_tinyvec_zero() {
+    for len in [0, 5, 8, 100] {
+        let v = (0..len).collect::<TinyVec<[u32; 8]>>();
+        let mut cursor = <AlignedCursor<A16>>::new();
+        v.serialize(&mut cursor).unwrap();
+
+        cursor.set_position(0);
+        let full = <TinyVec<[u32; 8]>>::deserialize_full(&mut cursor).unwrap();
+        assert_eq!(full, v);
+        assert_eq!(full.is_heap(), len > 8);
+
+        let eps = <TinyVec<[u32; 8]>>::deserialize_eps(cursor.as_bytes()).unwrap();
+        assert_eq!(eps, v.as_slice());
+    }
+}
+
+#[test]
+fn test_tinyvec_deep() {
+    for len in [2, 10] {
+        let v = (0..len)
+            .map(|i| i.to_string())
+            .collect::<TinyVec<[String; 4]>>();
+        let mut cursor = <AlignedCursor<A16>>::new();
+        v.serialize(&mut cursor).unwrap();
+
+        cursor.set_position(0);
+        let full = <TinyVec<[String; 4]>>::deserialize_full(&mut cursor).unwrap();
+        assert_eq!(full, v);
+        assert_eq!(full.is_heap(), len > 4);
+
+        let eps = <TinyVec<[String; 4]>>::deserialize_eps(cursor.as_bytes()).unwrap();
+        assert_eq!(eps, v.as_slice());
+    }
+}
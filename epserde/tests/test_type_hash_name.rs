@@ -0,0 +1,131 @@
+/*
+ * SPDX-FileCopyrightText: 2023 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+#![cfg(test)]
+
+use epserde::prelude::*;
+use maligned::A16;
+
+mod old {
+    use epserde::prelude::*;
+
+    #[derive(Epserde, Debug, PartialEq, Eq, Clone)]
+    pub struct Record {
+        pub id: u32,
+        pub tags: Vec<String>,
+    }
+
+    #[derive(Epserde, Debug, PartialEq, Eq, Clone, Copy)]
+    #[repr(C)]
+    #[zero_copy]
+    pub struct Point {
+        pub x: u32,
+        pub y: u32,
+    }
+
+    #[derive(Epserde, Debug, PartialEq, Eq, Clone)]
+    pub enum Shape {
+        Empty,
+        Square(u32),
+    }
+}
+
+mod new {
+    use epserde::prelude::*;
+
+    #[derive(Epserde, Debug, PartialEq, Eq, Clone)]
+    #[epserde(type_hash_name = "Record")]
+    pub struct Entry {
+        pub id: u32,
+        pub tags: Vec<String>,
+    }
+
+    #[derive(Epserde, Debug, PartialEq, Eq, Clone, Copy)]
+    #[repr(C)]
+    #[zero_copy]
+    #[epserde(type_hash_name = "Point")]
+    pub struct Coords {
+        pub x: u32,
+        pub y: u32,
+    }
+
+    #[derive(Epserde, Debug, PartialEq, Eq, Clone)]
+    #[epserde(type_hash_name = "Shape")]
+    pub enum Figure {
+        Empty,
+        Square(u32),
+    }
+
+    #[derive(Epserde, Debug, PartialEq, Eq, Clone)]
+    pub struct Record {
+        pub id: u32,
+        pub tags: Vec<String>,
+    }
+}
+
+#[test]
+fn test_type_hash_name() {
+    assert_eq!(
+        deser::type_and_repr_hash::<old::Record>(),
+        deser::type_and_repr_hash::<new::Entry>()
+    );
+    assert_eq!(
+        deser::type_and_repr_hash::<old::Point>(),
+        deser::type_and_repr_hash::<new::Coords>()
+    );
+    assert_eq!(
+        deser::type_and_repr_hash::<old::Shape>(),
+        deser::type_and_repr_hash::<new::Figure>()
+    );
+
+    // Data serialized with the old name can be deserialized with the new one
+    let record = old::Record {
+        id: 7,
+        tags: vec!["a".to_string()],
+    };
+    let mut cursor = <AlignedCursor<A16>>::new();
+    record.serialize(&mut cursor).unwrap();
+    cursor.set_position(0);
+    let full = new::Entry::deserialize_full(&mut cursor).unwrap();
+    assert_eq!(full.id, 7);
+    assert_eq!(full.tags, record.tags);
+    let eps = new::Entry::deserialize_eps(cursor.as_bytes()).unwrap();
+    assert_eq!(eps.id, 7);
+    assert_eq!(eps.tags, vec!["a"]);
+
+    // And vice versa
+    let point = new::Coords { x: 1, y: 2 };
+    let mut cursor = <AlignedCursor<A16>>::new();
+    point.serialize(&mut cursor).unwrap();
+    let eps = old::Point::deserialize_eps(cursor.as_bytes()).unwrap();
+    assert_eq!(*eps, old::Point { x: 1, y: 2 });
+
+    let mut cursor = <AlignedCursor<A16>>::new();
+    old::Shape::Square(3).serialize(&mut cursor).unwrap();
+    cursor.set_position(0);
+    assert_eq!(
+        new::Figure::deserialize_full(&mut cursor).unwrap(),
+        new::Figure::Square(3)
+    );
+
+    // The hash name is compatible with any type with the same name and fields
+    let mut cursor = <AlignedCursor<A16>>::new();
+    new::Record {
+        id: 7,
+        tags: vec![],
+    }
+    .serialize(&mut cursor)
+    .unwrap();
+    assert!(new::Entry::deserialize_eps(cursor.as_bytes()).is_ok());
+
+    // But not with other types
+    let mut cursor = <AlignedCursor<A16>>::new();
+    new::Coords { x: 1, y: 2 }.serialize(&mut cursor).unwrap();
+    assert!(matches!(
+        new::Entry::deserialize_eps(cursor.as_bytes()),
+        Err(deser::Error::WrongTypeHash { .. })
+    ));
+}
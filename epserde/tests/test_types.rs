@@ -50,6 +50,20 @@ fn test_vec_usize() {
     impl_test!(Vec<usize>, vec![1, 2, 3, 4, 5])
 }
 
+#[test]
+fn test_vec_duration() {
+    use core::time::Duration;
+    impl_test!(
+        Vec<Duration>,
+        vec![
+            Duration::ZERO,
+            Duration::new(1, 500),
+            Duration::from_millis(1234),
+            Duration::MAX
+        ]
+    )
+}
+
 #[test]
 fn test_box_slice_usize() {
     let a = vec![1, 2, 3, 4, 5].into_boxed_slice();
@@ -67,6 +81,28 @@ fn test_box_slice_usize() {
     assert_eq!(a, a2.into());
 }
 
+#[test]
+fn test_deserialize_full_boxed_slice() {
+    let v: Vec<u64> = (0..100).collect();
+    let mut cursor = <AlignedCursor<A16>>::new();
+    let mut writer_with_pos = WriterWithPos::new(&mut cursor);
+    v.serialize_no_header(&mut writer_with_pos).unwrap();
+
+    cursor.set_position(0);
+    let mut reader = deser::ReaderWithPos::new(&mut cursor);
+    let b: Box<[u64]> = deser::helpers::deserialize_full_boxed_slice_zero(&mut reader).unwrap();
+    assert_eq!(*b, *v);
+
+    // Larger than the maximum preallocation
+    cursor.set_position(0);
+    let config = deser::DeserConfig {
+        max_preallocate: 64,
+    };
+    let mut reader = deser::ReaderWithPos::with_config(&mut cursor, config);
+    let b: Box<[u64]> = deser::helpers::deserialize_full_boxed_slice_zero(&mut reader).unwrap();
+    assert_eq!(*b, *v);
+}
+
 #[test]
 fn test_box_slice_string() {
     let a = vec!["A".to_string(), "V".to_string()].into_boxed_slice();
@@ -185,6 +221,26 @@ fn test_struct_zero() {
     assert_eq!(a, eps);
 }
 
+#[test]
+fn test_transparent_zero() {
+    #[derive(Epserde, Copy, Clone, Debug, PartialEq)]
+    #[repr(transparent)]
+    #[zero_copy]
+    struct Id(u64);
+
+    let a = (0..100).map(Id).collect::<Vec<_>>();
+    let mut cursor = <AlignedCursor<A16>>::new();
+    // Serialize
+    let _bytes_written = a.serialize(&mut cursor).unwrap();
+
+    cursor.set_position(0);
+    let full = <Vec<Id>>::deserialize_full(&mut cursor).unwrap();
+    assert_eq!(a, full);
+
+    let eps: &[Id] = <Vec<Id>>::deserialize_eps(cursor.as_bytes()).unwrap();
+    assert_eq!(a, eps);
+}
+
 #[test]
 fn test_tuple_struct_deep() {
     #[derive(Epserde, Copy, Clone, Debug, PartialEq)]
@@ -221,6 +277,25 @@ fn test_tuple_struct_zero() {
     assert_eq!(a, *eps);
 }
 
+#[test]
+fn test_mut_slice() {
+    fn store(s: &impl Serialize, cursor: &mut AlignedCursor<A16>) {
+        s.serialize(cursor).unwrap();
+    }
+
+    let mut v = vec![1_u32, 2, 3, 4];
+    let s: &mut [u32] = v.as_mut_slice();
+    s[0] = 0;
+    let mut cursor = <AlignedCursor<A16>>::new();
+    store(&s, &mut cursor);
+
+    cursor.set_position(0);
+    let full = <Vec<u32>>::deserialize_full(&mut cursor).unwrap();
+    assert_eq!(full, [0, 2, 3, 4]);
+    let eps = <Vec<u32>>::deserialize_eps(cursor.as_bytes()).unwrap();
+    assert_eq!(eps, [0, 2, 3, 4]);
+}
+
 #[test]
 fn test_enum_deep() {
     #[derive(Epserde, Clone, Debug, PartialEq)]
@@ -292,6 +367,31 @@ fn test_enum_deep() {
     }
 }
 
+#[test]
+fn test_enum_tuple_variant_eps() {
+    // Fields whose type is a type parameter are ε-copy deserialized
+    #[derive(Epserde, Clone, Debug, PartialEq)]
+    enum Message<V = Vec<u32>> {
+        Empty,
+        Data(V),
+    }
+
+    let mut cursor = <AlignedCursor<A16>>::new();
+    let a = Message::Data(vec![1_u32, 2, 3]);
+    a.serialize(&mut cursor).unwrap();
+    cursor.set_position(0);
+    let full = <Message>::deserialize_full(&mut cursor).unwrap();
+    assert_eq!(a, full);
+    let bytes = cursor.as_bytes();
+    let eps: Message<&[u32]> = <Message>::deserialize_eps(bytes).unwrap();
+    let Message::Data(data) = eps else {
+        panic!("Wrong variant");
+    };
+    assert_eq!(data, &[1, 2, 3]);
+    // The slice borrows from the serialized data
+    assert!(bytes.as_ptr_range().contains(&(data.as_ptr() as *const u8)));
+}
+
 #[test]
 fn test_enum_zero() {
     #[derive(Epserde, Clone, Copy, Debug, PartialEq)]
@@ -349,3 +449,608 @@ fn test_enum_zero() {
     let eps = <Vec<Data>>::deserialize_eps(cursor.as_bytes()).unwrap();
     assert_eq!(a, *eps);
 }
+
+#[test]
+fn test_deserialize_eps_with_pos() {
+    let a = vec![1_u32, 2, 3, 4, 5];
+    let b = "trailing".to_string();
+
+    let mut cursor = <AlignedCursor<A16>>::new();
+    let len = a.serialize(&mut cursor).unwrap();
+    assert_eq!(len, cursor.len());
+    // Other data after the first structure must be aligned
+    let padding = len.next_multiple_of(16) - len;
+    std::io::Write::write_all(&mut cursor, &vec![0; padding]).unwrap();
+    b.serialize(&mut cursor).unwrap();
+
+    let bytes = cursor.as_bytes();
+    let (a1, pos) = <Vec<u32>>::deserialize_eps_with_pos(bytes).unwrap();
+    assert_eq!(a, a1);
+    assert_eq!(pos, len);
+
+    let (b1, _) = <String>::deserialize_eps_with_pos(&bytes[pos + padding..]).unwrap();
+    assert_eq!(b, b1);
+}
+
+#[test]
+fn test_enum_use_discriminant() {
+    #[derive(Epserde, Clone, Copy, Debug, PartialEq)]
+    #[epserde(use_discriminant)]
+    enum Data {
+        A = 10,
+        B = 20,
+        C,
+    }
+
+    for a in [Data::A, Data::B, Data::C] {
+        let mut cursor = <AlignedCursor<A16>>::new();
+        let schema = a.serialize_with_schema(&mut cursor).unwrap();
+        // The tag is the discriminant
+        let row = schema.0.iter().find(|row| row.field == "ROOT.tag").unwrap();
+        let tag = usize::from_ne_bytes(
+            cursor.as_bytes()[row.offset..row.offset + row.size]
+                .try_into()
+                .unwrap(),
+        );
+        assert_eq!(tag, a as usize);
+
+        cursor.set_position(0);
+        let full = <Data>::deserialize_full(&mut cursor).unwrap();
+        assert_eq!(a, full);
+
+        let eps = <Data>::deserialize_eps(cursor.as_bytes()).unwrap();
+        assert_eq!(a, eps);
+    }
+
+    // Unknown discriminants are rejected
+    let mut cursor = <AlignedCursor<A16>>::new();
+    let schema = Data::A.serialize_with_schema(&mut cursor).unwrap();
+    let row = schema.0.iter().find(|row| row.field == "ROOT.tag").unwrap();
+    cursor.as_bytes_mut()[row.offset..row.offset + row.size]
+        .copy_from_slice(&1_usize.to_ne_bytes());
+    assert!(matches!(
+        <Data>::deserialize_eps(cursor.as_bytes()),
+        Err(deser::Error::InvalidTag(1))
+    ));
+}
+
+#[test]
+fn test_array_string_eps() {
+    let a: [String; 8] = core::array::from_fn(|i| format!("string {}", i));
+
+    let mut cursor = <AlignedCursor<A16>>::new();
+    a.serialize(&mut cursor).unwrap();
+
+    cursor.set_position(0);
+    let full = <[String; 8]>::deserialize_full(&mut cursor).unwrap();
+    assert_eq!(a, full);
+
+    let bytes = cursor.as_bytes();
+    let range = bytes.as_ptr_range();
+    let eps: [&str; 8] = <[String; 8]>::deserialize_eps(bytes).unwrap();
+    for (s, e) in a.iter().zip(eps.iter()) {
+        assert_eq!(s, e);
+        // The strings borrow from the backend
+        assert!(range.contains(&e.as_ptr()));
+    }
+}
+
+#[test]
+fn test_nested_array_zero() {
+    let m: [[f32; 4]; 4] = core::array::from_fn(|i| core::array::from_fn(|j| (i * 4 + j) as f32));
+
+    let mut cursor = <AlignedCursor<A16>>::new();
+    let schema = m.serialize_with_schema(&mut cursor).unwrap();
+    // The matrix is written as a single block of zero-copy data
+    assert_eq!(
+        schema
+            .0
+            .iter()
+            .find(|row| row.field == "ROOT.zero")
+            .unwrap()
+            .size,
+        core::mem::size_of::<[[f32; 4]; 4]>()
+    );
+
+    cursor.set_position(0);
+    let full = <[[f32; 4]; 4]>::deserialize_full(&mut cursor).unwrap();
+    assert_eq!(m, full);
+
+    let bytes = cursor.as_bytes();
+    let eps: &[[f32; 4]; 4] = <[[f32; 4]; 4]>::deserialize_eps(bytes).unwrap();
+    assert_eq!(&m, eps);
+    // The matrix is a reference into the backend
+    assert!(bytes.as_ptr_range().contains(&(eps.as_ptr() as *const u8)));
+
+    // A nested array is not the same type as a flat array
+    assert!(<[f32; 16]>::deserialize_eps(bytes).is_err());
+    assert!(<[[f32; 2]; 8]>::deserialize_eps(bytes).is_err());
+}
+
+#[test]
+fn test_shared_str() {
+    use std::rc::Rc;
+    use std::sync::Arc;
+
+    let rc: Rc<str> = "hello".into();
+    let mut cursor = <AlignedCursor<A16>>::new();
+    rc.serialize(&mut cursor).unwrap();
+    cursor.set_position(0);
+    let full = <Rc<str>>::deserialize_full(&mut cursor).unwrap();
+    assert_eq!(full, rc);
+    let eps: &str = <Rc<str>>::deserialize_eps(cursor.as_bytes()).unwrap();
+    assert_eq!(eps, &*rc);
+    // Different pointer kinds have different type hashes
+    assert!(<Arc<str>>::deserialize_eps(cursor.as_bytes()).is_err());
+    assert!(<Box<str>>::deserialize_eps(cursor.as_bytes()).is_err());
+
+    let arc: Vec<Arc<str>> = vec!["a".into(), "".into(), "bcd".into()];
+    let mut cursor = <AlignedCursor<A16>>::new();
+    arc.serialize(&mut cursor).unwrap();
+    cursor.set_position(0);
+    let full = <Vec<Arc<str>>>::deserialize_full(&mut cursor).unwrap();
+    assert_eq!(full, arc);
+    let eps: Vec<&str> = <Vec<Arc<str>>>::deserialize_eps(cursor.as_bytes()).unwrap();
+    assert_eq!(eps, ["a", "", "bcd"]);
+}
+
+#[test]
+fn test_enum_tag_width() {
+    #[derive(Epserde, Clone, Debug, PartialEq)]
+    enum Two {
+        A,
+        B(u64),
+    }
+
+    let data = vec![Two::A, Two::B(3), Two::A];
+    let mut cursor = <AlignedCursor<A16>>::new();
+    let schema = data.serialize_with_schema(&mut cursor).unwrap();
+    let tags = schema
+        .0
+        .iter()
+        .filter(|row| row.field == "ROOT.item.tag")
+        .collect::<Vec<_>>();
+    assert_eq!(tags.len(), 3);
+    for tag in tags {
+        assert_eq!(tag.size, 1);
+    }
+
+    cursor.set_position(0);
+    let full = <Vec<Two>>::deserialize_full(&mut cursor).unwrap();
+    assert_eq!(full, data);
+    let eps = <Vec<Two>>::deserialize_eps(cursor.as_bytes()).unwrap();
+    assert_eq!(eps, data);
+
+    // Invalid tags are still detected
+    let tag = schema
+        .0
+        .iter()
+        .find(|row| row.field == "ROOT.item.tag")
+        .unwrap()
+        .offset;
+    cursor.as_bytes_mut()[tag] = 2;
+    assert!(matches!(
+        <Vec<Two>>::deserialize_eps(cursor.as_bytes()),
+        Err(deser::Error::InvalidTag(2))
+    ));
+}
+
+#[test]
+fn test_dyn_read() {
+    use std::io::Read;
+    let data = vec!["a".to_string(), "bc".to_string()];
+    let mut cursor = <AlignedCursor<A16>>::new();
+    data.serialize(&mut cursor).unwrap();
+    let bytes = cursor.as_bytes().to_vec();
+
+    let mut boxed: Box<dyn Read> = Box::new(std::io::Cursor::new(bytes.clone()));
+    let full = <Vec<String>>::deserialize_full(&mut boxed).unwrap();
+    assert_eq!(full, data);
+
+    let mut inner = std::io::Cursor::new(bytes);
+    let reader: &mut dyn Read = &mut inner;
+    let full = <Vec<String>>::deserialize_full(reader).unwrap();
+    assert_eq!(full, data);
+}
+
+#[test]
+fn test_interval() {
+    use core::ops::Bound;
+    use epserde::impls::stdlib::Interval;
+
+    let intervals = vec![
+        Interval::new(Bound::Excluded(1_u32), Bound::Included(5)),
+        Interval::from_range(10..),
+        Interval::new(Bound::Unbounded, Bound::Unbounded),
+    ];
+    let mut cursor = <AlignedCursor<A16>>::new();
+    intervals.serialize(&mut cursor).unwrap();
+
+    cursor.set_position(0);
+    let full = <Vec<Interval<u32>>>::deserialize_full(&mut cursor).unwrap();
+    assert_eq!(full, intervals);
+    let eps = <Vec<Interval<u32>>>::deserialize_eps(cursor.as_bytes()).unwrap();
+    assert_eq!(eps, intervals);
+
+    assert!(!full[0].contains(&1));
+    assert!(full[0].contains(&2));
+    assert!(full[0].contains(&5));
+    assert!(!full[0].contains(&6));
+    assert!(!full[1].contains(&9));
+    assert!(full[1].contains(&u32::MAX));
+    assert!(full[2].contains(&0));
+}
+
+#[test]
+fn test_option_vec() {
+    let a = Some(vec![1_u32, 2, 3]);
+    let mut cursor = <AlignedCursor<A16>>::new();
+    a.serialize(&mut cursor).unwrap();
+
+    cursor.set_position(0);
+    let a1 = <Option<Vec<u32>>>::deserialize_full(&mut cursor).unwrap();
+    assert_eq!(a, a1);
+
+    let bytes = cursor.as_bytes();
+    let a2: Option<&[u32]> = <Option<Vec<u32>>>::deserialize_eps(bytes).unwrap();
+    let s = a2.unwrap();
+    assert_eq!(s, &[1, 2, 3]);
+    // The slice must point inside the backend
+    assert!(bytes.as_ptr_range().contains(&(s.as_ptr() as *const u8)));
+
+    let b: Option<Box<[u32]>> = None;
+    let mut cursor = <AlignedCursor<A16>>::new();
+    b.serialize(&mut cursor).unwrap();
+
+    cursor.set_position(0);
+    let b1 = <Option<Box<[u32]>>>::deserialize_full(&mut cursor).unwrap();
+    assert_eq!(b, b1);
+
+    let b2: Option<&[u32]> = <Option<Box<[u32]>>>::deserialize_eps(cursor.as_bytes()).unwrap();
+    assert!(b2.is_none());
+}
+
+#[derive(Epserde, Debug, PartialEq, Eq, Clone, Copy)]
+#[repr(C)]
+#[zero_copy]
+struct UnitZero;
+
+#[derive(Epserde, Debug, PartialEq, Eq, Clone, Copy)]
+#[repr(C)]
+#[zero_copy]
+struct EmptyZero {}
+
+#[derive(Epserde, Debug, PartialEq, Eq, Clone, Copy)]
+#[deep_copy]
+struct UnitDeep;
+
+#[derive(Epserde, Debug, PartialEq, Eq, Clone, Copy)]
+#[deep_copy]
+struct EmptyDeep {}
+
+fn test_empty<T: Serialize + Deserialize + PartialEq + core::fmt::Debug>(value: T)
+where
+    for<'a> <T as DeserializeInner>::DeserType<'a>: core::fmt::Debug,
+{
+    let mut cursor = <AlignedCursor<A16>>::new();
+    let schema = value.serialize_with_schema(&mut cursor).unwrap();
+    // Only the header is written
+    assert!(schema
+        .0
+        .iter()
+        .all(|row| row.size == 0 || !row.field.starts_with("ROOT")));
+
+    cursor.set_position(0);
+    assert_eq!(T::deserialize_full(&mut cursor).unwrap(), value);
+    T::deserialize_eps(cursor.as_bytes()).unwrap();
+}
+
+#[test]
+fn test_empty_structs() {
+    test_empty(UnitZero);
+    test_empty(EmptyZero {});
+    test_empty(UnitDeep);
+    test_empty(EmptyDeep {});
+}
+
+#[test]
+fn test_ordering() {
+    use core::cmp::Ordering;
+
+    let orderings = vec![Ordering::Less, Ordering::Equal, Ordering::Greater];
+    let mut cursor = <AlignedCursor<A16>>::new();
+    orderings.serialize(&mut cursor).unwrap();
+
+    cursor.set_position(0);
+    let full = <Vec<Ordering>>::deserialize_full(&mut cursor).unwrap();
+    assert_eq!(full, orderings);
+    let eps = <Vec<Ordering>>::deserialize_eps(cursor.as_bytes()).unwrap();
+    assert_eq!(eps, orderings);
+
+    let mut cursor = <AlignedCursor<A16>>::new();
+    Ordering::Greater.serialize(&mut cursor).unwrap();
+    // The tag is the last byte
+    let len = cursor.len();
+    cursor.as_bytes_mut()[len - 1] = 3;
+    assert!(matches!(
+        Ordering::deserialize_eps(cursor.as_bytes()),
+        Err(deser::Error::InvalidTag(3))
+    ));
+    cursor.set_position(0);
+    assert!(matches!(
+        Ordering::deserialize_full(&mut cursor),
+        Err(deser::Error::InvalidTag(3))
+    ));
+}
+
+#[test]
+fn test_large_string() {
+    // Larger than MAX_PREALLOC_BYTES, so the buffer grows while reading
+    let s = "αβγ0123456789".repeat(300_000);
+    assert!(s.len() > 2 * deser::MAX_PREALLOC_BYTES);
+    let mut cursor = <AlignedCursor<A16>>::new();
+    s.serialize(&mut cursor).unwrap();
+
+    cursor.set_position(0);
+    let full = String::deserialize_full(&mut cursor).unwrap();
+    assert_eq!(full, s);
+    let eps = String::deserialize_eps(cursor.as_bytes()).unwrap();
+    assert_eq!(eps, s);
+}
+
+#[test]
+fn test_large_vec_u8() {
+    // Bytes are zero-copy, so they are read and written in bulk
+    let v: Vec<u8> = (0..64 << 20).map(|i: usize| i as u8).collect();
+    let mut cursor = <AlignedCursor<A16>>::new();
+    v.serialize(&mut cursor).unwrap();
+
+    cursor.set_position(0);
+    let full = <Vec<u8>>::deserialize_full(&mut cursor).unwrap();
+    assert_eq!(full, v);
+    let eps: &[u8] = <Vec<u8>>::deserialize_eps(cursor.as_bytes()).unwrap();
+    assert_eq!(eps, v.as_slice());
+}
+
+#[test]
+fn test_ip_addr() {
+    use core::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+    let addr = Ipv4Addr::new(192, 168, 0, 1);
+    let mut cursor = <AlignedCursor<A16>>::new();
+    addr.serialize(&mut cursor).unwrap();
+    cursor.set_position(0);
+    assert_eq!(Ipv4Addr::deserialize_full(&mut cursor).unwrap(), addr);
+    assert_eq!(Ipv4Addr::deserialize_eps(cursor.as_bytes()).unwrap(), addr);
+
+    let addrs = vec![
+        IpAddr::V4(addr),
+        IpAddr::V6(Ipv6Addr::LOCALHOST),
+        IpAddr::V6(Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 1)),
+    ];
+    let mut cursor = <AlignedCursor<A16>>::new();
+    addrs.serialize(&mut cursor).unwrap();
+    cursor.set_position(0);
+    assert_eq!(<Vec<IpAddr>>::deserialize_full(&mut cursor).unwrap(), addrs);
+    assert_eq!(
+        <Vec<IpAddr>>::deserialize_eps(cursor.as_bytes()).unwrap(),
+        addrs
+    );
+}
+
+#[test]
+fn test_vec_pairs() {
+    let pairs: Vec<(u32, u64)> = (0..1000).map(|i| (i, i as u64 * 3)).collect();
+    let mut cursor = <AlignedCursor<A16>>::new();
+    let bytes = pairs.serialize(&mut cursor).unwrap();
+    // The pairs are written as a slice, including their padding
+    let mut empty = <AlignedCursor<A16>>::new();
+    let empty_bytes = Vec::<(u32, u64)>::new().serialize(&mut empty).unwrap();
+    assert_eq!(
+        bytes - empty_bytes,
+        pairs.len() * core::mem::size_of::<(u32, u64)>()
+    );
+
+    cursor.set_position(0);
+    let full = <Vec<(u32, u64)>>::deserialize_full(&mut cursor).unwrap();
+    assert_eq!(full, pairs);
+    let eps: &[(u32, u64)] = <Vec<(u32, u64)>>::deserialize_eps(cursor.as_bytes()).unwrap();
+    assert_eq!(eps, pairs.as_slice());
+}
+
+#[test]
+fn test_infallible() {
+    use core::convert::Infallible;
+
+    #[derive(Epserde, Debug, PartialEq)]
+    struct Outcome<E> {
+        value: u64,
+        error: Option<E>,
+    }
+
+    let outcome: Outcome<Infallible> = Outcome {
+        value: 42,
+        error: None,
+    };
+    let mut cursor = <AlignedCursor<A16>>::new();
+    let schema = outcome.serialize_with_schema(&mut cursor).unwrap();
+
+    cursor.set_position(0);
+    let full = <Outcome<Infallible>>::deserialize_full(&mut cursor).unwrap();
+    assert_eq!(full, outcome);
+    let eps = <Outcome<Infallible>>::deserialize_eps(cursor.as_bytes()).unwrap();
+    assert_eq!(eps, outcome);
+
+    // A Some tag would require a value of an uninhabited type
+    let row = schema
+        .0
+        .iter()
+        .find(|row| row.field == "ROOT.error.Tag")
+        .unwrap();
+    cursor.as_bytes_mut()[row.offset] = 1;
+    cursor.set_position(0);
+    assert!(matches!(
+        <Outcome<Infallible>>::deserialize_full(&mut cursor),
+        Err(deser::Error::Uninhabited(_))
+    ));
+    assert!(matches!(
+        <Outcome<Infallible>>::deserialize_eps(cursor.as_bytes()),
+        Err(deser::Error::Uninhabited(_))
+    ));
+}
+
+#[test]
+fn test_range_string() {
+    let mut cursor = <AlignedCursor<A16>>::new();
+    let range = "a".to_string().."z".to_string();
+    range.serialize(&mut cursor).unwrap();
+    cursor.set_position(0);
+    assert_eq!(
+        <core::ops::Range<String>>::deserialize_full(&mut cursor).unwrap(),
+        range
+    );
+    let eps = <core::ops::Range<String>>::deserialize_eps(cursor.as_bytes()).unwrap();
+    assert_eq!(eps, "a".."z");
+
+    let mut cursor = <AlignedCursor<A16>>::new();
+    let range = "a".to_string()..;
+    range.serialize(&mut cursor).unwrap();
+    cursor.set_position(0);
+    assert_eq!(
+        <core::ops::RangeFrom<String>>::deserialize_full(&mut cursor).unwrap(),
+        range
+    );
+    let eps = <core::ops::RangeFrom<String>>::deserialize_eps(cursor.as_bytes()).unwrap();
+    assert_eq!(eps, "a"..);
+
+    let mut cursor = <AlignedCursor<A16>>::new();
+    let range = .."z".to_string();
+    range.serialize(&mut cursor).unwrap();
+    cursor.set_position(0);
+    assert_eq!(
+        <core::ops::RangeTo<String>>::deserialize_full(&mut cursor).unwrap(),
+        range
+    );
+    let eps = <core::ops::RangeTo<String>>::deserialize_eps(cursor.as_bytes()).unwrap();
+    assert_eq!(eps, .."z");
+
+    let mut cursor = <AlignedCursor<A16>>::new();
+    let range = "a".to_string()..="z".to_string();
+    range.serialize(&mut cursor).unwrap();
+    cursor.set_position(0);
+    assert_eq!(
+        <core::ops::RangeInclusive<String>>::deserialize_full(&mut cursor).unwrap(),
+        range
+    );
+    let eps = <core::ops::RangeInclusive<String>>::deserialize_eps(cursor.as_bytes()).unwrap();
+    assert_eq!(eps, "a"..="z");
+}
+
+#[test]
+fn test_vec_range() {
+    impl_test!(Vec<core::ops::Range<u32>>, vec![0..10, 5..7, 3..3]);
+}
+
+#[test]
+fn test_shared_slice() {
+    use std::rc::Rc;
+    use std::sync::Arc;
+
+    let a: Arc<[u32]> = Arc::from(vec![1, 2, 3, 4]);
+    let mut cursor = <AlignedCursor<A16>>::new();
+    a.serialize(&mut cursor).unwrap();
+    cursor.set_position(0);
+    let full = <Arc<[u32]>>::deserialize_full(&mut cursor).unwrap();
+    assert_eq!(full, a);
+    let bytes = cursor.as_bytes();
+    let eps = <Arc<[u32]>>::deserialize_eps(bytes).unwrap();
+    assert_eq!(eps, &*a);
+    // The ε-copy slice borrows from the backend
+    assert!(bytes.as_ptr_range().contains(&(eps.as_ptr() as *const u8)));
+
+    // Different type hash with respect to boxed slices
+    assert!(<Box<[u32]>>::deserialize_eps(bytes).is_err());
+
+    let r: Rc<[String]> = Rc::from(vec!["a".to_string(), "b".to_string()]);
+    let mut cursor = <AlignedCursor<A16>>::new();
+    r.serialize(&mut cursor).unwrap();
+    cursor.set_position(0);
+    let full = <Rc<[String]>>::deserialize_full(&mut cursor).unwrap();
+    assert_eq!(full, r);
+    let eps = <Rc<[String]>>::deserialize_eps(cursor.as_bytes()).unwrap();
+    assert_eq!(&*eps, &["a", "b"]);
+}
+
+#[test]
+fn test_lock() {
+    use std::sync::{Mutex, RwLock};
+
+    let m = Mutex::new(vec![1_u32, 2, 3]);
+    let mut cursor = <AlignedCursor<A16>>::new();
+    m.serialize(&mut cursor).unwrap();
+    cursor.set_position(0);
+    let full = <Mutex<Vec<u32>>>::deserialize_full(&mut cursor).unwrap();
+    assert_eq!(*full.lock().unwrap(), *m.lock().unwrap());
+    let eps = <Mutex<Vec<u32>>>::deserialize_eps(cursor.as_bytes()).unwrap();
+    assert_eq!(*eps.lock().unwrap(), &[1, 2, 3]);
+
+    // Different type hash with respect to read-write locks
+    assert!(<RwLock<Vec<u32>>>::deserialize_eps(cursor.as_bytes()).is_err());
+
+    let r = RwLock::new("config".to_string());
+    let mut cursor = <AlignedCursor<A16>>::new();
+    r.serialize(&mut cursor).unwrap();
+    cursor.set_position(0);
+    let full = <RwLock<String>>::deserialize_full(&mut cursor).unwrap();
+    assert_eq!(*full.read().unwrap(), "config");
+    let eps = <RwLock<String>>::deserialize_eps(cursor.as_bytes()).unwrap();
+    assert_eq!(*eps.read().unwrap(), "config");
+
+    // Poisoned locks cannot be serialized
+    let _ = std::panic::catch_unwind(|| {
+        let _guard = m.lock().unwrap();
+        panic!();
+    });
+    assert!(m.is_poisoned());
+    let mut cursor = <AlignedCursor<A16>>::new();
+    assert!(matches!(
+        m.serialize(&mut cursor),
+        Err(ser::Error::PoisonedLock)
+    ));
+}
+
+#[test]
+fn test_cstring() {
+    use std::ffi::{CStr, CString};
+
+    let s = CString::new("ε-serde").unwrap();
+    let mut cursor = <AlignedCursor<A16>>::new();
+    s.serialize(&mut cursor).unwrap();
+    cursor.set_position(0);
+    let full = CString::deserialize_full(&mut cursor).unwrap();
+    assert_eq!(full, s);
+    let eps: &CStr = CString::deserialize_eps(cursor.as_bytes()).unwrap();
+    assert_eq!(eps, s.as_c_str());
+
+    let s = CString::default();
+    let mut cursor = <AlignedCursor<A16>>::new();
+    s.serialize(&mut cursor).unwrap();
+    let eps = CString::deserialize_eps(cursor.as_bytes()).unwrap();
+    assert!(eps.is_empty());
+
+    // An interior nul byte is rejected
+    let s = CString::new("abc").unwrap();
+    let mut cursor = <AlignedCursor<A16>>::new();
+    s.serialize(&mut cursor).unwrap();
+    let len = cursor.len();
+    cursor.as_bytes_mut()[len - 3] = 0;
+    assert!(matches!(
+        CString::deserialize_eps(cursor.as_bytes()),
+        Err(deser::Error::InvalidCString)
+    ));
+    cursor.set_position(0);
+    assert!(matches!(
+        CString::deserialize_full(&mut cursor),
+        Err(deser::Error::InvalidCString)
+    ));
+}
@@ -0,0 +1,27 @@
+/*
+ * SPDX-FileCopyrightText: 2023 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+#![cfg(test)]
+#![cfg(feature = "uuid")]
+
+use epserde::prelude::*;
+use maligned::A16;
+use uuid::Uuid;
+
+#[test]
+fn test_uuid() {
+    let v: Vec<Uuid> = (0..100_u128)
+        .map(|i| Uuid::from_u128(i * 0x0123_4567_89ab_cdef_0123_4567_89ab_cdef))
+        .collect();
+    let mut cursor = <AlignedCursor<A16>>::new();
+    v.serialize(&mut cursor).unwrap();
+
+    cursor.set_position(0);
+    let full = <Vec<Uuid>>::deserialize_full(&mut cursor).unwrap();
+    assert_eq!(full, v);
+    let eps: &[Uuid] = <Vec<Uuid>>::deserialize_eps(cursor.as_bytes()).unwrap();
+    assert_eq!(eps, v.as_slice());
+}
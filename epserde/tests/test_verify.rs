@@ -0,0 +1,85 @@
+/*
+ * SPDX-FileCopyrightText: 2023 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+#![cfg(test)]
+
+use epserde::deser::{verify_full, Error};
+use epserde::prelude::*;
+use maligned::A16;
+
+type Data = Vec<Option<Vec<String>>>;
+
+fn serialize(data: &impl Serialize) -> Vec<u8> {
+    let mut cursor = <AlignedCursor<A16>>::new();
+    data.serialize(&mut cursor).unwrap();
+    cursor.as_bytes().to_vec()
+}
+
+/// Return the length of the header of some serialized data.
+fn header_len(bytes: &[u8]) -> usize {
+    let mut backend = SliceWithPos::new(bytes);
+    deser::read_header(&mut backend).unwrap();
+    backend.pos
+}
+
+#[test]
+fn test_verify_full() {
+    let data: Data = vec![
+        Some(vec!["ε-serde".to_string(), "".to_string()]),
+        None,
+        Some(vec![]),
+    ];
+    let bytes = serialize(&data);
+    verify_full::<Data>(&mut bytes.as_slice()).unwrap();
+
+    // Wrong type
+    assert!(matches!(
+        verify_full::<Vec<Vec<String>>>(&mut bytes.as_slice()),
+        Err(Error::WrongTypeHash { .. })
+    ));
+
+    // Truncated data
+    for len in [bytes.len() - 1, bytes.len() / 2] {
+        assert!(verify_full::<Data>(&mut &bytes[..len]).is_err());
+    }
+
+    // The tag of the first option follows the length of the outer vector
+    let mut corrupted = bytes.clone();
+    corrupted[header_len(&bytes) + 8] = 2;
+    assert!(matches!(
+        verify_full::<Data>(&mut corrupted.as_slice()),
+        Err(Error::InvalidTag(2))
+    ));
+}
+
+#[test]
+fn test_verify_full_strings() {
+    // Characters are split between chunks
+    let data = vec!["a".to_string() + &"é".repeat(3000)];
+    let mut bytes = serialize(&data);
+    verify_full::<Vec<String>>(&mut bytes.as_slice()).unwrap();
+
+    // The content of the string follows the lengths of the vector and of the string
+    let pos = header_len(&bytes) + 16 + 4095;
+    bytes[pos] = 0xFF;
+    assert!(matches!(
+        verify_full::<Vec<String>>(&mut bytes.as_slice()),
+        Err(Error::InvalidUtf8(_))
+    ));
+}
+
+#[test]
+fn test_verify_full_zero() {
+    let data = (0..10_000_u64).collect::<Vec<_>>();
+    let mut bytes = serialize(&data);
+    verify_full::<Vec<u64>>(&mut bytes.as_slice()).unwrap();
+    verify_full::<Box<[u64]>>(&mut bytes.as_slice()).unwrap_err();
+
+    // A corrupted length does not cause a large allocation
+    let pos = header_len(&bytes);
+    bytes[pos..pos + 8].copy_from_slice(&(1_usize << 56).to_ne_bytes());
+    assert!(verify_full::<Vec<u64>>(&mut bytes.as_slice()).is_err());
+}
@@ -0,0 +1,41 @@
+/*
+ * SPDX-FileCopyrightText: 2023 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+#![cfg(test)]
+
+use epserde::prelude::*;
+use maligned::A16;
+use std::sync::Mutex;
+
+static WARNINGS: Mutex<Vec<String>> = Mutex::new(Vec::new());
+
+fn record_warning(warning: &str) {
+    WARNINGS.lock().unwrap().push(warning.to_string());
+}
+
+/// Could be zero-copy, but it is not declared as such.
+#[derive(Epserde, Debug, Clone, Copy)]
+#[repr(C)]
+struct Mismatch {
+    a: u32,
+    b: u64,
+}
+
+#[test]
+fn test_warning_handler() {
+    ser::set_warning_handler(record_warning);
+
+    let mut cursor = <AlignedCursor<A16>>::new();
+    vec![Mismatch { a: 1, b: 2 }]
+        .serialize(&mut cursor)
+        .unwrap();
+
+    let warnings = WARNINGS.lock().unwrap();
+    assert!(!warnings.is_empty());
+    assert!(warnings
+        .iter()
+        .all(|w| w.contains("Mismatch") && w.contains("#[deep_copy]")));
+}
@@ -60,3 +60,47 @@ test_zero!(
     [((i64, i32), i32); 2],
     [((-1_i64, 1), -1), ((-2_i64, 2), -2)]
 );
+
+#[derive(Epserde, Debug, PartialEq, Eq, Clone, Copy)]
+#[repr(C)]
+#[zero_copy]
+struct WithMarkers {
+    value: u64,
+    range: core::ops::RangeFull,
+    unit: (),
+    marker: core::marker::PhantomData<u32>,
+}
+
+test_zero!(
+    test_zst_fields,
+    WithMarkers,
+    WithMarkers {
+        value: 42,
+        range: ..,
+        unit: (),
+        marker: core::marker::PhantomData,
+    }
+);
+
+#[test]
+fn test_zst_vec() {
+    let v = vec![(); 3];
+    let mut cursor = <AlignedCursor<A16>>::new();
+    v.serialize(&mut cursor).unwrap();
+    cursor.set_position(0);
+    assert_eq!(<Vec<()>>::deserialize_full(&mut cursor).unwrap(), v);
+    assert_eq!(
+        <Vec<()>>::deserialize_eps(cursor.as_bytes()).unwrap(),
+        &v[..]
+    );
+
+    let v = vec![..; 2];
+    let mut cursor = <AlignedCursor<A16>>::new();
+    v.serialize(&mut cursor).unwrap();
+    assert_eq!(
+        <Vec<core::ops::RangeFull>>::deserialize_eps(cursor.as_bytes())
+            .unwrap()
+            .len(),
+        2
+    );
+}
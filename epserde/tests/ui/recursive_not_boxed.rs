@@ -0,0 +1,9 @@
+use epserde::prelude::*;
+
+#[derive(Epserde, Debug)]
+struct Node {
+    value: u32,
+    children: Vec<Node>,
+}
+
+fn main() {}
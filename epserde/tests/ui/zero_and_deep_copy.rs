@@ -0,0 +1,12 @@
+use epserde::prelude::*;
+
+#[derive(Epserde, Debug, Clone, Copy)]
+#[repr(C)]
+#[zero_copy]
+#[deep_copy]
+struct Point {
+    x: u32,
+    y: u32,
+}
+
+fn main() {}
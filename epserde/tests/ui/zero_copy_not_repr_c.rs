@@ -0,0 +1,10 @@
+use epserde::prelude::*;
+
+#[derive(Epserde, Debug, Clone, Copy)]
+#[zero_copy]
+struct Point {
+    x: u32,
+    y: u32,
+}
+
+fn main() {}